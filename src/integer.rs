@@ -18,18 +18,53 @@
 mod ops;
 mod conv;
 mod extras;
+mod arith;
+mod borrowed;
+
+#[cfg(feature = "num-traits")]
+mod num;
 
 //#[cfg(feature = "serde")]
 //mod serde;
 
 //pub mod macros;
 
-use flint_sys::{flint, fmpz};
+pub use borrowed::IntegerRef;
+
+#[cfg(feature = "num-traits")]
+pub use num::FloorInteger;
+
+use flint_sys::{flint, fmpz, fmpz_factor};
 use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::mem::{ManuallyDrop, MaybeUninit};
 
+/// Rounding modes for conversions that may be inexact.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Round {
+    /// Round to the nearest value, with ties to even.
+    #[default]
+    Nearest,
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards positive infinity.
+    Ceil,
+    /// Round towards zero.
+    Zero,
+}
+
+/// An arbitrary precision integer.
+///
+/// Division of `Integer`s gives a [Rational], and `%` is the truncated
+/// remainder. Because `/` does not return an `Integer`, the `num-traits`
+/// feature implements `Num`, `Signed`, `CheckedDiv` and the `num-integer`
+/// traits (`num_integer::Integer`, `Roots`), which all require
+/// `Div<Output = Self>`, on the wrapper `FloorInteger`, whose `/` and `%`
+/// are [Integer::fdiv_q] and [Integer::fdiv_r].
+// Transparent so that an `fmpz` stored elsewhere can be borrowed as an
+// `Integer`, see `IntegerRef`.
 #[derive(Debug)]
+#[repr(transparent)]
 pub struct Integer {
     inner: flint::fmpz,
 }
@@ -63,10 +98,81 @@ impl Default for Integer {
     }
 }
 
+/// Decimal formatting respects the width, fill, alignment, sign and zero
+/// flags. A precision `{:.n}` limits the output to `n` digits for huge
+/// values, as in [to_str_truncated][Integer::to_str_truncated].
+///
+/// ```
+/// use rufl::integer::Integer;
+/// use rufl::ops::Pow;
+///
+/// let z = Integer::from(-255);
+/// assert_eq!(format!("{:>8}", z), "    -255");
+/// assert_eq!(format!("{:08}", z), "-0000255");
+/// assert_eq!(format!("{:+}", Integer::from(7)), "+7");
+///
+/// let z = Integer::from(10).pow(30u8) - 1u64;
+/// assert_eq!(format!("{:.8}", z), "9999…9999 (30 digits)");
+/// ```
 impl fmt::Display for Integer {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.to_str_radix(10))
+        let digits = match f.precision() {
+            Some(p) => self.abs().to_str_truncated(p.max(2)),
+            None => self.abs().to_str_radix(10),
+        };
+        f.pad_integral(self.sign() >= 0, "", &digits)
+    }
+}
+
+/// Formats the absolute value in base 16 with a leading minus sign for
+/// negative values. The alternate flag `{:#x}` adds the `0x` prefix.
+///
+/// ```
+/// use rufl::integer::Integer;
+///
+/// let z = Integer::from(-255);
+/// assert_eq!(format!("{:x}", z), "-ff");
+/// assert_eq!(format!("{:#X}", z), "-0xFF");
+/// assert_eq!(format!("{:#010x}", Integer::from(255)), "0x000000ff");
+/// ```
+impl fmt::LowerHex for Integer {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad_integral(self.sign() >= 0, "0x", &self.abs().to_str_radix(16))
+    }
+}
+
+impl fmt::UpperHex for Integer {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let digits = self.abs().to_str_radix(16).to_uppercase();
+        f.pad_integral(self.sign() >= 0, "0x", &digits)
+    }
+}
+
+/// ```
+/// use rufl::integer::Integer;
+///
+/// assert_eq!(format!("{:#o}", Integer::from(8)), "0o10");
+/// ```
+impl fmt::Octal for Integer {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad_integral(self.sign() >= 0, "0o", &self.abs().to_str_radix(8))
+    }
+}
+
+/// ```
+/// use rufl::integer::Integer;
+///
+/// assert_eq!(format!("{:b}", Integer::from(-5)), "-101");
+/// assert_eq!(format!("{:#b}", Integer::from(5)), "0b101");
+/// ```
+impl fmt::Binary for Integer {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad_integral(self.sign() >= 0, "0b", &self.abs().to_str_radix(2))
     }
 }
 
@@ -245,6 +351,117 @@ impl Integer {
         }
     }
 
+    /// Return an `Option` containing the input as a signed 128-bit integer if
+    /// possible.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    ///
+    /// let z = Integer::from(i128::MIN);
+    /// assert_eq!(z.get_i128(), Some(i128::MIN));
+    ///
+    /// let z = Integer::from(u128::MAX);
+    /// assert!(z.get_i128().is_none());
+    /// ```
+    #[inline]
+    pub fn get_i128(&self) -> Option<i128> {
+        let abs = self.abs().get_u128()?;
+        if self.sign() < 0 {
+            if abs <= 1u128 << 127 {
+                Some((abs as i128).wrapping_neg())
+            } else {
+                None
+            }
+        } else {
+            i128::try_from(abs).ok()
+        }
+    }
+
+    /// Return an `Option` containing the input as an unsigned 128-bit integer
+    /// if possible.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    ///
+    /// let z = Integer::from(u128::MAX);
+    /// assert_eq!(z.get_u128(), Some(u128::MAX));
+    ///
+    /// let z = Integer::from(-1);
+    /// assert!(z.get_u128().is_none());
+    /// ```
+    #[inline]
+    pub fn get_u128(&self) -> Option<u128> {
+        if self.sign() < 0 || self.bits() > 128 {
+            return None;
+        }
+        let limbs = self.get_ui_vector();
+        Some(limbs.iter().rev().fold(0u128, |acc, &x| (acc << 64) | x as u128))
+    }
+
+    /// Convert the `Integer` to an `f64`, rounding in the direction `round`
+    /// if it is not exactly representable. Values too large for an `f64`
+    /// round to infinity or to `f64::MAX` according to `round`.
+    ///
+    /// ```
+    /// use rufl::integer::{Integer, Round};
+    ///
+    /// let z = Integer::from((1u64 << 53) + 1);
+    /// assert_eq!(z.to_f64(Round::Nearest), 9007199254740992.0);
+    /// assert_eq!(z.to_f64(Round::Ceil), 9007199254740994.0);
+    ///
+    /// let z = -(Integer::from(1) << 2000u64);
+    /// assert_eq!(z.to_f64(Round::Nearest), f64::NEG_INFINITY);
+    /// assert_eq!(z.to_f64(Round::Zero), f64::MIN);
+    /// ```
+    #[inline]
+    pub fn to_f64(&self, round: Round) -> f64 {
+        let neg = self.sign() < 0;
+        let (m, e) = round_float(&self.abs(), 0, neg, round, F64_PREC, F64_EMIN);
+        compose_f64(&m, e, neg, round)
+    }
+
+    /// Convert the `Integer` to an `f32`, rounding in the direction `round`
+    /// if it is not exactly representable.
+    ///
+    /// ```
+    /// use rufl::integer::{Integer, Round};
+    ///
+    /// let z = Integer::from(16777217);
+    /// assert_eq!(z.to_f32(Round::Floor), 16777216.0);
+    /// assert_eq!(z.to_f32(Round::Ceil), 16777218.0);
+    /// ```
+    #[inline]
+    pub fn to_f32(&self, round: Round) -> f32 {
+        let neg = self.sign() < 0;
+        let (m, e) = round_float(&self.abs(), 0, neg, round, F32_PREC, F32_EMIN);
+        compose_f32(&m, e, neg, round)
+    }
+
+    /// Return a pair `(d, exp)` such that the `Integer` is approximately
+    /// `d * 2^exp` with `0.5 <= |d| < 1`, in the manner of C's `frexp`. The
+    /// mantissa `d` is rounded to 53 bits in the direction `round`, so unlike
+    /// [to_f64][Integer::to_f64] this never overflows. Zero gives `(0.0, 0)`.
+    ///
+    /// ```
+    /// use rufl::integer::{Integer, Round};
+    ///
+    /// let z = Integer::from(-12);
+    /// assert_eq!(z.to_f64_exp(Round::Nearest), (-0.75, 4));
+    ///
+    /// let z = Integer::from(1) << 5000u64;
+    /// assert_eq!(z.to_f64_exp(Round::Nearest), (0.5, 5001));
+    /// ```
+    pub fn to_f64_exp(&self, round: Round) -> (f64, i64) {
+        if self.is_zero() {
+            return (0.0, 0);
+        }
+        let neg = self.sign() < 0;
+        let (m, e) = round_float(&self.abs(), 0, neg, round, F64_PREC, i64::MIN);
+        let b = m.bits() as i64;
+        let d = m.get_ui().unwrap() as f64 * pow2_f64(-b);
+        (if neg { -d } else { d }, e + b)
+    }
+
     /// Return a vector `A` of unsigned longs such that the original [Integer] can
     /// be written as `a[0] + a[1]*x + ... + a[n-1]*x^(n-1)` where `x = 2^FLINT_BITS`.
     ///
@@ -342,6 +559,120 @@ impl Integer {
         }
     }
 
+    /// Parse an `Integer` in base `base`, which can be between 2 and 62,
+    /// inclusive; other bases return an error. A leading `+` or `-` sign is
+    /// accepted, and single underscores may be used to separate digits. Bases
+    /// up to 36 are case-insensitive, while larger bases use the digits `0-9`,
+    /// then `A-Z`, then `a-z`.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    ///
+    /// let z = Integer::from_str_radix("-ff_ff", 16).unwrap();
+    /// assert_eq!(z, -65535);
+    ///
+    /// let z = Integer::from_str_radix("zZ", 62).unwrap();
+    /// assert_eq!(z, 61 * 62 + 35);
+    ///
+    /// assert!(Integer::from_str_radix("102", 2).is_err());
+    /// assert!(Integer::from_str_radix("1__0", 2).is_err());
+    /// assert!(Integer::from_str_radix("10_", 2).is_err());
+    /// assert!(Integer::from_str_radix("10", 63).is_err());
+    /// ```
+    pub fn from_str_radix(s: &str, base: u32) -> crate::Result<Integer> {
+        if !(2..=62).contains(&base) {
+            return Err(crate::Error::Msg(
+                "Base must be between 2 and 62.".to_string()
+            ));
+        }
+        conv::parse_radix(s, base)
+    }
+
+    /// Convert the `Integer` to a string in base `base`, inserting the
+    /// separator `sep` between groups of `group` digits counted from the
+    /// right. Panics if `group` is zero.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    ///
+    /// let x = Integer::from(-1234567);
+    /// assert_eq!(x.to_str_grouped(10, 3, ','), "-1,234,567");
+    ///
+    /// let x = Integer::from(0xdeadbeefu32);
+    /// assert_eq!(x.to_str_grouped(16, 4, '_'), "dead_beef");
+    /// ```
+    pub fn to_str_grouped(&self, base: u8, group: usize, sep: char) -> String {
+        assert!(group > 0);
+        let digits = self.abs().to_str_radix(base);
+        let n = digits.len();
+
+        let mut res = String::with_capacity(n + n / group + 1);
+        if self.sign() < 0 {
+            res.push('-');
+        }
+        for (i, c) in digits.chars().enumerate() {
+            if i > 0 && (n - i) % group == 0 {
+                res.push(sep);
+            }
+            res.push(c);
+        }
+        res
+    }
+
+    /// Convert the `Integer` to a decimal string, abbreviating it to its
+    /// leading and trailing digits if it has more than `max_digits` digits.
+    /// At most `max_digits` digits are shown in total, followed by the full
+    /// digit count. Panics if `max_digits < 2`.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    /// use rufl::ops::Pow;
+    ///
+    /// let x = Integer::from(12345678);
+    /// assert_eq!(x.to_str_truncated(8), "12345678");
+    ///
+    /// let x = Integer::from(2).pow(1000u32);
+    /// assert_eq!(x.to_str_truncated(8), "1071…9376 (302 digits)");
+    /// ```
+    pub fn to_str_truncated(&self, max_digits: usize) -> String {
+        assert!(max_digits >= 2);
+        let abs = self.abs();
+
+        // sizeinbase may overestimate the number of digits by one.
+        let mut n = abs.sizeinbase(10);
+        if n > max_digits {
+            let mut p = Integer::default();
+            unsafe { fmpz::fmpz_ui_pow_ui(p.as_mut_ptr(), 10, n as u64 - 1); }
+            if abs < p {
+                n -= 1;
+            }
+        }
+        if n <= max_digits {
+            return self.to_str_radix(10);
+        }
+
+        let lead = (max_digits + 1) / 2;
+        let trail = max_digits / 2;
+        let mut hi = Integer::default();
+        let mut lo = Integer::default();
+        unsafe {
+            let mut p = Integer::default();
+            fmpz::fmpz_ui_pow_ui(p.as_mut_ptr(), 10, (n - lead) as u64);
+            fmpz::fmpz_tdiv_q(hi.as_mut_ptr(), abs.as_ptr(), p.as_ptr());
+            fmpz::fmpz_ui_pow_ui(p.as_mut_ptr(), 10, trail as u64);
+            fmpz::fmpz_tdiv_r(lo.as_mut_ptr(), abs.as_ptr(), p.as_ptr());
+        }
+
+        let sign = if self.sign() < 0 { "-" } else { "" };
+        format!("{}{}…{:0>width$} ({} digits)",
+            sign,
+            hi.to_str_radix(10),
+            lo.to_str_radix(10),
+            n,
+            width = trail
+        )
+    }
+
     /// Determines the size of the absolute value of an `Integer` in base `base`
     /// in terms of number of digits. The base can be between 2 and 62, inclusive.
     ///
@@ -459,6 +790,368 @@ impl Integer {
         unsafe { fmpz::fmpz_tstbit(self.as_ptr(), bit_index) == 1 }
     }
 
+    /// Clears the bit index `bit_index` of an `Integer`. Negative values are
+    /// treated as two's complement with infinitely many leading ones.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    ///
+    /// let mut z = Integer::from(1025);
+    /// z.clrbit(0);
+    /// assert_eq!(1024, z);
+    ///
+    /// let mut z = Integer::from(-1);
+    /// z.clrbit(1);
+    /// assert_eq!(-3, z);
+    /// ```
+    #[inline]
+    pub fn clrbit(&mut self, bit_index: u64) {
+        unsafe { fmpz::fmpz_clrbit(self.as_mut_ptr(), bit_index) }
+    }
+
+    /// Complements the bit index `bit_index` of an `Integer`. Negative values
+    /// are treated as two's complement with infinitely many leading ones.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    ///
+    /// let mut z = Integer::from(8);
+    /// z.combit(0);
+    /// assert_eq!(9, z);
+    /// z.combit(3);
+    /// assert_eq!(1, z);
+    /// ```
+    #[inline]
+    pub fn combit(&mut self, bit_index: u64) {
+        unsafe { fmpz::fmpz_combit(self.as_mut_ptr(), bit_index) }
+    }
+
+    /// Returns the number of one bits of a nonnegative `Integer`, or `None`
+    /// if the `Integer` is negative (its two's complement representation has
+    /// infinitely many ones).
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    ///
+    /// let z = Integer::from(0b1011);
+    /// assert_eq!(z.popcount(), Some(3));
+    ///
+    /// let z = Integer::from(-5);
+    /// assert_eq!(z.popcount(), None);
+    /// ```
+    #[inline]
+    pub fn popcount(&self) -> Option<u64> {
+        if self.sign() < 0 {
+            None
+        } else {
+            unsafe { Some(fmpz::fmpz_popcnt(self.as_ptr())) }
+        }
+    }
+
+    /// Returns the number of trailing zero bits of an `Integer`, that is, the
+    /// exponent of the largest power of two dividing it. Returns `None` if the
+    /// `Integer` is zero.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    ///
+    /// let z = Integer::from(-96);
+    /// assert_eq!(z.trailing_zeros(), Some(5));
+    ///
+    /// let z = Integer::from(0);
+    /// assert_eq!(z.trailing_zeros(), None);
+    /// ```
+    #[inline]
+    pub fn trailing_zeros(&self) -> Option<u64> {
+        if self.is_zero() {
+            None
+        } else {
+            unsafe { Some(fmpz::fmpz_val2(self.as_ptr())) }
+        }
+    }
+
+    /// Returns an iterator over the bits of an `Integer`, least significant
+    /// first. The iterator yields `self.bits()` values as in
+    /// [testbit][Integer::testbit], so for a negative `Integer` these are the
+    /// low bits of its two's complement representation and every bit past the
+    /// end is one.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    ///
+    /// let z = Integer::from(0b1101);
+    /// let bits: Vec<bool> = z.iter_bits().collect();
+    /// assert_eq!(bits, vec![true, false, true, true]);
+    ///
+    /// let z = Integer::from(-4);
+    /// let bits: Vec<bool> = z.iter_bits().collect();
+    /// assert_eq!(bits, vec![false, false, true]);
+    /// ```
+    #[inline]
+    pub fn iter_bits(&self) -> IntegerBits<'_> {
+        IntegerBits { x: self, front: 0, back: self.bits() }
+    }
+
+    /// Returns the little-endian bytes of a nonnegative `Integer`, using as
+    /// few bytes as possible. Returns `None` if the `Integer` is negative.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    ///
+    /// let z = Integer::from(0x12345);
+    /// assert_eq!(z.to_bytes_le(), Some(vec![0x45, 0x23, 0x01]));
+    ///
+    /// let z = Integer::from(0);
+    /// assert_eq!(z.to_bytes_le(), Some(vec![0]));
+    /// ```
+    pub fn to_bytes_le(&self) -> Option<Vec<u8>> {
+        if self.sign() < 0 {
+            None
+        } else {
+            Some(self.abs_bytes_le())
+        }
+    }
+
+    /// Returns the big-endian bytes of a nonnegative `Integer`, using as
+    /// few bytes as possible. Returns `None` if the `Integer` is negative.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    ///
+    /// let z = Integer::from(0x12345);
+    /// assert_eq!(z.to_bytes_be(), Some(vec![0x01, 0x23, 0x45]));
+    /// ```
+    pub fn to_bytes_be(&self) -> Option<Vec<u8>> {
+        let mut res = self.to_bytes_le()?;
+        res.reverse();
+        Some(res)
+    }
+
+    /// Returns the minimal little-endian two's complement bytes of an
+    /// `Integer`.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    ///
+    /// let z = Integer::from(128);
+    /// assert_eq!(z.to_signed_bytes_le(), vec![0x80, 0x00]);
+    ///
+    /// let z = Integer::from(-128);
+    /// assert_eq!(z.to_signed_bytes_le(), vec![0x80]);
+    ///
+    /// let z = Integer::from(-200);
+    /// assert_eq!(z.to_signed_bytes_le(), vec![0x38, 0xff]);
+    /// ```
+    pub fn to_signed_bytes_le(&self) -> Vec<u8> {
+        let mut res = self.abs_bytes_le();
+        if self.sign() < 0 {
+            // Negate the magnitude in place: invert every byte and add one.
+            let mut carry = true;
+            for b in res.iter_mut() {
+                *b = !*b;
+                if carry {
+                    let (s, c) = b.overflowing_add(1);
+                    *b = s;
+                    carry = c;
+                }
+            }
+            if res[res.len() - 1] & 0x80 == 0 {
+                res.push(0xff);
+            }
+            while res.len() > 1
+                && res[res.len() - 1] == 0xff
+                && res[res.len() - 2] & 0x80 != 0
+            {
+                res.pop();
+            }
+        } else if res[res.len() - 1] & 0x80 != 0 {
+            res.push(0);
+        }
+        res
+    }
+
+    /// Returns the minimal big-endian two's complement bytes of an `Integer`.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    ///
+    /// let z = Integer::from(-200);
+    /// assert_eq!(z.to_signed_bytes_be(), vec![0xff, 0x38]);
+    /// ```
+    pub fn to_signed_bytes_be(&self) -> Vec<u8> {
+        let mut res = self.to_signed_bytes_le();
+        res.reverse();
+        res
+    }
+
+    /// Create a nonnegative `Integer` from its little-endian bytes.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    ///
+    /// let z = Integer::from_bytes_le(&[0x45, 0x23, 0x01]);
+    /// assert_eq!(z, 0x12345);
+    /// ```
+    pub fn from_bytes_le(bytes: &[u8]) -> Integer {
+        let limbs = bytes
+            .chunks(8)
+            .map(|chunk| {
+                let mut word = [0u8; 8];
+                word[..chunk.len()].copy_from_slice(chunk);
+                u64::from_le_bytes(word)
+            })
+            .collect();
+        let mut res = Integer::default();
+        res.set_ui_vector(limbs);
+        res
+    }
+
+    /// Create a nonnegative `Integer` from its big-endian bytes.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    ///
+    /// let z = Integer::from_bytes_be(&[0x01, 0x23, 0x45]);
+    /// assert_eq!(z, 0x12345);
+    /// ```
+    pub fn from_bytes_be(bytes: &[u8]) -> Integer {
+        let mut le = bytes.to_vec();
+        le.reverse();
+        Integer::from_bytes_le(&le)
+    }
+
+    /// Create an `Integer` from its little-endian two's complement bytes.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    ///
+    /// let z = Integer::from_signed_bytes_le(&[0x38, 0xff]);
+    /// assert_eq!(z, -200);
+    ///
+    /// let z = Integer::from(-1234567890123456789i64);
+    /// assert_eq!(Integer::from_signed_bytes_le(&z.to_signed_bytes_le()), z);
+    /// ```
+    pub fn from_signed_bytes_le(bytes: &[u8]) -> Integer {
+        let mut res = Integer::from_bytes_le(bytes);
+        if let Some(last) = bytes.last() {
+            if last & 0x80 != 0 {
+                res -= Integer::one() << (8 * bytes.len() as u64);
+            }
+        }
+        res
+    }
+
+    /// Create an `Integer` from its big-endian two's complement bytes.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    ///
+    /// let z = Integer::from_signed_bytes_be(&[0xff, 0x38]);
+    /// assert_eq!(z, -200);
+    /// ```
+    pub fn from_signed_bytes_be(bytes: &[u8]) -> Integer {
+        let mut le = bytes.to_vec();
+        le.reverse();
+        Integer::from_signed_bytes_le(&le)
+    }
+
+    /// Returns the digits of the absolute value of an `Integer` in base
+    /// `base`, least significant first. Zero has no digits. Panics if
+    /// `base < 2`.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    ///
+    /// let z = Integer::from(-1234);
+    /// assert_eq!(z.digits(10), vec![4, 3, 2, 1]);
+    ///
+    /// let z = Integer::from(255);
+    /// assert_eq!(z.digits(16), vec![15, 15]);
+    /// assert!(Integer::from(0).digits(7).is_empty());
+    /// ```
+    pub fn digits(&self, base: u64) -> Vec<u64> {
+        assert!(base >= 2);
+        let (chunk_base, chunk_len) = digit_chunk(base);
+
+        let mut res = vec![];
+        let mut x = self.abs();
+        while !x.is_zero() {
+            let mut r = unsafe {
+                fmpz::fmpz_fdiv_ui(x.as_ptr(), chunk_base)
+            };
+            unsafe {
+                fmpz::fmpz_fdiv_q_ui(x.as_mut_ptr(), x.as_ptr(), chunk_base);
+            }
+
+            // Only the leading chunk may be shorter than chunk_len.
+            let mut i = 0;
+            while i < chunk_len && (r != 0 || !x.is_zero()) {
+                res.push(r % base);
+                r /= base;
+                i += 1;
+            }
+        }
+        res
+    }
+
+    /// Create a nonnegative `Integer` from its digits in base `base`, least
+    /// significant first. Panics if `base < 2` or any digit is not less
+    /// than `base`.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    ///
+    /// let z = Integer::from_digits(&[4, 3, 2, 1], 10);
+    /// assert_eq!(z, 1234);
+    ///
+    /// let z: Integer = "98765432109876543210".parse().unwrap();
+    /// assert_eq!(Integer::from_digits(&z.digits(3), 3), z);
+    /// ```
+    pub fn from_digits(digits: &[u64], base: u64) -> Integer {
+        assert!(base >= 2);
+        assert!(digits.iter().all(|&d| d < base), "Digit out of range.");
+        let (chunk_base, chunk_len) = digit_chunk(base);
+
+        let mut res = Integer::default();
+        let rem = digits.len() % chunk_len;
+        let mut hi = digits.len();
+        let mut first = true;
+        while hi > 0 {
+            let lo = if first && rem != 0 { hi - rem } else { hi - chunk_len };
+            let mut word = 0u64;
+            for &d in digits[lo..hi].iter().rev() {
+                word = word * base + d;
+            }
+            unsafe {
+                if first {
+                    fmpz::fmpz_set_ui(res.as_mut_ptr(), word);
+                } else {
+                    fmpz::fmpz_mul_ui(res.as_mut_ptr(), res.as_ptr(), chunk_base);
+                    fmpz::fmpz_add_ui(res.as_mut_ptr(), res.as_ptr(), word);
+                }
+            }
+            first = false;
+            hi = lo;
+        }
+        res
+    }
+
+    // Little-endian bytes of the absolute value, at least one byte.
+    fn abs_bytes_le(&self) -> Vec<u8> {
+        let mut res: Vec<u8> = self
+            .get_ui_vector()
+            .iter()
+            .flat_map(|limb| limb.to_le_bytes())
+            .collect();
+        while res.last() == Some(&0) {
+            res.pop();
+        }
+        if res.is_empty() {
+            res.push(0);
+        }
+        res
+    }
+
     // Comparison //
     
     /// Return true if the `Integer` is zero.
@@ -2150,25 +2843,20 @@ impl Integer {
     // bit_pack
     // bit_unpack
 
-    // Logic operations //
-
-    // complement
-    // clrbit
-    // combit
-    // popcnt
-
     // Chinese remaindering //
 
     // crt_ui
     // crt
     // multi_crt
 
+    */
+
     // Primality testing //
 
     /// Returns true if `self` is a prime.
     ///
     /// ```
-    /// use inertia_core::Integer;
+    /// use rufl::integer::Integer;
     ///
     /// let a = Integer::from(3);
     /// assert!(a.is_prime());
@@ -2178,9 +2866,51 @@ impl Integer {
     /// ```
     #[inline]
     pub fn is_prime(&self) -> bool {
+        if self < &2 {
+            return false;
+        }
         unsafe { fmpz::fmpz_is_prime(self.as_ptr()) == 1 }
     }
-   
+
+    // Factoring //
+
+    /// Return the prime factorization of the absolute value of `self` as a
+    /// list of `(prime, exponent)` pairs in increasing order of the primes.
+    /// Returns an empty list for 1 and -1. Panics if `self` is zero.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    ///
+    /// let z = Integer::from(-360);
+    /// let fac: Vec<(Integer, u64)> = z.factor();
+    /// assert_eq!(fac, vec![
+    ///     (Integer::from(2), 3),
+    ///     (Integer::from(3), 2),
+    ///     (Integer::from(5), 1)
+    /// ]);
+    /// ```
+    pub fn factor(&self) -> Vec<(Integer, u64)> {
+        assert!(!self.is_zero());
+        let mut fac = MaybeUninit::uninit();
+        unsafe {
+            fmpz_factor::fmpz_factor_init(fac.as_mut_ptr());
+            let mut fac = fac.assume_init();
+            fmpz_factor::fmpz_factor(&mut fac, self.as_ptr());
+
+            let mut res = Vec::with_capacity(fac.num as usize);
+            for i in 0..fac.num as usize {
+                let mut p = Integer::default();
+                fmpz::fmpz_set(p.as_mut_ptr(), fac.p.add(i));
+                res.push((p, *fac.exp.add(i)));
+            }
+            fmpz_factor::fmpz_factor_clear(&mut fac);
+
+            res.sort();
+            res
+        }
+    }
+
+    /*
     #[inline]
     pub fn reconstruct(&self, modulus: T) -> Rational
     where
@@ -2218,3 +2948,165 @@ impl Integer {
     // Special functions //
     */
 }
+
+/// An iterator over the bits of an [Integer], least significant first.
+/// Created by [Integer::iter_bits].
+#[derive(Clone, Debug)]
+pub struct IntegerBits<'a> {
+    x: &'a Integer,
+    front: u64,
+    back: u64,
+}
+
+impl Iterator for IntegerBits<'_> {
+    type Item = bool;
+
+    #[inline]
+    fn next(&mut self) -> Option<bool> {
+        if self.front < self.back {
+            let bit = self.x.testbit(self.front);
+            self.front += 1;
+            Some(bit)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = (self.back - self.front) as usize;
+        (n, Some(n))
+    }
+}
+
+impl DoubleEndedIterator for IntegerBits<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<bool> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(self.x.testbit(self.back))
+        } else {
+            None
+        }
+    }
+}
+
+impl ExactSizeIterator for IntegerBits<'_> {}
+
+// The largest power of `base` that fits in a word, and its exponent.
+fn digit_chunk(base: u64) -> (u64, usize) {
+    let mut chunk_base = base;
+    let mut chunk_len = 1;
+    while let Some(next) = chunk_base.checked_mul(base) {
+        chunk_base = next;
+        chunk_len += 1;
+    }
+    (chunk_base, chunk_len)
+}
+
+pub(crate) const F64_PREC: u64 = 53;
+pub(crate) const F64_EMIN: i64 = -1074;
+pub(crate) const F64_EMAX: i64 = 1024;
+pub(crate) const F32_PREC: u64 = 24;
+pub(crate) const F32_EMIN: i64 = -149;
+pub(crate) const F32_EMAX: i64 = 128;
+
+// Round `x / 2^k` to an integer in the direction `round`, where `x` is
+// nonnegative and `neg` is the sign of the value being rounded.
+fn round_shift(x: &Integer, k: u64, neg: bool, round: Round) -> Integer {
+    let mut m = Integer::default();
+    unsafe {
+        fmpz::fmpz_fdiv_q_2exp(m.as_mut_ptr(), x.as_ptr(), k);
+    }
+    if k == 0 {
+        return m;
+    }
+
+    let half = x.testbit(k - 1);
+    let sticky = x.trailing_zeros().map_or(false, |tz| tz < k - 1);
+    let up = match round {
+        Round::Nearest => half && (sticky || m.is_odd()),
+        Round::Floor => neg && (half || sticky),
+        Round::Ceil => !neg && (half || sticky),
+        Round::Zero => false,
+    };
+    if up {
+        m += 1u64;
+    }
+    m
+}
+
+// Round the nonnegative value `x * 2^exp` to a binary float with `prec` bits
+// of precision whose least significant bit is at least `2^emin`. Returns
+// `(m, e)` with `m < 2^prec + 1` such that the result is `m * 2^e`.
+pub(crate) fn round_float(
+    x: &Integer,
+    exp: i64,
+    neg: bool,
+    round: Round,
+    prec: u64,
+    emin: i64
+) -> (Integer, i64) {
+    let b = x.bits() as i64;
+    if b == 0 {
+        return (Integer::zero(), 0);
+    }
+    let ulp = std::cmp::max(exp.saturating_add(b - prec as i64), emin);
+    if ulp <= exp {
+        return (x.clone(), exp);
+    }
+    (round_shift(x, (ulp - exp) as u64, neg, round), ulp)
+}
+
+// Whether a value rounded in the direction `round` that overflows becomes
+// infinite rather than the largest finite value.
+fn overflows_to_infinity(neg: bool, round: Round) -> bool {
+    match round {
+        Round::Nearest => true,
+        Round::Floor => neg,
+        Round::Ceil => !neg,
+        Round::Zero => false,
+    }
+}
+
+// The exact power of two `2^e`, for `e` in the range of finite `f64` values.
+pub(crate) fn pow2_f64(e: i64) -> f64 {
+    if e < -1022 {
+        f64::from_bits(1u64 << (e + 1074))
+    } else {
+        f64::from_bits(((e + 1023) as u64) << 52)
+    }
+}
+
+// The exact power of two `2^e`, for `e` in the range of finite `f32` values.
+fn pow2_f32(e: i64) -> f32 {
+    if e < -126 {
+        f32::from_bits(1u32 << (e + 149))
+    } else {
+        f32::from_bits(((e + 127) as u32) << 23)
+    }
+}
+
+// Build the `f64` with magnitude `m * 2^e` from the output of `round_float`.
+pub(crate) fn compose_f64(m: &Integer, e: i64, neg: bool, round: Round) -> f64 {
+    let res = if m.is_zero() {
+        0.0
+    } else if m.bits() as i64 + e > F64_EMAX {
+        if overflows_to_infinity(neg, round) { f64::INFINITY } else { f64::MAX }
+    } else {
+        m.get_ui().unwrap() as f64 * pow2_f64(e)
+    };
+    if neg { -res } else { res }
+}
+
+// Build the `f32` with magnitude `m * 2^e` from the output of `round_float`.
+pub(crate) fn compose_f32(m: &Integer, e: i64, neg: bool, round: Round) -> f32 {
+    let res = if m.is_zero() {
+        0.0
+    } else if m.bits() as i64 + e > F32_EMAX {
+        if overflows_to_infinity(neg, round) { f32::INFINITY } else { f32::MAX }
+    } else {
+        m.get_ui().unwrap() as f32 * pow2_f32(e)
+    };
+    if neg { -res } else { res }
+}
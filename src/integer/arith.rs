@@ -0,0 +1,200 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Combinatorial number functions from FLINT's `arith` module.
+
+use crate::Integer;
+use flint_sys::{arith, flint, fmpz, fmpz_vec};
+
+// Convert an unsigned argument to a signed long for FLINT.
+#[inline]
+fn to_si(n: u64) -> i64 {
+    n.try_into().expect("Cannot convert input to a signed long.")
+}
+
+// Call `f` on a FLINT vector of length `len` and copy the result out.
+fn with_fmpz_vec<F>(len: usize, f: F) -> Vec<Integer>
+where
+    F: FnOnce(*mut flint::fmpz)
+{
+    let len_si = to_si(len as u64);
+    unsafe {
+        let v = fmpz_vec::_fmpz_vec_init(len_si);
+        f(v);
+        let res = (0..len).map(|i| {
+            let mut x = Integer::default();
+            fmpz::fmpz_set(x.as_mut_ptr(), v.add(i));
+            x
+        }).collect();
+        fmpz_vec::_fmpz_vec_clear(v, len_si);
+        res
+    }
+}
+
+impl Integer {
+    /// Return the Euler number `E_n`, the coefficient of `x^n / n!` in the
+    /// expansion of `sech(x)`.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    ///
+    /// let e: Vec<Integer> = (0..7u64).map(Integer::euler_number).collect();
+    /// assert_eq!(e, vec![1, 0, -1, 0, 5, 0, -61]);
+    /// ```
+    #[inline]
+    pub fn euler_number(n: u64) -> Integer {
+        let mut res = Integer::default();
+        unsafe { arith::arith_euler_number(res.as_mut_ptr(), n); }
+        res
+    }
+
+    /// Return the signed Stirling number of the first kind `s(n, k)`, so that
+    /// `x(x - 1)...(x - n + 1)` is the sum of `s(n, k) x^k`.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    ///
+    /// assert_eq!(Integer::stirling1(4, 2), 11);
+    /// assert_eq!(Integer::stirling1(5, 2), -50);
+    /// ```
+    #[inline]
+    pub fn stirling1(n: u64, k: u64) -> Integer {
+        let mut res = Integer::default();
+        unsafe { arith::arith_stirling_number_1(res.as_mut_ptr(), to_si(n), to_si(k)); }
+        res
+    }
+
+    /// Return the Stirling number of the second kind `S(n, k)`, the number of
+    /// partitions of an `n`-element set into `k` nonempty blocks.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    ///
+    /// assert_eq!(Integer::stirling2(5, 2), 15);
+    /// ```
+    #[inline]
+    pub fn stirling2(n: u64, k: u64) -> Integer {
+        let mut res = Integer::default();
+        unsafe { arith::arith_stirling_number_2(res.as_mut_ptr(), to_si(n), to_si(k)); }
+        res
+    }
+
+    /// Return the row `s(n, 0), ..., s(n, n)` of signed Stirling numbers of
+    /// the first kind.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    ///
+    /// assert_eq!(Integer::stirling1_row(3), vec![0, 2, -3, 1]);
+    /// ```
+    #[inline]
+    pub fn stirling1_row(n: u64) -> Vec<Integer> {
+        let len = n as usize + 1;
+        with_fmpz_vec(len, |v| unsafe {
+            arith::arith_stirling_number_1_vec(v, to_si(n), to_si(len as u64));
+        })
+    }
+
+    /// Return the row `S(n, 0), ..., S(n, n)` of Stirling numbers of the
+    /// second kind.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    ///
+    /// assert_eq!(Integer::stirling2_row(4), vec![0, 1, 7, 6, 1]);
+    /// ```
+    #[inline]
+    pub fn stirling2_row(n: u64) -> Vec<Integer> {
+        let len = n as usize + 1;
+        with_fmpz_vec(len, |v| unsafe {
+            arith::arith_stirling_number_2_vec(v, to_si(n), to_si(len as u64));
+        })
+    }
+
+    /// Return the Bell number `B_n`, the number of partitions of an
+    /// `n`-element set.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    ///
+    /// assert_eq!(Integer::bell(5), 52);
+    /// ```
+    #[inline]
+    pub fn bell(n: u64) -> Integer {
+        let mut res = Integer::default();
+        unsafe { arith::arith_bell_number(res.as_mut_ptr(), n); }
+        res
+    }
+
+    /// Return the Catalan number `C_n = binomial(2n, n)/(n + 1)`.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    ///
+    /// let c: Vec<Integer> = (0..6u64).map(Integer::catalan).collect();
+    /// assert_eq!(c, vec![1, 1, 2, 5, 14, 42]);
+    /// ```
+    #[inline]
+    pub fn catalan(n: u64) -> Integer {
+        let two_n = n.checked_mul(2).expect("Input is too large.");
+        let mut res = Integer::default();
+        unsafe {
+            fmpz::fmpz_bin_uiui(res.as_mut_ptr(), two_n, n);
+            fmpz::fmpz_divexact_ui(res.as_mut_ptr(), res.as_ptr(), n + 1);
+        }
+        res
+    }
+
+    /// Return the number of partitions `p(n)` of `n` into positive integers,
+    /// computed with the Hardy–Ramanujan–Rademacher formula.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    ///
+    /// assert_eq!(Integer::number_of_partitions(10), 42);
+    /// assert_eq!(Integer::number_of_partitions(100), 190569292);
+    /// ```
+    #[inline]
+    pub fn number_of_partitions(n: u64) -> Integer {
+        let mut res = Integer::default();
+        unsafe { arith::arith_number_of_partitions(res.as_mut_ptr(), n); }
+        res
+    }
+
+    /// Return the multinomial coefficient `(k_1 + ... + k_m)! / (k_1! ... k_m!)`.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    ///
+    /// assert_eq!(Integer::multinomial(&[2, 3, 4]), 1260);
+    /// assert_eq!(Integer::multinomial(&[]), 1);
+    /// ```
+    pub fn multinomial(k: &[u64]) -> Integer {
+        // The product of binomial(k_1 + ... + k_i, k_i).
+        let mut res = Integer::one();
+        let mut b = Integer::default();
+        let mut n = 0u64;
+        for &ki in k {
+            n = n.checked_add(ki).expect("Input is too large.");
+            unsafe {
+                fmpz::fmpz_bin_uiui(b.as_mut_ptr(), n, ki);
+                fmpz::fmpz_mul(res.as_mut_ptr(), res.as_ptr(), b.as_ptr());
+            }
+        }
+        res
+    }
+}
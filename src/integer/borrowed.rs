@@ -0,0 +1,127 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Borrowed references to integers stored inside other objects.
+
+use crate::Integer;
+use flint_sys::flint;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+
+// Storage for references to the implicit zero coefficients of polynomials.
+static ZERO: Integer = Integer { inner: 0 };
+
+/// A borrowed reference to an [Integer] stored inside another object, such
+/// as an entry of an [IntMat][crate::IntMat] or a coefficient of an
+/// [IntPoly][crate::IntPoly], obtained without copying.
+///
+/// An `IntegerRef` dereferences to [Integer], so any method taking `&self`
+/// can be called on it, and it can be passed wherever `AsRef<Integer>` is
+/// accepted. Use [Integer::from] or `clone` on the dereferenced value to get
+/// an owned copy.
+///
+/// ```
+/// use rufl::integer::Integer;
+/// use rufl::intmat::IntMat;
+///
+/// let m = IntMat::new([1, -2, 3, 4], 2, 2);
+/// let e = m.entry(0, 1);
+/// assert_eq!(e, -2);
+/// assert_eq!(e.abs(), 2);
+/// assert_eq!(Integer::from(e) * 2, -4);
+/// ```
+#[derive(Clone, Copy)]
+pub struct IntegerRef<'a> {
+    inner: &'a Integer,
+}
+
+impl<'a> IntegerRef<'a> {
+    /// Borrow the `fmpz` at `ptr` as an [Integer].
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to an initialized `fmpz` that is neither modified nor
+    /// freed for the lifetime `'a`.
+    #[inline]
+    pub unsafe fn from_ptr(ptr: *const flint::fmpz) -> Self {
+        IntegerRef { inner: &*(ptr as *const Integer) }
+    }
+
+    /// A reference to zero, for entries that are not stored explicitly.
+    #[inline]
+    pub fn zero() -> IntegerRef<'static> {
+        IntegerRef { inner: &ZERO }
+    }
+
+    /// Return the borrowed integer with the full lifetime `'a`.
+    #[inline]
+    pub fn as_integer(&self) -> &'a Integer {
+        self.inner
+    }
+}
+
+impl Deref for IntegerRef<'_> {
+    type Target = Integer;
+    #[inline]
+    fn deref(&self) -> &Integer {
+        self.inner
+    }
+}
+
+impl AsRef<Integer> for IntegerRef<'_> {
+    #[inline]
+    fn as_ref(&self) -> &Integer {
+        self.inner
+    }
+}
+
+impl fmt::Debug for IntegerRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.inner, f)
+    }
+}
+
+impl fmt::Display for IntegerRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self.inner, f)
+    }
+}
+
+impl Hash for IntegerRef<'_> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner.hash(state);
+    }
+}
+
+impl<T> PartialEq<T> for IntegerRef<'_>
+where
+    Integer: PartialEq<T>
+{
+    #[inline]
+    fn eq(&self, rhs: &T) -> bool {
+        self.inner == rhs
+    }
+}
+
+impl From<IntegerRef<'_>> for Integer {
+    #[inline]
+    fn from(src: IntegerRef<'_>) -> Integer {
+        src.inner.clone()
+    }
+}
@@ -15,41 +15,101 @@
  *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
-use crate::{Integer, Rational, IntMod};
+use crate::{Integer, Rational, IntMod, IntModWord};
+use crate::ops::NegAssign;
 use crate::{Error, Result};
-use crate::util::is_digit;
+use crate::util::digit_value;
 use flint_sys::fmpz;
 use std::ffi::CString;
 use std::str::FromStr;
 
 
-// FIXME: panics from negative sign...
-// FIXME: Valgrind sometimes complains about possibly lost bytes.
-// Probably false negative, how can we be sure?
+/// Parses a decimal integer, or a hexadecimal, octal or binary integer with a
+/// `0x`, `0o` or `0b` prefix. A leading `+` or `-` sign is accepted, and
+/// underscores may be used to separate digits.
+///
+/// ```
+/// use rufl::integer::Integer;
+///
+/// let z: Integer = "-1_000_000".parse().unwrap();
+/// assert_eq!(z, -1000000);
+///
+/// let z: Integer = "+0xFF".parse().unwrap();
+/// assert_eq!(z, 255);
+///
+/// let z: Integer = "-0b1010".parse().unwrap();
+/// assert_eq!(z, -10);
+///
+/// assert!("12a".parse::<Integer>().is_err());
+/// assert!("0x".parse::<Integer>().is_err());
+/// ```
 impl FromStr for Integer {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self> {
-        if !s.chars().all(is_digit) {
+        let (neg, rest) = split_sign(s);
+        let (base, digits) = match rest.get(..2) {
+            Some("0x") | Some("0X") => (16, &rest[2..]),
+            Some("0o") | Some("0O") => (8, &rest[2..]),
+            Some("0b") | Some("0B") => (2, &rest[2..]),
+            _ => (10, rest),
+        };
+        parse_digits(neg, digits, base)
+    }
+}
+
+// Parse an integer in base `base` with an optional sign and no prefix.
+pub(super) fn parse_radix(s: &str, base: u32) -> Result<Integer> {
+    let (neg, digits) = split_sign(s);
+    parse_digits(neg, digits, base)
+}
+
+fn split_sign(s: &str) -> (bool, &str) {
+    if let Some(rest) = s.strip_prefix('-') {
+        (true, rest)
+    } else if let Some(rest) = s.strip_prefix('+') {
+        (false, rest)
+    } else {
+        (false, s)
+    }
+}
+
+// FIXME: Valgrind sometimes complains about possibly lost bytes.
+// Probably false negative, how can we be sure?
+fn parse_digits(neg: bool, s: &str, base: u32) -> Result<Integer> {
+    // Underscores may only separate digits.
+    if s.starts_with('_') || s.ends_with('_') || s.contains("__") {
+        return Err(Error::Msg("Input is not an integer.".to_string()));
+    }
+
+    let mut digits = String::with_capacity(s.len());
+    for c in s.chars().filter(|&c| c != '_') {
+        if digit_value(c, base).is_none() {
             return Err(Error::Msg("Input is not an integer.".to_string()));
         }
+        digits.push(c);
+    }
+    if digits.is_empty() {
+        return Err(Error::Msg("Input is not an integer.".to_string()));
+    }
 
-        if let Ok(c_str) = CString::new(s) {
-            let mut z = Integer::default();
-            unsafe {
-                let res = fmpz::fmpz_set_str(
-                    z.as_mut_ptr(), 
-                    c_str.as_ptr(), 
-                    10
-                );
-                if res == 0 {
-                    Ok(z)
-                } else {
-                    Err(Error::Msg("Error in conversion.".to_string()))
-                }
+    if let Ok(c_str) = CString::new(digits) {
+        let mut z = Integer::default();
+        unsafe {
+            let res = fmpz::fmpz_set_str(
+                z.as_mut_ptr(), 
+                c_str.as_ptr(), 
+                base as i32
+            );
+            if res != 0 {
+                return Err(Error::Msg("Error in conversion.".to_string()));
             }
-        } else {
-            Err(Error::Msg("String contains 0 byte.".to_string()))
         }
+        if neg {
+            z.neg_assign();
+        }
+        Ok(z)
+    } else {
+        Err(Error::Msg("String contains 0 byte.".to_string()))
     }
 }
 
@@ -69,12 +129,45 @@ impl_from_unsafe! {
     fmpz::fmpz_set_si
 }
 
+impl_from! {
+    Integer, u128
+    {
+        fn from(src: &u128) -> Integer {
+            let mut res = Integer::default();
+            res.set_ui_vector(vec![*src as u64, (*src >> 64) as u64]);
+            res
+        }
+    }
+}
+
+impl_from! {
+    Integer, i128
+    {
+        fn from(src: &i128) -> Integer {
+            let mut res = Integer::from(src.unsigned_abs());
+            if *src < 0 {
+                res.neg_assign();
+            }
+            res
+        }
+    }
+}
+
 impl_from_unsafe! {
     None
     Integer, IntMod
     fmpz::fmpz_set
 }
 
+impl_from! {
+    Integer, IntModWord
+    {
+        fn from(src: &IntModWord) -> Integer {
+            Integer::from(src.get_ui())
+        }
+    }
+}
+
 ///////////////////////////////////////////////////////////////////
 // TryFrom
 ///////////////////////////////////////////////////////////////////
@@ -93,3 +186,34 @@ impl TryFrom<Rational> for Integer {
         }
     }
 }
+
+macro_rules! impl_tryfrom_integer {
+    ($get:ident {$($t:ident)+}) => ($(
+        impl TryFrom<&Integer> for $t {
+            type Error = Error;
+            #[inline]
+            fn try_from(src: &Integer) -> Result<Self> {
+                src.$get()
+                    .and_then(|x| <$t>::try_from(x).ok())
+                    .ok_or_else(|| Error::ConversionError {
+                        val: src.to_string(),
+                        in_type: "Integer".to_string(),
+                        out_type: stringify!($t).to_string(),
+                    })
+            }
+        }
+
+        impl TryFrom<Integer> for $t {
+            type Error = Error;
+            #[inline]
+            fn try_from(src: Integer) -> Result<Self> {
+                <$t>::try_from(&src)
+            }
+        }
+    )+);
+}
+
+impl_tryfrom_integer! { get_ui {usize u64 u32 u16 u8} }
+impl_tryfrom_integer! { get_si {isize i64 i32 i16 i8} }
+impl_tryfrom_integer! { get_u128 {u128} }
+impl_tryfrom_integer! { get_i128 {i128} }
@@ -0,0 +1,564 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! [num-traits](https://docs.rs/num-traits) implementations for [Integer],
+//! and the [num-integer](https://docs.rs/num-integer) traits for
+//! [FloorInteger].
+//!
+//! `Integer / Integer` is a [Rational], so `Num`, `Signed`, `CheckedDiv`
+//! and the `num-integer` traits are implemented for the wrapper
+//! [FloorInteger] instead, whose `/` and `%` round towards negative
+//! infinity.
+
+use crate::{Integer, Rational, Round};
+use flint_sys::fmpz;
+use num_integer::Roots;
+use num_traits::{
+    CheckedAdd,
+    CheckedDiv,
+    CheckedMul,
+    CheckedNeg,
+    CheckedRem,
+    CheckedSub,
+    FromPrimitive,
+    Inv,
+    Num,
+    One,
+    Pow,
+    Signed,
+    ToPrimitive,
+    Zero,
+};
+use std::fmt;
+use std::ops::{
+    Add,
+    AddAssign,
+    Div,
+    DivAssign,
+    Mul,
+    MulAssign,
+    Neg,
+    Rem,
+    RemAssign,
+    Sub,
+    SubAssign,
+};
+
+impl Zero for Integer {
+    #[inline]
+    fn zero() -> Self {
+        Integer::zero()
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        Integer::is_zero(self)
+    }
+}
+
+impl One for Integer {
+    #[inline]
+    fn one() -> Self {
+        Integer::one()
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        Integer::is_one(self)
+    }
+}
+
+macro_rules! impl_pow {
+    ($($t:ident)+) => ($(
+        impl Pow<$t> for Integer {
+            type Output = Integer;
+            #[inline]
+            fn pow(self, rhs: $t) -> Integer {
+                crate::ops::Pow::pow(self, rhs)
+            }
+        }
+
+        impl Pow<$t> for &Integer {
+            type Output = Integer;
+            #[inline]
+            fn pow(self, rhs: $t) -> Integer {
+                crate::ops::Pow::pow(self, rhs)
+            }
+        }
+    )+);
+}
+
+impl_pow! { u64 u32 u16 u8 }
+
+impl Inv for Integer {
+    type Output = Rational;
+    #[inline]
+    fn inv(self) -> Rational {
+        crate::ops::Inv::inv(self)
+    }
+}
+
+impl Inv for &Integer {
+    type Output = Rational;
+    #[inline]
+    fn inv(self) -> Rational {
+        crate::ops::Inv::inv(self)
+    }
+}
+
+impl CheckedAdd for Integer {
+    #[inline]
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(self + rhs)
+    }
+}
+
+impl CheckedSub for Integer {
+    #[inline]
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        Some(self - rhs)
+    }
+}
+
+impl CheckedMul for Integer {
+    #[inline]
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        Some(self * rhs)
+    }
+}
+
+impl CheckedRem for Integer {
+    #[inline]
+    fn checked_rem(&self, rhs: &Self) -> Option<Self> {
+        if rhs.is_zero() {
+            None
+        } else {
+            Some(self % rhs)
+        }
+    }
+}
+
+impl CheckedNeg for Integer {
+    #[inline]
+    fn checked_neg(&self) -> Option<Self> {
+        Some(-self)
+    }
+}
+
+impl ToPrimitive for Integer {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        self.get_si()
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        self.get_ui()
+    }
+
+    #[inline]
+    fn to_i128(&self) -> Option<i128> {
+        self.get_i128()
+    }
+
+    #[inline]
+    fn to_u128(&self) -> Option<u128> {
+        self.get_u128()
+    }
+
+    #[inline]
+    fn to_f32(&self) -> Option<f32> {
+        Some(Integer::to_f32(self, Round::Nearest))
+    }
+
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        Some(Integer::to_f64(self, Round::Nearest))
+    }
+}
+
+impl FromPrimitive for Integer {
+    #[inline]
+    fn from_i64(n: i64) -> Option<Self> {
+        Some(Integer::from(n))
+    }
+
+    #[inline]
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(Integer::from(n))
+    }
+
+    #[inline]
+    fn from_i128(n: i128) -> Option<Self> {
+        Some(Integer::from(n))
+    }
+
+    #[inline]
+    fn from_u128(n: u128) -> Option<Self> {
+        Some(Integer::from(n))
+    }
+
+    /// Converts a finite float to an `Integer`, truncating towards zero.
+    #[inline]
+    fn from_f64(n: f64) -> Option<Self> {
+        let q = Rational::from_f64(n)?;
+        let mut res = Integer::default();
+        unsafe {
+            let q = q.as_ptr();
+            fmpz::fmpz_tdiv_q(res.as_mut_ptr(), &(*q).num, &(*q).den);
+        }
+        Some(res)
+    }
+}
+
+/// An [Integer] whose `/` and `%` are floor division and the floored
+/// remainder, so that it can implement `Num`, `Signed`, `CheckedDiv` and the
+/// `num-integer` traits `Integer` and `Roots`, which all need `/` to return
+/// an integer. `num_integer::Integer::div_rem` is still the truncated
+/// division, as that trait requires.
+///
+/// ```
+/// use num_integer::{Integer as _, Roots};
+/// use rufl::integer::{FloorInteger, Integer};
+///
+/// let a = FloorInteger(Integer::from(-7));
+/// let b = FloorInteger(Integer::from(2));
+/// assert_eq!(&a / &b, FloorInteger(Integer::from(-4)));
+/// assert_eq!(&a % &b, FloorInteger(Integer::from(1)));
+/// assert_eq!(a.div_rem(&b), (FloorInteger(Integer::from(-3)), FloorInteger(Integer::from(-1))));
+/// assert_eq!(FloorInteger(Integer::from(-1001)).cbrt(), FloorInteger(Integer::from(-10)));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FloorInteger(pub Integer);
+
+impl AsRef<Integer> for FloorInteger {
+    #[inline]
+    fn as_ref(&self) -> &Integer {
+        &self.0
+    }
+}
+
+impl From<Integer> for FloorInteger {
+    #[inline]
+    fn from(x: Integer) -> FloorInteger {
+        FloorInteger(x)
+    }
+}
+
+impl From<FloorInteger> for Integer {
+    #[inline]
+    fn from(x: FloorInteger) -> Integer {
+        x.0
+    }
+}
+
+impl fmt::Display for FloorInteger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+macro_rules! impl_floor_binop {
+    ($(
+        $op:ident {$meth:ident}
+        $op_assign:ident {$meth_assign:ident}
+        |$a:ident, $b:ident| $e:expr;
+    )+) => ($(
+        impl $op<&FloorInteger> for &FloorInteger {
+            type Output = FloorInteger;
+            #[inline]
+            fn $meth(self, rhs: &FloorInteger) -> FloorInteger {
+                let ($a, $b) = (&self.0, &rhs.0);
+                FloorInteger($e)
+            }
+        }
+
+        impl $op<FloorInteger> for &FloorInteger {
+            type Output = FloorInteger;
+            #[inline]
+            fn $meth(self, rhs: FloorInteger) -> FloorInteger {
+                self.$meth(&rhs)
+            }
+        }
+
+        impl $op<&FloorInteger> for FloorInteger {
+            type Output = FloorInteger;
+            #[inline]
+            fn $meth(self, rhs: &FloorInteger) -> FloorInteger {
+                (&self).$meth(rhs)
+            }
+        }
+
+        impl $op<FloorInteger> for FloorInteger {
+            type Output = FloorInteger;
+            #[inline]
+            fn $meth(self, rhs: FloorInteger) -> FloorInteger {
+                (&self).$meth(&rhs)
+            }
+        }
+
+        impl $op_assign<&FloorInteger> for FloorInteger {
+            #[inline]
+            fn $meth_assign(&mut self, rhs: &FloorInteger) {
+                *self = (&*self).$meth(rhs);
+            }
+        }
+
+        impl $op_assign<FloorInteger> for FloorInteger {
+            #[inline]
+            fn $meth_assign(&mut self, rhs: FloorInteger) {
+                *self = (&*self).$meth(&rhs);
+            }
+        }
+    )+);
+}
+
+impl_floor_binop! {
+    Add {add} AddAssign {add_assign} |a, b| a + b;
+    Sub {sub} SubAssign {sub_assign} |a, b| a - b;
+    Mul {mul} MulAssign {mul_assign} |a, b| a * b;
+    Div {div} DivAssign {div_assign} |a, b| a.fdiv_q(b);
+    Rem {rem} RemAssign {rem_assign} |a, b| a.fdiv_r(b);
+}
+
+impl Neg for FloorInteger {
+    type Output = FloorInteger;
+    #[inline]
+    fn neg(self) -> FloorInteger {
+        FloorInteger(-self.0)
+    }
+}
+
+impl Neg for &FloorInteger {
+    type Output = FloorInteger;
+    #[inline]
+    fn neg(self) -> FloorInteger {
+        FloorInteger(-&self.0)
+    }
+}
+
+impl Zero for FloorInteger {
+    #[inline]
+    fn zero() -> Self {
+        FloorInteger(Integer::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl One for FloorInteger {
+    #[inline]
+    fn one() -> Self {
+        FloorInteger(Integer::one())
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        self.0.is_one()
+    }
+}
+
+impl Num for FloorInteger {
+    type FromStrRadixErr = crate::Error;
+
+    #[inline]
+    fn from_str_radix(s: &str, radix: u32) -> crate::Result<Self> {
+        Integer::from_str_radix(s, radix).map(FloorInteger)
+    }
+}
+
+impl Signed for FloorInteger {
+    #[inline]
+    fn abs(&self) -> Self {
+        FloorInteger(self.0.abs())
+    }
+
+    #[inline]
+    fn abs_sub(&self, other: &Self) -> Self {
+        if self <= other {
+            FloorInteger::zero()
+        } else {
+            self - other
+        }
+    }
+
+    #[inline]
+    fn signum(&self) -> Self {
+        FloorInteger(Integer::from(self.0.sign()))
+    }
+
+    #[inline]
+    fn is_positive(&self) -> bool {
+        self.0.sign() > 0
+    }
+
+    #[inline]
+    fn is_negative(&self) -> bool {
+        self.0.sign() < 0
+    }
+}
+
+impl CheckedDiv for FloorInteger {
+    #[inline]
+    fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        if rhs.is_zero() {
+            None
+        } else {
+            Some(self / rhs)
+        }
+    }
+}
+
+impl num_integer::Integer for FloorInteger {
+    #[inline]
+    fn div_floor(&self, other: &Self) -> Self {
+        self / other
+    }
+
+    #[inline]
+    fn mod_floor(&self, other: &Self) -> Self {
+        self % other
+    }
+
+    #[inline]
+    fn gcd(&self, other: &Self) -> Self {
+        FloorInteger(self.0.gcd(&other.0))
+    }
+
+    #[inline]
+    fn lcm(&self, other: &Self) -> Self {
+        FloorInteger(self.0.lcm(&other.0))
+    }
+
+    #[inline]
+    fn is_multiple_of(&self, other: &Self) -> bool {
+        if other.is_zero() {
+            self.is_zero()
+        } else {
+            self.0.divisible(&other.0)
+        }
+    }
+
+    #[inline]
+    fn is_even(&self) -> bool {
+        self.0.is_even()
+    }
+
+    #[inline]
+    fn is_odd(&self) -> bool {
+        self.0.is_odd()
+    }
+
+    /// Return the truncated quotient and remainder, as for the primitive
+    /// integers.
+    #[inline]
+    fn div_rem(&self, other: &Self) -> (Self, Self) {
+        let (q, r) = self.0.tdiv_qr(&other.0);
+        (FloorInteger(q), FloorInteger(r))
+    }
+}
+
+impl Roots for FloorInteger {
+    /// Return the `n`-th root truncated towards zero. Panics if `n` is zero
+    /// or if `n` is even and `self` is negative.
+    #[inline]
+    fn nth_root(&self, n: u32) -> Self {
+        assert!(n > 0, "Root of degree zero.");
+        FloorInteger(self.0.root(n))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{FloorInteger, Integer, Rational};
+    use num_integer::Roots;
+    use num_traits::{
+        CheckedDiv,
+        CheckedRem,
+        FromPrimitive,
+        Inv,
+        Num,
+        One,
+        Pow,
+        Signed,
+        ToPrimitive,
+        Zero,
+    };
+
+    fn sum_of_powers<T: Zero + One + Clone + Pow<u32, Output = T>>(
+        xs: &[T],
+        k: u32
+    ) -> T {
+        xs.iter().fold(T::zero(), |acc, x| acc + x.clone().pow(k))
+    }
+
+    #[test]
+    fn generic() {
+        let xs: Vec<Integer> = (1..=4).map(Integer::from).collect();
+        assert_eq!(sum_of_powers(&xs, 3), 100);
+        assert!(<Integer as One>::one().is_one());
+
+        let x = Integer::from(7);
+        assert_eq!(x.checked_rem(&Integer::from(0)), None);
+        assert_eq!(x.checked_rem(&Integer::from(4)), Some(Integer::from(3)));
+
+        assert_eq!(Integer::from_f64(-2.75), Some(Integer::from(-2)));
+        assert_eq!(Integer::from_f64(f64::NAN), None);
+        assert_eq!(Integer::from(u128::MAX).to_u128(), Some(u128::MAX));
+        assert_eq!(Integer::from(3).inv(), Rational::from([1, 3]));
+    }
+
+    // Binary gcd, written only against the `num-integer` traits.
+    fn binary_gcd<T: num_integer::Integer + Signed + Clone>(a: &T, b: &T) -> T {
+        let two = T::one() + T::one();
+        let (mut a, mut b) = (a.abs(), b.abs());
+        let mut shift = T::one();
+        while !a.is_zero() && !b.is_zero() && a.is_even() && b.is_even() {
+            a = a / two.clone();
+            b = b / two.clone();
+            shift = shift * two.clone();
+        }
+        while !b.is_zero() {
+            let r = a.mod_floor(&b);
+            a = b;
+            b = r;
+        }
+        a * shift
+    }
+
+    #[test]
+    fn floor_integer() {
+        let f = |x: i64| FloorInteger(Integer::from(x));
+        for (a, b) in [(7, 2), (-7, 2), (7, -2), (-7, -2), (6, 3), (-6, 4)] {
+            assert_eq!(&f(a) / &f(b), f(num_integer::Integer::div_floor(&a, &b)));
+            assert_eq!(&f(a) % &f(b), f(num_integer::Integer::mod_floor(&a, &b)));
+            assert_eq!(num_integer::Integer::div_rem(&f(a), &f(b)), (f(a / b), f(a % b)));
+            assert_eq!(binary_gcd(&f(a), &f(b)), f(binary_gcd(&a, &b)));
+        }
+        assert_eq!(f(7).checked_div(&f(0)), None);
+        assert_eq!(f(-9).signum(), f(-1));
+        assert_eq!(f(-9).abs_sub(&f(-12)), f(3));
+        assert_eq!(FloorInteger::from_str_radix("-ff", 16).unwrap(), f(-255));
+        assert_eq!(f(1 << 40).sqrt(), f(1 << 20));
+        assert_eq!(f(-30).nth_root(3), f(-3));
+        assert!(num_integer::Integer::is_multiple_of(&f(0), &f(0)));
+        assert!(!num_integer::Integer::is_multiple_of(&f(3), &f(0)));
+    }
+}
@@ -207,6 +207,22 @@ impl_binop_unsafe! {
     fmpz::fmpz_pow_ui;
 }
 
+impl_binop_unsafe! {
+    None
+    op_assign
+    Integer, u64 {u64 u32 u16 u8 usize}, Integer
+
+    Shl {shl}
+    ShlAssign {shl_assign}
+    AssignShl {assign_shl}
+    fmpz::fmpz_mul_2exp;
+
+    Shr {shr}
+    ShrAssign {shr_assign}
+    AssignShr {assign_shr}
+    fmpz::fmpz_fdiv_q_2exp;
+}
+
 impl_binop_unsafe! {
     None
     Integer, u64 {u64 u32 u16 u8}, Rational
@@ -18,10 +18,15 @@
 mod ops;
 mod extras;
 mod conv;
+mod io;
+mod borrowed;
+mod index;
 
 #[cfg(feature = "serde")]
 mod serde;
 
+pub use borrowed::IntMatWindow;
+
 use crate::*;
 use flint_sys::fmpz_types::fmpz_mat_struct;
 use flint_sys::fmpz::*;
@@ -32,7 +37,10 @@ use std::hash::{Hash, Hasher};
 use std::mem::{MaybeUninit, ManuallyDrop};
 
 
+// Transparent so that windows, which share entries with their parent, can
+// be borrowed as an `IntMat`.
 #[derive(Debug)]
+#[repr(transparent)]
 pub struct IntMat {
     inner: fmpz_mat_struct,
 }
@@ -87,11 +95,14 @@ impl Drop for IntMat {
     }
 }
 
-// TODO: make entries method that borrows so we dont need to copy entries
 impl Hash for IntMat {
     #[inline]
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.get_entries().hash(state);
+        self.nrows().hash(state);
+        self.ncols().hash(state);
+        for e in self.iter_entries() {
+            e.hash(state);
+        }
     }
 }
 
@@ -273,6 +284,44 @@ impl IntMat {
         }
         res
     }
+
+    /// Return the `nrows` by `ncols` matrix of signed Stirling numbers of the
+    /// first kind, with entry `(n, k)` equal to `s(n, k)`.
+    ///
+    /// ```
+    /// use rufl::intmat::IntMat;
+    ///
+    /// let s = IntMat::stirling1(5, 5);
+    /// assert_eq!(s.get_entry(4, 2), 11);
+    /// assert_eq!(s.get_entry(4, 3), -6);
+    /// ```
+    #[inline]
+    pub fn stirling1(nrows: i64, ncols: i64) -> IntMat {
+        let mut res = IntMat::zero(nrows, ncols);
+        unsafe {
+            flint_sys::arith::arith_stirling_matrix_1(res.as_mut_ptr());
+        }
+        res
+    }
+
+    /// Return the `nrows` by `ncols` matrix of Stirling numbers of the second
+    /// kind, with entry `(n, k)` equal to `S(n, k)`.
+    ///
+    /// ```
+    /// use rufl::intmat::IntMat;
+    ///
+    /// let s = IntMat::stirling2(5, 5);
+    /// assert_eq!(s.get_entry(4, 2), 7);
+    /// ```
+    #[inline]
+    pub fn stirling2(nrows: i64, ncols: i64) -> IntMat {
+        let mut res = IntMat::zero(nrows, ncols);
+        unsafe {
+            flint_sys::arith::arith_stirling_matrix_2(res.as_mut_ptr());
+        }
+        res
+    }
+
     /// Set `self` to the zero matrix.
     #[inline]
     pub fn zero_assign(&mut self) {
@@ -531,35 +580,14 @@ impl IntMat {
         res
     }
    
-    // TODO: 'window' version to avoid allocation
     /// Return a new matrix containing the `r2 - r1` by `c2 - c1` submatrix of 
     /// an integer matrix whose `(0, 0)` entry is the `(r1, c1)` entry of the input.
+    /// See [IntMat::window] for a view that does not copy the entries.
     pub fn submatrix(&self, r1: usize, c1: usize, r2: usize, c2: usize) -> IntMat {
         if r1 == r2 || c1 == c2 {
             return IntMat::zero(0, 0)
         }
-        
-        assert!(r1 <= r2);
-        assert!(c1 <= c2);
-        let (r1, c1) = self.check_indices(r1, c1);
-        let (r2, c2) = self.check_indices(r2, c2);
-
-        let mut res = IntMat::zero(r2 - r1, c2 - c1);
-        let mut win = MaybeUninit::uninit();
-        unsafe {
-            fmpz_mat_window_init(
-                win.as_mut_ptr(), 
-                self.as_ptr(),
-                r1,
-                c1,
-                r2,
-                c2
-            );
-            fmpz_mat_set(res.as_mut_ptr(), win.as_ptr());
-            fmpz_mat_window_clear(win.as_mut_ptr());
-        }
-        res
-
+        IntMat::clone(&self.window(r1, c1, r2, c2))
     }
     
     /// Return row `i` as an integer matrix.
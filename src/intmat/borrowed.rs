@@ -0,0 +1,167 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Borrowed views of integer matrices: entry references, windows and
+//! iterators that do not copy entries.
+
+use crate::{IntMat, IntegerRef};
+use flint_sys::fmpz_mat;
+use flint_sys::fmpz_types::fmpz_mat_struct;
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::ops::Deref;
+
+/// A read-only view of a block of an [IntMat], created by [IntMat::window]
+/// without copying any entries.
+///
+/// A window dereferences to [IntMat], so any method taking `&self` can be
+/// used on it, including arithmetic by reference and further windows. Use
+/// `clone` on the dereferenced matrix to get an owned copy.
+///
+/// ```
+/// use rufl::intmat::IntMat;
+///
+/// let m = IntMat::new([1, 2, 3, 4, 5, 6, 7, 8, 9], 3, 3);
+/// let w = m.window(1, 1, 3, 3);
+/// assert_eq!(w.nrows(), 2);
+/// assert_eq!(w.entry(0, 0), 5);
+/// assert_eq!(*w, IntMat::new([5, 6, 8, 9], 2, 2));
+/// assert_eq!(w.det(), 5*9 - 6*8);
+/// ```
+pub struct IntMatWindow<'a> {
+    inner: fmpz_mat_struct,
+    phantom: PhantomData<&'a IntMat>,
+}
+
+impl Deref for IntMatWindow<'_> {
+    type Target = IntMat;
+    #[inline]
+    fn deref(&self) -> &IntMat {
+        // `IntMat` is a transparent wrapper of `fmpz_mat_struct`. The window
+        // shares the entries of its parent and is only ever freed by
+        // `fmpz_mat_window_clear`.
+        unsafe { &*(&self.inner as *const fmpz_mat_struct as *const IntMat) }
+    }
+}
+
+impl Drop for IntMatWindow<'_> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { fmpz_mat::fmpz_mat_window_clear(&mut self.inner) }
+    }
+}
+
+impl fmt::Debug for IntMatWindow<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl fmt::Display for IntMatWindow<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
+    }
+}
+
+impl IntMat {
+    /// Return a reference to the `(i, j)`-th entry without copying it.
+    ///
+    /// ```
+    /// use rufl::intmat::IntMat;
+    ///
+    /// let m = IntMat::new([1, 2, 3, 4], 2, 2);
+    /// assert_eq!(m.entry(1, 0), 3);
+    /// assert!(m.entry(0, 0).is_one());
+    /// ```
+    #[inline]
+    pub fn entry(&self, i: usize, j: usize) -> IntegerRef<'_> {
+        let (i, j) = self.check_indices(i, j);
+        unsafe { IntegerRef::from_ptr(fmpz_mat::fmpz_mat_entry(self.as_ptr(), i, j)) }
+    }
+
+    /// Return a view of the `r2 - r1` by `c2 - c1` block whose `(0, 0)`
+    /// entry is the `(r1, c1)` entry of the matrix, without copying.
+    pub fn window(&self, r1: usize, c1: usize, r2: usize, c2: usize) -> IntMatWindow<'_> {
+        assert!(r1 <= r2 && r2 <= self.nrows());
+        assert!(c1 <= c2 && c2 <= self.ncols());
+        let mut win = MaybeUninit::uninit();
+        unsafe {
+            fmpz_mat::fmpz_mat_window_init(
+                win.as_mut_ptr(),
+                self.as_ptr(),
+                r1 as i64,
+                c1 as i64,
+                r2 as i64,
+                c2 as i64
+            );
+            IntMatWindow { inner: win.assume_init(), phantom: PhantomData }
+        }
+    }
+
+    /// Return an iterator over references to the entries in row-major order.
+    ///
+    /// ```
+    /// use rufl::intmat::IntMat;
+    ///
+    /// let m = IntMat::new([1, -2, 3, 4], 2, 2);
+    /// let sum: i64 = m.iter_entries().map(|e| e.get_si().unwrap()).sum();
+    /// assert_eq!(sum, 6);
+    /// ```
+    pub fn iter_entries(&self) -> impl Iterator<Item = IntegerRef<'_>> + '_ {
+        (0..self.nrows()).flat_map(move |i| self.iter_row(i))
+    }
+
+    /// Return an iterator over references to the entries of row `i`.
+    ///
+    /// ```
+    /// use rufl::intmat::IntMat;
+    ///
+    /// let m = IntMat::new([1, 2, 3, 4, 5, 6], 2, 3);
+    /// assert!(m.iter_row(1).eq([4, 5, 6]));
+    /// assert!(m.iter_column(2).rev().eq([6, 3]));
+    /// ```
+    pub fn iter_row(&self, i: usize)
+        -> impl ExactSizeIterator<Item = IntegerRef<'_>> + DoubleEndedIterator + '_
+    {
+        assert!(i < self.nrows());
+        (0..self.ncols()).map(move |j| self.entry(i, j))
+    }
+
+    /// Return an iterator over references to the entries of column `j`.
+    pub fn iter_column(&self, j: usize)
+        -> impl ExactSizeIterator<Item = IntegerRef<'_>> + DoubleEndedIterator + '_
+    {
+        assert!(j < self.ncols());
+        (0..self.nrows()).map(move |i| self.entry(i, j))
+    }
+
+    /// Return an iterator over the rows as one-row windows.
+    ///
+    /// ```
+    /// use rufl::intmat::IntMat;
+    ///
+    /// let m = IntMat::new([1, 2, 0, 0, 3, 4], 3, 2);
+    /// let nonzero = m.iter_rows().filter(|row| !row.is_zero()).count();
+    /// assert_eq!(nonzero, 2);
+    /// ```
+    pub fn iter_rows(&self)
+        -> impl ExactSizeIterator<Item = IntMatWindow<'_>> + DoubleEndedIterator + '_
+    {
+        (0..self.nrows()).map(move |i| self.window(i, 0, i + 1, self.ncols()))
+    }
+}
@@ -15,10 +15,21 @@
  *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
-//use crate::*;
-//use flint_sys::fmpz_mat;
+use crate::{IntMat, IntModWordMat};
+use flint_sys::fmpz_mat;
 //use std::mem::MaybeUninit;
 
+impl_from! {
+    IntMat, IntModWordMat
+    {
+        fn from(x: &IntModWordMat) -> IntMat {
+            let mut res = IntMat::zero(x.nrows_si(), x.ncols_si());
+            unsafe { fmpz_mat::fmpz_mat_set_nmod_mat_unsigned(res.as_mut_ptr(), x.as_ptr()); }
+            res
+        }
+    }
+}
+
 
 /*
 impl_from! {
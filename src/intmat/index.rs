@@ -0,0 +1,63 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Indexing of integer matrices by `(row, column)` pairs.
+
+use crate::{IntMat, Integer};
+use flint_sys::fmpz_mat;
+use std::ops::{Index, IndexMut};
+
+/// Read an entry by reference, without copying it.
+///
+/// Panics if either index is out of bounds.
+///
+/// ```
+/// use rufl::intmat::IntMat;
+///
+/// let m = IntMat::new([1, 2, 3, 4], 2, 2);
+/// assert_eq!(m[(1, 0)], 3);
+/// ```
+impl Index<(usize, usize)> for IntMat {
+    type Output = Integer;
+    #[inline]
+    fn index(&self, (i, j): (usize, usize)) -> &Integer {
+        self.entry(i, j).as_integer()
+    }
+}
+
+/// Modify an entry in place.
+///
+/// Panics if either index is out of bounds.
+///
+/// ```
+/// use rufl::intmat::IntMat;
+/// use rufl::integer::Integer;
+///
+/// let mut m = IntMat::new([1, 2, 3, 4], 2, 2);
+/// m[(0, 1)] += 5;
+/// m[(1, 0)] = Integer::from(-3);
+/// assert_eq!(m, IntMat::new([1, 7, -3, 4], 2, 2));
+/// ```
+impl IndexMut<(usize, usize)> for IntMat {
+    #[inline]
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut Integer {
+        let (i, j) = self.check_indices(i, j);
+        // `Integer` is a transparent wrapper of `fmpz`, so the entry can be
+        // modified in place by any `Integer` method.
+        unsafe { &mut *(fmpz_mat::fmpz_mat_entry(self.as_mut_ptr(), i, j) as *mut Integer) }
+    }
+}
@@ -0,0 +1,98 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Reading and writing integer matrices in the formats of [MatrixFormat].
+
+use crate::{Error, IntMat, Integer, Result};
+use crate::matio::{self, MatrixFormat};
+use crate::ops::NegAssign;
+use std::io::{self, Read, Write};
+use std::str::FromStr;
+
+impl IntMat {
+    /// Write the matrix to `w` in the format `fmt`.
+    pub fn write_format<W: Write>(&self, w: W, fmt: MatrixFormat) -> io::Result<()> {
+        matio::write_matrix(w, fmt, &matio::INTEGERS, self.nrows(), self.ncols(), |i, j| {
+            self.get_entry(i, j).to_string()
+        })
+    }
+
+    /// Return the matrix as a string in the format `fmt`.
+    pub fn to_str_format(&self, fmt: MatrixFormat) -> String {
+        let mut buf = vec![];
+        self.write_format(&mut buf, fmt).expect("Writing to a buffer cannot fail.");
+        String::from_utf8(buf).expect("Matrix output is valid UTF-8.")
+    }
+
+    /// Read a matrix in the format `fmt` from `r`. Errors in the input are
+    /// reported with the line they occur on.
+    ///
+    /// ```
+    /// use rufl::intmat::IntMat;
+    /// use rufl::matio::MatrixFormat;
+    ///
+    /// let src = "\
+    /// %%MatrixMarket matrix coordinate integer symmetric
+    /// % A comment.
+    /// 3 3 2
+    /// 2 1 5
+    /// 3 3 -1
+    /// ";
+    /// let m = IntMat::read_format(src.as_bytes(), MatrixFormat::MatrixMarketCoordinate).unwrap();
+    /// assert_eq!(m, IntMat::new([0, 5, 0, 5, 0, 0, 0, 0, -1], 3, 3));
+    ///
+    /// let err = IntMat::read_format("1 2\n3 x\n".as_bytes(), MatrixFormat::Text).unwrap_err();
+    /// assert!(err.to_string().starts_with("Line 2:"));
+    ///
+    /// // Dense matrices are limited to `MAX_DENSE_ENTRIES` entries.
+    /// let src = "%%MatrixMarket matrix coordinate integer general\n100000000 100000000 0\n";
+    /// let err = IntMat::read_format(src.as_bytes(), MatrixFormat::MatrixMarketCoordinate).unwrap_err();
+    /// assert_eq!(err.to_string(), "Line 2: Matrix dimensions are too large.");
+    ///
+    /// let src = "%%MatrixMarket matrix coordinate integer general\n2 2 2\n1 1 5\n";
+    /// let err = IntMat::read_format(src.as_bytes(), MatrixFormat::MatrixMarketCoordinate).unwrap_err();
+    /// assert_eq!(err.to_string(), "Line 3: Expected 2 entries, found 1.");
+    ///
+    /// let huge = "matrix(ZZ, 9223372036854775808, 2, [])";
+    /// assert!(IntMat::from_str_format(huge, MatrixFormat::Sage).is_err());
+    /// ```
+    pub fn read_format<R: Read>(r: R, fmt: MatrixFormat) -> Result<IntMat> {
+        let parsed = matio::read_matrix(r, fmt)?;
+        if parsed.real {
+            return Err(Error::Msg(
+                "Cannot read real Matrix Market data into an integer matrix.".to_string()
+            ));
+        }
+
+        let (nrows, ncols) = parsed.dense_dims()?;
+        let mut res = IntMat::zero(nrows, ncols);
+        for e in parsed.entries {
+            let mut x = Integer::from_str(&e.val).map_err(|err| matio::line_error(e.line, err))?;
+            if e.neg {
+                x.neg_assign();
+            }
+            res.set_entry(e.i, e.j, x);
+        }
+        Ok(res)
+    }
+
+    /// Read a matrix in the format `fmt` from a string, as for
+    /// [IntMat::read_format].
+    pub fn from_str_format(s: &str, fmt: MatrixFormat) -> Result<IntMat> {
+        IntMat::read_format(s.as_bytes(), fmt)
+    }
+}
@@ -18,6 +18,11 @@
 mod ops;
 mod conv;
 mod extras;
+mod group;
+mod roots;
+
+#[cfg(feature = "num-traits")]
+mod num;
 
 //#[cfg(feature = "serde")]
 //mod serde;
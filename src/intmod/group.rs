@@ -0,0 +1,348 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! The structure of the unit group of the integers modulo `n`.
+
+use crate::{IntMod, IntModCtx, Integer};
+use crate::ops::{Inv, Pow};
+use flint_sys::fmpz;
+use std::collections::{BTreeMap, HashMap};
+
+// A positive integer as a map from its prime divisors to their exponents.
+type Factored = BTreeMap<Integer, u64>;
+
+fn expand(f: &Factored) -> Integer {
+    let mut res = Integer::one();
+    for (p, e) in f {
+        res *= p.pow(*e);
+    }
+    res
+}
+
+pub(super) fn gcd(a: &Integer, b: &Integer) -> Integer {
+    let mut res = Integer::default();
+    unsafe { fmpz::fmpz_gcd(res.as_mut_ptr(), a.as_ptr(), b.as_ptr()); }
+    res
+}
+
+pub(super) fn divexact(a: &Integer, b: &Integer) -> Integer {
+    let mut res = Integer::default();
+    unsafe { fmpz::fmpz_divexact(res.as_mut_ptr(), a.as_ptr(), b.as_ptr()); }
+    res
+}
+
+// The unique `x` in `[0, m1*m2)` congruent to `a1` mod `m1` and `a2` mod `m2`,
+// where `m1` and `m2` are coprime.
+pub(super) fn crt(a1: &Integer, m1: &Integer, a2: &Integer, m2: &Integer) -> Integer {
+    let inv = m1.invmod(m2).expect("Moduli must be coprime.");
+    let mut t = (a2 - a1) * inv;
+    unsafe { fmpz::fmpz_mod(t.as_mut_ptr(), t.as_ptr(), m2.as_ptr()); }
+    a1 + m1 * t
+}
+
+impl IntModCtx {
+    // The orders of the cyclic groups `(Z/p^e)^*` (or their two factors when
+    // `p = 2`) whose product is the unit group, omitting trivial factors.
+    fn unit_group_components(&self) -> Vec<Factored> {
+        let mut res = vec![];
+        for (p, e) in self.modulus().factor() {
+            if p == 2 {
+                if e >= 2 {
+                    res.push(Factored::from([(Integer::from(2), 1)]));
+                }
+                if e >= 3 {
+                    res.push(Factored::from([(Integer::from(2), e - 2)]));
+                }
+            } else {
+                let mut f: Factored = (&p - 1u64).factor().into_iter().collect();
+                if e > 1 {
+                    *f.entry(p).or_insert(0) += e - 1;
+                }
+                res.push(f);
+            }
+        }
+        res
+    }
+
+    // The exponent of the unit group (the Carmichael function of `n`), in
+    // factored form.
+    fn unit_group_exponent(&self) -> Factored {
+        let mut res = Factored::new();
+        for f in self.unit_group_components() {
+            for (q, e) in f {
+                let x = res.entry(q).or_insert(0);
+                *x = std::cmp::max(*x, e);
+            }
+        }
+        res
+    }
+
+    /// Return the invariant factors `d_1 | d_2 | ... | d_k` of the unit group
+    /// of the integers modulo `n`, so that the group is isomorphic to the
+    /// product of the cyclic groups of orders `d_i`. The list is empty if the
+    /// group is trivial.
+    ///
+    /// ```
+    /// use rufl::intmod::IntModCtx;
+    ///
+    /// let ctx = IntModCtx::new(7);
+    /// assert_eq!(ctx.unit_group_structure(), vec![6]);
+    ///
+    /// let ctx = IntModCtx::new(8);
+    /// assert_eq!(ctx.unit_group_structure(), vec![2, 2]);
+    ///
+    /// let ctx = IntModCtx::new(15);
+    /// assert_eq!(ctx.unit_group_structure(), vec![2, 4]);
+    ///
+    /// let ctx = IntModCtx::new(2);
+    /// assert!(ctx.unit_group_structure().is_empty());
+    /// ```
+    pub fn unit_group_structure(&self) -> Vec<Integer> {
+        // Collect the prime power parts of each component by prime, largest
+        // first. The i-th largest invariant factor is the product of the
+        // i-th largest prime power for each prime.
+        let mut parts: BTreeMap<Integer, Vec<u64>> = BTreeMap::new();
+        for f in self.unit_group_components() {
+            for (q, e) in f {
+                parts.entry(q).or_default().push(e);
+            }
+        }
+        let k = parts.values().map(|v| v.len()).max().unwrap_or(0);
+
+        let mut res = vec![Integer::one(); k];
+        for (q, mut exps) in parts {
+            exps.sort_unstable_by(|a, b| b.cmp(a));
+            for (i, e) in exps.into_iter().enumerate() {
+                res[k - 1 - i] *= (&q).pow(e);
+            }
+        }
+        res
+    }
+
+    /// Return true if the unit group of the integers modulo `n` is cyclic,
+    /// that is, `n` is 1, 2, 4, `p^k` or `2p^k` for an odd prime `p`.
+    ///
+    /// ```
+    /// use rufl::intmod::IntModCtx;
+    ///
+    /// assert!(IntModCtx::new(50).has_primitive_root());
+    /// assert!(!IntModCtx::new(12).has_primitive_root());
+    /// ```
+    #[inline]
+    pub fn has_primitive_root(&self) -> bool {
+        self.unit_group_structure().len() <= 1
+    }
+
+    /// Return the smallest positive primitive root modulo `n`, or `None` if
+    /// the unit group is not cyclic.
+    ///
+    /// ```
+    /// use rufl::intmod::{IntMod, IntModCtx};
+    ///
+    /// let ctx = IntModCtx::new(7);
+    /// assert_eq!(ctx.primitive_root(), Some(IntMod::new(3, &ctx)));
+    ///
+    /// let ctx = IntModCtx::new(8);
+    /// assert!(ctx.primitive_root().is_none());
+    /// ```
+    pub fn primitive_root(&self) -> Option<IntMod> {
+        if !self.has_primitive_root() {
+            return None;
+        }
+        let exponent = self.unit_group_exponent();
+        let mut g = Integer::one();
+        loop {
+            let x = IntMod::new(g.clone(), self);
+            if x.is_unit() && x.order_factored(&exponent) == exponent {
+                return Some(x);
+            }
+            g += 1u64;
+        }
+    }
+}
+
+impl IntMod {
+    /// Return true if the `IntMod` is invertible, that is, coprime to the
+    /// modulus.
+    ///
+    /// ```
+    /// use rufl::intmod::{IntMod, IntModCtx};
+    ///
+    /// let ctx = IntModCtx::new(10);
+    /// assert!(IntMod::new(3, &ctx).is_unit());
+    /// assert!(!IntMod::new(4, &ctx).is_unit());
+    /// ```
+    #[inline]
+    pub fn is_unit(&self) -> bool {
+        gcd(&Integer::from(self), &self.modulus()).is_one()
+    }
+
+    // The order of a unit in factored form, given the factored exponent of
+    // the unit group.
+    fn order_factored(&self, exponent: &Factored) -> Factored {
+        let mut res = Factored::new();
+        for q in exponent.keys() {
+            let mut rest = exponent.clone();
+            rest.remove(q);
+
+            let mut y = self.pow(expand(&rest));
+            let mut f = 0;
+            while !y.is_one() {
+                y = y.pow(q);
+                f += 1;
+            }
+            if f > 0 {
+                res.insert(q.clone(), f);
+            }
+        }
+        res
+    }
+
+    /// Return the multiplicative order of the `IntMod`, or `None` if it is
+    /// not a unit.
+    ///
+    /// ```
+    /// use rufl::intmod::{IntMod, IntModCtx};
+    ///
+    /// let ctx = IntModCtx::new(7);
+    /// assert_eq!(IntMod::new(2, &ctx).multiplicative_order().unwrap(), 3);
+    /// assert_eq!(IntMod::new(3, &ctx).multiplicative_order().unwrap(), 6);
+    /// assert!(IntMod::new(0, &ctx).multiplicative_order().is_none());
+    /// ```
+    pub fn multiplicative_order(&self) -> Option<Integer> {
+        if !self.is_unit() {
+            return None;
+        }
+        let exponent = self.context().unit_group_exponent();
+        Some(expand(&self.order_factored(&exponent)))
+    }
+
+    /// Return true if the `IntMod` generates the unit group.
+    ///
+    /// ```
+    /// use rufl::intmod::{IntMod, IntModCtx};
+    ///
+    /// let ctx = IntModCtx::new(7);
+    /// assert!(IntMod::new(3, &ctx).is_primitive_root());
+    /// assert!(!IntMod::new(2, &ctx).is_primitive_root());
+    /// ```
+    pub fn is_primitive_root(&self) -> bool {
+        let ctx = self.context();
+        if !self.is_unit() || !ctx.has_primitive_root() {
+            return false;
+        }
+        let exponent = ctx.unit_group_exponent();
+        self.order_factored(&exponent) == exponent
+    }
+
+    /// Return the smallest nonnegative `x` such that `base^x == self`, or
+    /// `None` if there is no such `x`. Both `self` and `base` must be units
+    /// or `None` is returned.
+    ///
+    /// This uses Pohlig–Hellman with baby-step giant-step, so the cost grows
+    /// with the square root of the largest prime factor of the order of
+    /// `base`.
+    ///
+    /// ```
+    /// use rufl::intmod::{IntMod, IntModCtx};
+    /// use rufl::ops::Pow;
+    ///
+    /// let ctx = IntModCtx::new(1000003);
+    /// let g = ctx.primitive_root().unwrap();
+    /// let y = g.pow(123456u64);
+    /// assert_eq!(y.discrete_log(&g).unwrap(), 123456);
+    ///
+    /// let ctx = IntModCtx::new(7);
+    /// let b = IntMod::new(2, &ctx);
+    /// assert!(IntMod::new(3, &ctx).discrete_log(&b).is_none());
+    /// ```
+    pub fn discrete_log(&self, base: &IntMod) -> Option<Integer> {
+        assert_eq!(self.context(), base.context());
+        if !self.is_unit() || !base.is_unit() {
+            return None;
+        }
+
+        let exponent = self.context().unit_group_exponent();
+        let order = base.order_factored(&exponent);
+        let n = expand(&order);
+
+        let mut x = Integer::zero();
+        let mut m = Integer::one();
+        for (q, e) in &order {
+            let qe = q.pow(*e);
+            let cofactor = divexact(&n, &qe);
+            let g = base.pow(&cofactor);
+            let h = self.pow(&cofactor);
+            let xq = discrete_log_prime_power(&h, &g, q, *e)?;
+            x = crt(&x, &m, &xq, &qe);
+            m *= qe;
+        }
+
+        if base.pow(&x) == *self {
+            Some(x)
+        } else {
+            None
+        }
+    }
+}
+
+// Solve `g^x = h` where `g` has order `q^e` for a prime `q`, one base `q`
+// digit of `x` at a time.
+fn discrete_log_prime_power(
+    h: &IntMod,
+    g: &IntMod,
+    q: &Integer,
+    e: u64
+) -> Option<Integer> {
+    let gamma = g.pow(q.pow(e - 1));
+    let g_inv = g.inv();
+
+    let mut x = Integer::zero();
+    let mut qk = Integer::one();
+    for k in 0..e {
+        let hk = (g_inv.pow(&x) * h).pow(q.pow(e - 1 - k));
+        let d = baby_step_giant_step(&hk, &gamma, q)?;
+        x += d * &qk;
+        qk *= q;
+    }
+    Some(x)
+}
+
+// Solve `g^x = h` for `0 <= x < q` where `g` has order `q`.
+fn baby_step_giant_step(h: &IntMod, g: &IntMod, q: &Integer) -> Option<Integer> {
+    let mut m = Integer::default();
+    unsafe { fmpz::fmpz_sqrt(m.as_mut_ptr(), q.as_ptr()); }
+    m += 1u64;
+    let steps = m.get_ui().expect("Discrete logarithm is too large to compute.");
+
+    let mut table = HashMap::new();
+    let mut cur = IntMod::one(g.context());
+    for j in 0..steps {
+        table.entry(Integer::from(&cur)).or_insert(j);
+        cur *= g;
+    }
+
+    let giant = g.pow(&m).inv();
+    let mut y = h.clone();
+    for i in 0..steps {
+        if let Some(j) = table.get(&Integer::from(&y)) {
+            return Some(Integer::from(i) * &m + *j);
+        }
+        y *= &giant;
+    }
+    None
+}
@@ -0,0 +1,135 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! [num-traits](https://docs.rs/num-traits) implementations for [IntMod].
+//!
+//! `Zero`, `One` and the traits built on them are not implemented since an
+//! `IntMod` cannot be constructed without its [IntModCtx][crate::IntModCtx].
+
+use crate::{IntMod, Integer};
+use flint_sys::fmpz_mod;
+use num_traits::{
+    CheckedAdd,
+    CheckedDiv,
+    CheckedMul,
+    CheckedNeg,
+    CheckedSub,
+    Inv,
+    Pow,
+};
+
+macro_rules! impl_pow {
+    ($($t:ident)+) => ($(
+        impl Pow<$t> for IntMod {
+            type Output = IntMod;
+            #[inline]
+            fn pow(self, rhs: $t) -> IntMod {
+                crate::ops::Pow::pow(self, rhs)
+            }
+        }
+
+        impl Pow<$t> for &IntMod {
+            type Output = IntMod;
+            #[inline]
+            fn pow(self, rhs: $t) -> IntMod {
+                crate::ops::Pow::pow(self, rhs)
+            }
+        }
+    )+);
+}
+
+impl_pow! { u64 u32 u16 u8 i64 i32 i16 i8 Integer }
+
+impl Inv for IntMod {
+    type Output = IntMod;
+    #[inline]
+    fn inv(self) -> IntMod {
+        crate::ops::Inv::inv(self)
+    }
+}
+
+impl Inv for &IntMod {
+    type Output = IntMod;
+    #[inline]
+    fn inv(self) -> IntMod {
+        crate::ops::Inv::inv(self)
+    }
+}
+
+impl CheckedAdd for IntMod {
+    #[inline]
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(self + rhs)
+    }
+}
+
+impl CheckedSub for IntMod {
+    #[inline]
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        Some(self - rhs)
+    }
+}
+
+impl CheckedMul for IntMod {
+    #[inline]
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        Some(self * rhs)
+    }
+}
+
+/// Returns `None` if the divisor is not invertible modulo `n`.
+impl CheckedDiv for IntMod {
+    #[inline]
+    fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        let invertible = unsafe {
+            fmpz_mod::fmpz_mod_is_invertible(rhs.as_ptr(), rhs.ctx_as_ptr()) == 1
+        };
+        if invertible {
+            Some(self / rhs)
+        } else {
+            None
+        }
+    }
+}
+
+impl CheckedNeg for IntMod {
+    #[inline]
+    fn checked_neg(&self) -> Option<Self> {
+        Some(-self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{IntMod, IntModCtx};
+    use num_traits::{CheckedDiv, Pow};
+
+    fn cube<T: Pow<u32, Output = T>>(x: T) -> T {
+        x.pow(3)
+    }
+
+    #[test]
+    fn generic() {
+        let ctx = IntModCtx::new(10);
+        let x = IntMod::new(3, &ctx);
+        assert_eq!(cube(x.clone()), IntMod::new(7, &ctx));
+
+        let y = IntMod::new(4, &ctx);
+        assert!(x.checked_div(&y).is_none());
+        assert_eq!(y.checked_div(&x), Some(IntMod::new(8, &ctx)));
+    }
+}
@@ -0,0 +1,346 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Square roots and k-th roots modulo arbitrary `n`.
+
+use super::group::{crt, divexact, gcd};
+use crate::{IntMod, IntModCtx, IntModPoly, Integer};
+use crate::ops::Pow;
+use flint_sys::{fmpz, fmpz_mod_poly, fmpz_mod_poly_factor, fmpz_mod_types};
+use std::mem::MaybeUninit;
+
+impl IntMod {
+    /// Return the smallest square root of the `IntMod`, or `None` if it is not
+    /// a square. The modulus need not be prime.
+    ///
+    /// ```
+    /// use rufl::intmod::{IntMod, IntModCtx};
+    ///
+    /// let ctx = IntModCtx::new(15);
+    /// assert_eq!(IntMod::new(4, &ctx).sqrt().unwrap(), IntMod::new(2, &ctx));
+    /// assert!(IntMod::new(2, &ctx).sqrt().is_none());
+    /// ```
+    #[inline]
+    pub fn sqrt(&self) -> Option<IntMod> {
+        self.all_sqrts().into_iter().next()
+    }
+
+    /// Return all square roots of the `IntMod` in increasing order. The
+    /// modulus is factored; use [IntMod::all_sqrts_factored] if the
+    /// factorization is already known.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    /// use rufl::intmod::{IntMod, IntModCtx};
+    ///
+    /// let ctx = IntModCtx::new(15);
+    /// let roots: Vec<Integer> = IntMod::new(4, &ctx).all_sqrts().iter().map(Integer::from).collect();
+    /// assert_eq!(roots, vec![2, 7, 8, 13]);
+    ///
+    /// let ctx = IntModCtx::new(8);
+    /// assert_eq!(IntMod::new(1, &ctx).all_sqrts().len(), 4);
+    ///
+    /// let ctx = IntModCtx::new(9);
+    /// let roots: Vec<Integer> = IntMod::new(0, &ctx).all_sqrts().iter().map(Integer::from).collect();
+    /// assert_eq!(roots, vec![0, 3, 6]);
+    /// ```
+    #[inline]
+    pub fn all_sqrts(&self) -> Vec<IntMod> {
+        self.nth_roots(2)
+    }
+
+    /// Return all square roots of the `IntMod` in increasing order, given the
+    /// factorization of the modulus as a list of primes and exponents. Panics
+    /// if the factorization does not multiply to the modulus.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    /// use rufl::intmod::{IntMod, IntModCtx};
+    ///
+    /// let ctx = IntModCtx::new(15);
+    /// let fac = [(Integer::from(3), 1), (Integer::from(5), 1)];
+    /// assert_eq!(IntMod::new(4, &ctx).all_sqrts_factored(&fac).len(), 4);
+    /// ```
+    #[inline]
+    pub fn all_sqrts_factored(&self, factors: &[(Integer, u64)]) -> Vec<IntMod> {
+        self.nth_roots_factored(2, factors)
+    }
+
+    /// Return all `k`-th roots of the `IntMod` in increasing order. Panics if
+    /// `k` is zero.
+    ///
+    /// The roots modulo each prime power dividing the modulus are found by
+    /// Hensel lifting the roots modulo the prime and combined with the
+    /// Chinese remainder theorem, so the cost grows with the number of roots.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    /// use rufl::intmod::{IntMod, IntModCtx};
+    ///
+    /// let ctx = IntModCtx::new(7);
+    /// let roots: Vec<Integer> = IntMod::new(1, &ctx).nth_roots(3).iter().map(Integer::from).collect();
+    /// assert_eq!(roots, vec![1, 2, 4]);
+    ///
+    /// let ctx = IntModCtx::new(27);
+    /// let roots: Vec<Integer> = IntMod::new(8, &ctx).nth_roots(3).iter().map(Integer::from).collect();
+    /// assert_eq!(roots, vec![2, 11, 20]);
+    /// ```
+    #[inline]
+    pub fn nth_roots(&self, k: u64) -> Vec<IntMod> {
+        let factors = self.modulus().factor();
+        self.nth_roots_factored(k, &factors)
+    }
+
+    /// Return all `k`-th roots of the `IntMod` in increasing order, given the
+    /// factorization of the modulus as a list of primes and exponents. Panics
+    /// if `k` is zero or the factorization does not multiply to the modulus.
+    pub fn nth_roots_factored(&self, k: u64, factors: &[(Integer, u64)]) -> Vec<IntMod> {
+        assert!(k > 0);
+        let n = self.modulus();
+        assert_eq!(
+            factors.iter().fold(Integer::one(), |acc, (p, e)| acc * p.pow(*e)),
+            n,
+            "Factorization does not match the modulus."
+        );
+
+        let a = Integer::from(self);
+        let mut res = vec![Integer::zero()];
+        let mut m = Integer::one();
+        for (p, e) in factors {
+            let pe = p.pow(*e);
+            let roots = roots_mod_prime_power(&a, k, p, *e);
+            res = res.iter()
+                .flat_map(|x| roots.iter().map(|y| crt(x, &m, y, &pe)).collect::<Vec<_>>())
+                .collect();
+            m *= pe;
+        }
+
+        res.sort();
+        let ctx = self.context();
+        res.into_iter().map(|x| IntMod::new(x, ctx)).collect()
+    }
+
+    /// Return true if the `IntMod` is a square. The modulus need not be
+    /// prime.
+    ///
+    /// ```
+    /// use rufl::intmod::{IntMod, IntModCtx};
+    ///
+    /// let ctx = IntModCtx::new(7);
+    /// assert!(IntMod::new(2, &ctx).is_square());
+    /// assert!(!IntMod::new(3, &ctx).is_square());
+    ///
+    /// let ctx = IntModCtx::new(16);
+    /// assert!(IntMod::new(9, &ctx).is_square());
+    /// assert!(!IntMod::new(5, &ctx).is_square());
+    /// ```
+    #[inline]
+    pub fn is_square(&self) -> bool {
+        self.is_kth_power(2)
+    }
+
+    /// Return true if the `IntMod` is a `k`-th power. This does not compute
+    /// any roots. Panics if `k` is zero.
+    ///
+    /// ```
+    /// use rufl::intmod::{IntMod, IntModCtx};
+    ///
+    /// let ctx = IntModCtx::new(7);
+    /// assert!(IntMod::new(6, &ctx).is_kth_power(3));
+    /// assert!(!IntMod::new(2, &ctx).is_kth_power(3));
+    /// ```
+    pub fn is_kth_power(&self, k: u64) -> bool {
+        assert!(k > 0);
+        let a = Integer::from(self);
+        self.modulus().factor().iter().all(|(p, e)| {
+            let pe = p.pow(*e);
+            let a = fmod(&a, &pe);
+            if a.is_zero() {
+                return true;
+            }
+            let (v, b) = remove(&a, p);
+            if v % k != 0 {
+                return false;
+            }
+            let f = e - v;
+            if p == &2 {
+                // The unit group modulo 2^f is generated by -1 and 5, and the
+                // 2^s-th powers are exactly the units that are 1 mod 2^(s+2).
+                let s = k.trailing_zeros() as u64;
+                s == 0 || fmod(&b, &Integer::from(2).pow(std::cmp::min(s + 2, f))).is_one()
+            } else {
+                // The unit group modulo p^f is cyclic of order phi.
+                let phi = p.pow(f - 1) * (p - 1u64);
+                let g = gcd(&Integer::from(k), &phi);
+                powm(&b, &divexact(&phi, &g), &p.pow(f)).is_one()
+            }
+        })
+    }
+}
+
+#[inline]
+fn fmod(a: &Integer, m: &Integer) -> Integer {
+    let mut res = Integer::default();
+    unsafe { fmpz::fmpz_mod(res.as_mut_ptr(), a.as_ptr(), m.as_ptr()); }
+    res
+}
+
+#[inline]
+fn powm(a: &Integer, e: &Integer, m: &Integer) -> Integer {
+    let mut res = Integer::default();
+    unsafe { fmpz::fmpz_powm(res.as_mut_ptr(), a.as_ptr(), e.as_ptr(), m.as_ptr()); }
+    res
+}
+
+// Write a nonzero `a` as `p^v * b` with `b` coprime to `p`, returning `(v, b)`.
+#[inline]
+fn remove(a: &Integer, p: &Integer) -> (u64, Integer) {
+    let mut b = Integer::default();
+    let v = unsafe { fmpz::fmpz_remove(b.as_mut_ptr(), a.as_ptr(), p.as_ptr()) };
+    (v as u64, b)
+}
+
+// All `x` in `[0, p^e)` with `x^k = a mod p^e`, in increasing order.
+fn roots_mod_prime_power(a: &Integer, k: u64, p: &Integer, e: u64) -> Vec<Integer> {
+    let pe = p.pow(e);
+    let a = fmod(a, &pe);
+    let mut res = vec![];
+
+    if a.is_zero() {
+        // x^k = 0 exactly when p^ceil(e/k) divides x.
+        let step = p.pow((e + k - 1) / k);
+        let mut x = Integer::zero();
+        while x < pe {
+            res.push(x.clone());
+            x += &step;
+        }
+        return res;
+    }
+
+    // With a = p^v * b, a root has the form p^(v/k) * y with y a unit root
+    // of b modulo p^(e-v), determined modulo p^(e-v/k).
+    let (v, b) = remove(&a, p);
+    if v % k != 0 {
+        return res;
+    }
+    let f = e - v;
+    let s = p.pow(v / k);
+    let pf = p.pow(f);
+    let reps = p.pow(v - v / k);
+    for y in unit_roots_mod_prime_power(&b, k, p, f) {
+        let mut t = Integer::zero();
+        while t < reps {
+            res.push(&s * (&y + &t * &pf));
+            t += 1u64;
+        }
+    }
+    res.sort();
+    res
+}
+
+// All units `y` modulo `p^f` with `y^k = b`, where `b` is a unit.
+fn unit_roots_mod_prime_power(b: &Integer, k: u64, p: &Integer, f: u64) -> Vec<Integer> {
+    let mut roots = unit_roots_mod_prime(&fmod(b, p), k, p);
+    let kk = Integer::from(k);
+    let p_divides_k = fmod(&kk, p).is_zero();
+
+    let mut pj = p.clone();
+    for _ in 1..f {
+        let pj1 = &pj * p;
+        let mut next = vec![];
+        for r in roots {
+            let fr = fmod(&(powm(&r, &kk, &pj1) - b), &pj1);
+            if p_divides_k {
+                // The derivative vanishes modulo p, so either every lift of
+                // r is a root modulo p^(j+1) or none is.
+                if fr.is_zero() {
+                    let pu = p.get_ui().expect("p divides k");
+                    for t in 0..pu {
+                        next.push(&r + Integer::from(t) * &pj);
+                    }
+                }
+            } else {
+                // Newton step: r - f(r)/f'(r), with f'(r) = k r^(k-1)
+                // invertible modulo p.
+                let q = divexact(&fr, &pj);
+                let d = fmod(&(&kk * powm(&r, &(&kk - 1u64), p)), p);
+                let inv = d.invmod(p).expect("p does not divide k r^(k-1)");
+                let t = fmod(&(-(q * inv)), p);
+                next.push(&r + t * &pj);
+            }
+        }
+        roots = next;
+        pj = pj1;
+    }
+    roots
+}
+
+// All `y` modulo a prime `p` with `y^k = b`, where `b` is a unit.
+fn unit_roots_mod_prime(b: &Integer, k: u64, p: &Integer) -> Vec<Integer> {
+    // With g = gcd(k, p - 1) = u k + v (p - 1), the k-th roots of b are the
+    // g-th roots of b^u when b is a k-th power, that is, b^((p-1)/g) = 1.
+    let pm1 = p - 1u64;
+    let kk = Integer::from(k);
+    let mut g = Integer::default();
+    let mut u = Integer::default();
+    let mut v = Integer::default();
+    unsafe {
+        fmpz::fmpz_xgcd(
+            g.as_mut_ptr(),
+            u.as_mut_ptr(),
+            v.as_mut_ptr(),
+            kk.as_ptr(),
+            pm1.as_ptr()
+        );
+    }
+    if !powm(b, &divexact(&pm1, &g), p).is_one() {
+        return vec![];
+    }
+    let c = powm(b, &fmod(&u, &pm1), p);
+    if g.is_one() {
+        return vec![c];
+    }
+
+    // Find the roots of x^g - c.
+    let ctx = IntModCtx::new(p.clone());
+    let g = g.get_ui().expect("g divides k") as usize;
+    let mut poly = IntModPoly::zero(&ctx);
+    poly.set_coeff(g, IntMod::one(&ctx));
+    poly.set_coeff(0, -IntMod::new(c, &ctx));
+
+    let mut res = vec![];
+    unsafe {
+        let mut fac = MaybeUninit::<fmpz_mod_types::fmpz_mod_poly_factor_struct>::uninit();
+        fmpz_mod_poly_factor::fmpz_mod_poly_factor_init(fac.as_mut_ptr(), ctx.as_ptr());
+        let mut fac = fac.assume_init();
+        fmpz_mod_poly_factor::fmpz_mod_poly_roots(&mut fac, poly.as_ptr(), 0, ctx.as_ptr());
+        for i in 0..fac.num as usize {
+            // Each factor is monic and linear, x - r.
+            let mut r = Integer::default();
+            fmpz_mod_poly::fmpz_mod_poly_get_coeff_fmpz(
+                r.as_mut_ptr(),
+                fac.poly.add(i),
+                0,
+                ctx.as_ptr()
+            );
+            res.push(fmod(&-r, p));
+        }
+        fmpz_mod_poly_factor::fmpz_mod_poly_factor_clear(&mut fac, ctx.as_ptr());
+    }
+    res.sort();
+    res
+}
@@ -18,8 +18,17 @@
 //mod ops;
 mod conv;
 mod extras;
+mod division;
+mod interpolate;
+mod modular;
+mod roots;
+mod recurrence;
+mod index;
 mod ops;
 
+pub use index::IntModPolyCoeffMut;
+pub use recurrence::{IntModBerlekampMassey, IntModRecurrenceTerms};
+
 use crate::*;
 use flint_sys::flint;
 use flint_sys::fmpz_mod_types::*;
@@ -30,6 +39,12 @@ use std::hash::{Hash, Hasher};
 use std::mem::{ManuallyDrop, MaybeUninit};
 
 
+/// A polynomial with coefficients in the integers mod `n`.
+///
+/// Indexing with `f[i]` borrows the coefficient as its residue, an
+/// [Integer] in `[0, n)`, since the coefficients are not stored as
+/// [IntMod]s. There is no `IndexMut`; [IntModPoly::coeff_mut] returns a
+/// guard which reduces and writes the coefficient back when dropped.
 #[derive(Debug)]
 pub struct IntModPoly {
     inner: fmpz_mod_poly_struct,
@@ -61,7 +76,7 @@ impl Clone for IntModPoly {
 impl fmt::Display for IntModPoly {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", IntPoly::from(self))
+        write!(f, "{}", self.to_str_format(&PolyFormat::default()))
     }
 }
 
@@ -267,5 +282,47 @@ impl IntModPoly {
         }
         res
     }
+
+    /// Return the polynomial printed according to `fmt`, with coefficients
+    /// in `[0, n)`.
+    ///
+    /// ```
+    /// use rufl::intmod::IntModCtx;
+    /// use rufl::intmodpoly::IntModPoly;
+    /// use rufl::polyfmt::PolyFormat;
+    /// use rufl::ops::NewCtx;
+    ///
+    /// let ctx = IntModCtx::new(7);
+    /// let f = IntModPoly::new([-1, 0, 1], &ctx);
+    /// let fmt = PolyFormat { var: "a".to_string(), ascending: true, ..Default::default() };
+    /// assert_eq!(f.to_str_format(&fmt), "6 + a^2");
+    /// ```
+    pub fn to_str_format(&self, fmt: &PolyFormat) -> String {
+        let coeffs: Vec<_> = self.get_coeffs()
+            .iter()
+            .map(|c| {
+                let c = Integer::from(c);
+                (c.sign(), c.to_string())
+            })
+            .collect();
+        fmt.format_terms(&coeffs)
+    }
+
+    /// Parse a polynomial over the integers modulo `n` with the notation of
+    /// [IntPoly], reducing the coefficients by the modulus of `ctx`.
+    ///
+    /// ```
+    /// use rufl::intmod::IntModCtx;
+    /// use rufl::intmodpoly::IntModPoly;
+    /// use rufl::ops::NewCtx;
+    ///
+    /// let ctx = IntModCtx::new(7);
+    /// let f = IntModPoly::from_str_ctx("x^2 - 1", &ctx).unwrap();
+    /// assert_eq!(f, IntModPoly::new([6, 0, 1], &ctx));
+    /// assert_eq!(IntModPoly::from_str_ctx(&f.to_string(), &ctx).unwrap(), f);
+    /// ```
+    pub fn from_str_ctx(s: &str, ctx: &IntModCtx) -> Result<IntModPoly> {
+        Ok(IntModPoly::new(s.parse::<IntPoly>()?, ctx))
+    }
 }
 
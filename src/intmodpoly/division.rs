@@ -0,0 +1,124 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Euclidean division and exact division of polynomials over the integers
+//! modulo `n`.
+
+use crate::{Error, IntModPoly, Integer, Result};
+use flint_sys::fmpz_mod_poly;
+
+impl IntModPoly {
+    /// Return the quotient and remainder `(q, r)` with `self = q*rhs + r` and
+    /// `deg(r) < deg(rhs)`. Returns an error if `rhs` is zero or its leading
+    /// coefficient is not invertible modulo `n`.
+    ///
+    /// ```
+    /// use rufl::intmod::IntModCtx;
+    /// use rufl::intmodpoly::IntModPoly;
+    /// use rufl::ops::NewCtx;
+    ///
+    /// let ctx = IntModCtx::new(7);
+    /// let a = IntModPoly::new([1, 0, 0, 1], &ctx);
+    /// let b = IntModPoly::new([0, 2], &ctx);
+    /// let (q, r) = a.div_rem(&b).unwrap();
+    /// assert_eq!(q * &b + r, a);
+    ///
+    /// let ctx = IntModCtx::new(6);
+    /// let a = IntModPoly::new([1, 0, 1], &ctx);
+    /// let b = IntModPoly::new([1, 3], &ctx);
+    /// assert!(a.div_rem(&b).is_err());
+    /// ```
+    pub fn div_rem<T: AsRef<IntModPoly>>(&self, rhs: T) -> Result<(IntModPoly, IntModPoly)> {
+        let rhs = rhs.as_ref();
+        assert_eq!(self.context(), rhs.context());
+        if rhs.is_zero() {
+            return Err(Error::DivisionError("Division by zero.".to_string()));
+        }
+
+        let mut f = Integer::default();
+        let mut q = IntModPoly::zero(self.context());
+        let mut r = IntModPoly::zero(self.context());
+        unsafe {
+            fmpz_mod_poly::fmpz_mod_poly_divrem_f(
+                f.as_mut_ptr(),
+                q.as_mut_ptr(),
+                r.as_mut_ptr(),
+                self.as_ptr(),
+                rhs.as_ptr(),
+                self.ctx_as_ptr()
+            );
+        }
+        if f.is_one() {
+            Ok((q, r))
+        } else {
+            Err(Error::DivisionError(format!(
+                "Leading coefficient of the divisor is not invertible; it shares the factor {} with the modulus.",
+                f
+            )))
+        }
+    }
+
+    /// Return the quotient `self/rhs` if `rhs` divides `self` exactly, and
+    /// `None` otherwise. Returns an error if `rhs` is zero or its leading
+    /// coefficient is not invertible modulo `n`.
+    ///
+    /// ```
+    /// use rufl::intmod::IntModCtx;
+    /// use rufl::intmodpoly::IntModPoly;
+    /// use rufl::ops::NewCtx;
+    ///
+    /// let ctx = IntModCtx::new(7);
+    /// let a = IntModPoly::new([-4, 0, 1], &ctx);
+    /// let q = a.divides(IntModPoly::new([2, 1], &ctx)).unwrap();
+    /// assert_eq!(q, Some(IntModPoly::new([-2, 1], &ctx)));
+    /// assert_eq!(a.divides(IntModPoly::new([3, 1], &ctx)).unwrap(), None);
+    ///
+    /// let ctx = IntModCtx::new(6);
+    /// let a = IntModPoly::new([0, 3], &ctx);
+    /// assert!(a.divides(IntModPoly::new([0, 3], &ctx)).is_err());
+    /// ```
+    pub fn divides<T: AsRef<IntModPoly>>(&self, rhs: T) -> Result<Option<IntModPoly>> {
+        let (q, r) = self.div_rem(rhs)?;
+        if r.is_zero() {
+            Ok(Some(q))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Return the exact quotient `self/rhs`, or an error if `rhs` is zero,
+    /// its leading coefficient is not invertible modulo `n`, or it does not
+    /// divide `self`.
+    ///
+    /// ```
+    /// use rufl::intmod::IntModCtx;
+    /// use rufl::intmodpoly::IntModPoly;
+    /// use rufl::ops::NewCtx;
+    ///
+    /// let ctx = IntModCtx::new(7);
+    /// let a = IntModPoly::new([-1, 0, 0, 1], &ctx);
+    /// let b = IntModPoly::new([-1, 1], &ctx);
+    /// assert_eq!(a.div_exact(&b).unwrap(), IntModPoly::new([1, 1, 1], &ctx));
+    /// assert!(a.div_exact(IntModPoly::new([1, 1], &ctx)).is_err());
+    /// assert!(a.div_exact(IntModPoly::zero(&ctx)).is_err());
+    /// ```
+    pub fn div_exact<T: AsRef<IntModPoly>>(&self, rhs: T) -> Result<IntModPoly> {
+        self.divides(rhs)?.ok_or_else(|| Error::DivisionError(
+            "Divisor does not divide the dividend exactly.".to_string()
+        ))
+    }
+}
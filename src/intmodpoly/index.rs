@@ -0,0 +1,123 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Indexing of polynomials over the integers mod `n` by coefficient, and a
+//! guard for modifying a single coefficient in place.
+
+use crate::{IntMod, IntModPoly, Integer, IntegerRef};
+use std::fmt;
+use std::ops::{Deref, DerefMut, Index};
+
+/// Read the coefficient of `x^i` by reference, as its residue in `[0, n)`.
+/// Coefficients beyond the length of the polynomial are zero.
+///
+/// There is no `IndexMut`, since changing a coefficient requires reducing
+/// it mod `n` and may change the length of the polynomial; use
+/// [IntModPoly::coeff_mut] instead.
+///
+/// ```
+/// use rufl::intmod::IntModCtx;
+/// use rufl::intmodpoly::IntModPoly;
+/// use rufl::ops::NewCtx;
+///
+/// let ctx = IntModCtx::new(7);
+/// let f = IntModPoly::new([1, -1, 9], &ctx);
+/// assert_eq!(f[1], 6);
+/// assert_eq!(f[2], 2);
+/// assert_eq!(f[5], 0);
+/// ```
+impl Index<usize> for IntModPoly {
+    type Output = Integer;
+    #[inline]
+    fn index(&self, i: usize) -> &Integer {
+        if i < self.len() {
+            unsafe { IntegerRef::from_ptr((*self.as_ptr()).coeffs.add(i)).as_integer() }
+        } else {
+            IntegerRef::zero().as_integer()
+        }
+    }
+}
+
+/// A mutable handle to one coefficient of an [IntModPoly], created by
+/// [IntModPoly::coeff_mut].
+///
+/// The handle dereferences to a copy of the coefficient, which is written
+/// back when the handle is dropped. The polynomial is normalised at that
+/// point, so it is never observed with a zero leading coefficient.
+pub struct IntModPolyCoeffMut<'a> {
+    poly: &'a mut IntModPoly,
+    i: usize,
+    value: IntMod,
+}
+
+impl Deref for IntModPolyCoeffMut<'_> {
+    type Target = IntMod;
+    #[inline]
+    fn deref(&self) -> &IntMod {
+        &self.value
+    }
+}
+
+impl DerefMut for IntModPolyCoeffMut<'_> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut IntMod {
+        &mut self.value
+    }
+}
+
+impl Drop for IntModPolyCoeffMut<'_> {
+    #[inline]
+    fn drop(&mut self) {
+        self.poly.set_coeff(self.i, &self.value);
+    }
+}
+
+impl fmt::Debug for IntModPolyCoeffMut<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.value, f)
+    }
+}
+
+impl fmt::Display for IntModPolyCoeffMut<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)
+    }
+}
+
+impl IntModPoly {
+    /// Return a handle for modifying the coefficient of `x^i` in place. The
+    /// polynomial grows or shrinks as needed when the handle is dropped.
+    ///
+    /// ```
+    /// use rufl::intmod::IntModCtx;
+    /// use rufl::intmodpoly::IntModPoly;
+    /// use rufl::ops::NewCtx;
+    ///
+    /// let ctx = IntModCtx::new(7);
+    /// let mut f = IntModPoly::new([1, 2, 3], &ctx);
+    /// *f.coeff_mut(0) += 10;
+    /// assert_eq!(f[0], 4);
+    ///
+    /// *f.coeff_mut(2) -= 3;
+    /// assert_eq!(f.degree(), 1);
+    /// ```
+    #[inline]
+    pub fn coeff_mut(&mut self, i: usize) -> IntModPolyCoeffMut<'_> {
+        let value = self.get_coeff(i);
+        IntModPolyCoeffMut { poly: self, i, value }
+    }
+}
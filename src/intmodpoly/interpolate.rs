@@ -0,0 +1,148 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Interpolation and construction from roots for polynomials over the
+//! integers modulo `n`.
+
+use crate::{Error, IntMod, IntModCtx, IntModPoly, Result};
+use crate::ops::Inv;
+use flint_sys::fmpz_mod_poly;
+
+// The polynomial `x - r`.
+fn linear(r: &IntMod) -> IntModPoly {
+    let mut res = IntModPoly::from(-r);
+    res.set_coeff(1, IntMod::one(r.context()));
+    res
+}
+
+impl_subproduct_tree!(IntModPoly, IntMod, linear);
+
+fn not_invertible() -> Error {
+    Error::DivisionError(
+        "Differences of interpolation points must be invertible.".to_string()
+    )
+}
+
+impl IntModPoly {
+    /// Return the monic polynomial whose roots are `roots`, with
+    /// multiplicity, computed with a product tree.
+    ///
+    /// ```
+    /// use rufl::intmod::{IntMod, IntModCtx};
+    /// use rufl::intmodpoly::IntModPoly;
+    /// use rufl::ops::NewCtx;
+    ///
+    /// let ctx = IntModCtx::new(7);
+    /// let roots = [IntMod::new(1, &ctx), IntMod::new(2, &ctx)];
+    /// assert_eq!(IntModPoly::from_roots(&roots, &ctx), IntModPoly::new([2, 4, 1], &ctx));
+    /// ```
+    pub fn from_roots(roots: &[IntMod], ctx: &IntModCtx) -> IntModPoly {
+        if roots.is_empty() {
+            return IntModPoly::one(ctx);
+        }
+        for r in roots {
+            assert_eq!(r.context(), ctx);
+        }
+        product_tree(roots).pop().unwrap().pop().unwrap()
+    }
+
+    /// Return the unique polynomial of degree less than the number of points
+    /// passing through `points`, using Newton's divided differences. Returns
+    /// an error if the difference of two `x` values is not invertible, in
+    /// particular if two points share an `x` value.
+    ///
+    /// ```
+    /// use rufl::intmod::{IntMod, IntModCtx};
+    /// use rufl::intmodpoly::IntModPoly;
+    /// use rufl::ops::NewCtx;
+    ///
+    /// // Recover the secret f(0) = 42 from three shares of f = 42 + 7x + 19x^2.
+    /// let ctx = IntModCtx::new(101);
+    /// let points: Vec<(IntMod, IntMod)> = (1..4)
+    ///     .map(|x| (IntMod::new(x, &ctx), IntMod::new(42 + 7*x + 19*x*x, &ctx)))
+    ///     .collect();
+    /// let g = IntModPoly::interpolate(&points, &ctx).unwrap();
+    /// assert_eq!(g, IntModPoly::new([42, 7, 19], &ctx));
+    ///
+    /// let ctx = IntModCtx::new(10);
+    /// let points = [(IntMod::new(1, &ctx), IntMod::new(0, &ctx)),
+    ///               (IntMod::new(3, &ctx), IntMod::new(1, &ctx))];
+    /// assert!(IntModPoly::interpolate(&points, &ctx).is_err());
+    /// ```
+    pub fn interpolate(points: &[(IntMod, IntMod)], ctx: &IntModCtx) -> Result<IntModPoly> {
+        let xs: Vec<&IntMod> = points.iter().map(|p| &p.0).collect();
+        let n = points.len();
+        let mut c: Vec<IntMod> = points.iter().map(|p| p.1.clone()).collect();
+        for j in 1..n {
+            for i in (j..n).rev() {
+                let d = xs[i] - xs[i - j];
+                if !d.is_unit() {
+                    return Err(not_invertible());
+                }
+                c[i] = (&c[i] - &c[i - 1]) * d.inv();
+            }
+        }
+
+        let mut res = IntModPoly::zero(ctx);
+        for i in (0..n).rev() {
+            res = res * linear(xs[i]) + IntModPoly::from(&c[i]);
+        }
+        Ok(res)
+    }
+
+    /// Return the same polynomial as [IntModPoly::interpolate] using a
+    /// subproduct tree, which is faster for many points.
+    ///
+    /// ```
+    /// use rufl::intmod::{IntMod, IntModCtx};
+    /// use rufl::intmodpoly::IntModPoly;
+    ///
+    /// let ctx = IntModCtx::new(1000003);
+    /// let points: Vec<(IntMod, IntMod)> = (0..50u64)
+    ///     .map(|x| (IntMod::new(x, &ctx), IntMod::new(x * x * x + 5, &ctx)))
+    ///     .collect();
+    /// assert_eq!(
+    ///     IntModPoly::interpolate_fast(&points, &ctx).unwrap(),
+    ///     IntModPoly::interpolate(&points, &ctx).unwrap()
+    /// );
+    /// ```
+    pub fn interpolate_fast(points: &[(IntMod, IntMod)], ctx: &IntModCtx) -> Result<IntModPoly> {
+        if points.is_empty() {
+            return Ok(IntModPoly::zero(ctx));
+        }
+        let xs: Vec<IntMod> = points.iter().map(|p| p.0.clone()).collect();
+
+        let tree = product_tree(&xs);
+        let root = &tree.last().unwrap()[0];
+        let mut dm = IntModPoly::zero(ctx);
+        unsafe {
+            fmpz_mod_poly::fmpz_mod_poly_derivative(dm.as_mut_ptr(), root.as_ptr(), ctx.as_ptr());
+        }
+
+        // m'(x_i) is the product of the differences x_i - x_j, so it is a
+        // unit exactly when they all are.
+        let mut vals = vec![];
+        for (d, p) in evaluate_tree(&dm, &tree[..tree.len() - 1]).iter().zip(points) {
+            if !d.is_unit() {
+                return Err(not_invertible());
+            }
+            vals.push(IntModPoly::from(&p.1 * d.inv()));
+        }
+
+        Ok(combine_tree(vals, &tree[..tree.len() - 1]))
+    }
+}
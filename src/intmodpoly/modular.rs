@@ -0,0 +1,204 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Exponentiation and composition of polynomials modulo a polynomial over
+//! the integers modulo `n`.
+
+use crate::{Error, IntMod, IntModPoly, Integer, Result};
+use flint_sys::fmpz_mod_poly;
+
+impl IntModPoly {
+    // Return an error unless `self` can be used as a modulus, that is, it is
+    // nonzero with an invertible leading coefficient.
+    fn check_poly_modulus(&self) -> Result<()> {
+        if self.is_zero() {
+            return Err(Error::DivisionError("Division by zero.".to_string()));
+        }
+        if !self.get_coeff(self.degree() as usize).is_unit() {
+            return Err(Error::DivisionError(
+                "Leading coefficient of the modulus is not invertible.".to_string()
+            ));
+        }
+        Ok(())
+    }
+
+    // The inverse of the reversal of `self` as a power series to the length
+    // of `self`, used to speed up reduction modulo `self`.
+    fn rev_inv(&self) -> IntModPoly {
+        let len: i64 = self.len().try_into().expect("Cannot convert length to a signed long.");
+        let mut rev = IntModPoly::zero(self.context());
+        let mut res = IntModPoly::zero(self.context());
+        unsafe {
+            fmpz_mod_poly::fmpz_mod_poly_reverse(
+                rev.as_mut_ptr(),
+                self.as_ptr(),
+                len,
+                self.ctx_as_ptr()
+            );
+            fmpz_mod_poly::fmpz_mod_poly_inv_series(
+                res.as_mut_ptr(),
+                rev.as_ptr(),
+                len,
+                self.ctx_as_ptr()
+            );
+        }
+        res
+    }
+
+    /// Return `self^exp mod modulus`, using sliding window exponentiation
+    /// with a precomputed inverse of the modulus. Returns an error if the
+    /// modulus is zero or its leading coefficient is not invertible, or if
+    /// the exponent is negative.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    /// use rufl::intmod::IntModCtx;
+    /// use rufl::intmodpoly::IntModPoly;
+    /// use rufl::ops::{NewCtx, Pow};
+    ///
+    /// // x^2 = -1 modulo x^2 + 1, so x has order 4.
+    /// let ctx = IntModCtx::new(7);
+    /// let f = IntModPoly::new([1, 0, 1], &ctx);
+    /// let x = IntModPoly::new([0, 1], &ctx);
+    /// assert_eq!(x.powmod(&Integer::from(2), &f).unwrap(), IntModPoly::new([-1], &ctx));
+    /// assert!(x.powmod(&Integer::from(4), &f).unwrap().is_one());
+    ///
+    /// let e = Integer::from(7).pow(100u64);
+    /// assert_eq!(x.powmod(&e, &f).unwrap(), IntModPoly::new([0, 1], &ctx));
+    /// ```
+    pub fn powmod(&self, exp: &Integer, modulus: &IntModPoly) -> Result<IntModPoly> {
+        assert_eq!(self.context(), modulus.context());
+        modulus.check_poly_modulus()?;
+        if exp < &0 {
+            return Err(Error::Msg("The exponent must be nonnegative.".to_string()));
+        }
+        if modulus.degree() == 0 {
+            return Ok(IntModPoly::zero(self.context()));
+        }
+        if exp.is_zero() {
+            return Ok(IntModPoly::one(self.context()));
+        }
+
+        let finv = modulus.rev_inv();
+        let mut res = IntModPoly::zero(self.context());
+        unsafe {
+            // A window size of zero lets FLINT choose it from the size of
+            // the exponent.
+            fmpz_mod_poly::fmpz_mod_poly_powmod_fmpz_sliding_preinv(
+                res.as_mut_ptr(),
+                self.as_ptr(),
+                exp.as_ptr(),
+                0,
+                modulus.as_ptr(),
+                finv.as_ptr(),
+                self.ctx_as_ptr()
+            );
+        }
+        Ok(res)
+    }
+
+    /// Return the composition `self(h) mod modulus`, using the Brent–Kung
+    /// baby-step giant-step algorithm. Returns an error if the modulus is
+    /// zero or its leading coefficient is not invertible.
+    ///
+    /// ```
+    /// use rufl::intmod::IntModCtx;
+    /// use rufl::intmodpoly::IntModPoly;
+    /// use rufl::ops::NewCtx;
+    ///
+    /// let ctx = IntModCtx::new(5);
+    /// let f = IntModPoly::new([1, 1, 0, 1], &ctx);
+    /// let g = IntModPoly::new([1, 0, 1], &ctx);
+    /// let h = IntModPoly::new([2, 1], &ctx);
+    /// assert_eq!(g.compose_mod(&h, &f).unwrap(), IntModPoly::new([0, 4, 1], &ctx));
+    /// ```
+    pub fn compose_mod(&self, h: &IntModPoly, modulus: &IntModPoly) -> Result<IntModPoly> {
+        assert_eq!(self.context(), h.context());
+        assert_eq!(self.context(), modulus.context());
+        modulus.check_poly_modulus()?;
+        if modulus.degree() == 0 {
+            return Ok(IntModPoly::zero(self.context()));
+        }
+
+        // Brent–Kung requires both inputs reduced modulo the modulus.
+        let (_, g) = self.div_rem(modulus)?;
+        let (_, h) = h.div_rem(modulus)?;
+        let finv = modulus.rev_inv();
+        let mut res = IntModPoly::zero(self.context());
+        unsafe {
+            fmpz_mod_poly::fmpz_mod_poly_compose_mod_brent_kung_preinv(
+                res.as_mut_ptr(),
+                g.as_ptr(),
+                h.as_ptr(),
+                modulus.as_ptr(),
+                finv.as_ptr(),
+                self.ctx_as_ptr()
+            );
+        }
+        Ok(res)
+    }
+
+    /// Return `self^(p^k) mod modulus`, where the modulus of the coefficient
+    /// ring is a prime `p`. In particular, `x.frobenius_power(k, f)` is
+    /// `x^(p^k) mod f`.
+    ///
+    /// Since the Frobenius map is a ring homomorphism, this computes
+    /// `x^p mod f` once and then `x^(p^k)` by `k - 1` modular compositions,
+    /// rather than exponentiating to the power `p^k`. Returns an error if
+    /// `p` is not prime, or if the modulus is zero or its leading
+    /// coefficient is not invertible.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    /// use rufl::intmod::IntModCtx;
+    /// use rufl::intmodpoly::IntModPoly;
+    /// use rufl::ops::NewCtx;
+    ///
+    /// // f is irreducible of degree 3 over GF(5), so x^(5^3) = x mod f.
+    /// let ctx = IntModCtx::new(5);
+    /// let f = IntModPoly::new([1, 1, 0, 1], &ctx);
+    /// let x = IntModPoly::new([0, 1], &ctx);
+    /// assert_eq!(x.frobenius_power(3, &f).unwrap(), x);
+    /// assert_eq!(
+    ///     x.frobenius_power(1, &f).unwrap(),
+    ///     x.powmod(&Integer::from(5), &f).unwrap()
+    /// );
+    /// ```
+    pub fn frobenius_power(&self, k: u64, modulus: &IntModPoly) -> Result<IntModPoly> {
+        assert_eq!(self.context(), modulus.context());
+        let p = self.modulus();
+        if !p.is_prime() {
+            return Err(Error::Msg(format!(
+                "The Frobenius map requires a prime modulus, got {}.",
+                p
+            )));
+        }
+        modulus.check_poly_modulus()?;
+        if k == 0 {
+            return Ok(self.div_rem(modulus)?.1);
+        }
+
+        let mut x = IntModPoly::zero(self.context());
+        x.set_coeff(1, IntMod::one(self.context()));
+        let xp = x.powmod(&p, modulus)?;
+        let mut xq = xp.clone();
+        for _ in 1..k {
+            xq = xq.compose_mod(&xp, modulus)?;
+        }
+        self.compose_mod(&xq, modulus)
+    }
+}
@@ -81,6 +81,60 @@ impl_binop_unsafe! {
     fmpz_mod_poly::fmpz_mod_poly_mul;
 }
 
+// Division panics where `div_rem` would return an error.
+impl_binop! {
+    IntModPoly, IntModPoly, IntModPoly
+    Div {div}
+    {
+        fn div(self, rhs: &IntModPoly) -> IntModPoly {
+            self.div_rem(rhs).unwrap_or_else(|e| panic!("{}", e)).0
+        }
+    }
+    DivAssign {div_assign}
+    {
+        fn div_assign(&mut self, rhs: &IntModPoly) {
+            *self = &*self / rhs;
+        }
+    }
+    DivFrom {div_from}
+    {
+        fn div_from(&mut self, lhs: &IntModPoly) {
+            *self = lhs / &*self;
+        }
+    }
+    AssignDiv {assign_div}
+    {
+        fn assign_div(&mut self, lhs: &IntModPoly, rhs: &IntModPoly) {
+            *self = lhs / rhs;
+        }
+    }
+
+    Rem {rem}
+    {
+        fn rem(self, rhs: &IntModPoly) -> IntModPoly {
+            self.div_rem(rhs).unwrap_or_else(|e| panic!("{}", e)).1
+        }
+    }
+    RemAssign {rem_assign}
+    {
+        fn rem_assign(&mut self, rhs: &IntModPoly) {
+            *self = &*self % rhs;
+        }
+    }
+    RemFrom {rem_from}
+    {
+        fn rem_from(&mut self, lhs: &IntModPoly) {
+            *self = lhs % &*self;
+        }
+    }
+    AssignRem {assign_rem}
+    {
+        fn assign_rem(&mut self, lhs: &IntModPoly, rhs: &IntModPoly) {
+            *self = lhs % rhs;
+        }
+    }
+}
+
 impl_binop_unsafe! {
     ctx
     op_assign
@@ -121,6 +175,11 @@ impl_binop_unsafe! {
     MulAssign {mul_assign}
     AssignMul {assign_mul}
     fmpz_mod_poly::fmpz_mod_poly_scalar_mul_ui;
+
+    Pow {pow}
+    PowAssign {pow_assign}
+    AssignPow {assign_pow}
+    fmpz_mod_poly::fmpz_mod_poly_pow;
 }
 
 impl_binop_unsafe! {
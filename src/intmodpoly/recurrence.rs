@@ -0,0 +1,168 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Linear recurrences of sequences over the integers modulo `n`.
+
+use crate::{IntMod, IntModCtx, IntModPoly};
+use flint_sys::fmpz_mod_poly;
+
+impl_berlekamp_massey! {
+    /// An incremental Berlekamp–Massey computation of the shortest linear
+    /// recurrence satisfied by a sequence over the integers modulo a prime.
+    ///
+    /// After feeding `s_0, ..., s_{N-1}`, the connection polynomial
+    /// `C(x) = 1 + c_1 x + ... + c_L x^L` satisfies
+    /// `s_n + c_1 s_{n-1} + ... + c_L s_{n-L} = 0` for `L <= n < N`, where `L` is
+    /// the linear complexity of the sequence.
+    ///
+    /// ```
+    /// use rufl::intmod::{IntMod, IntModCtx};
+    /// use rufl::intmodpoly::{IntModBerlekampMassey, IntModPoly};
+    /// use rufl::ops::NewCtx;
+    ///
+    /// // The Fibonacci numbers modulo 7.
+    /// let ctx = IntModCtx::new(7);
+    /// let mut bm = IntModBerlekampMassey::new(&ctx);
+    /// for s in [0, 1, 1, 2, 3, 5, 1, 6] {
+    ///     bm.push(IntMod::new(s, &ctx));
+    /// }
+    /// assert_eq!(bm.linear_complexity(), 2);
+    /// assert_eq!(bm.connection_polynomial(), IntModPoly::new([1, -1, -1], &ctx));
+    /// ```
+    IntModBerlekampMassey, IntMod, IntModPoly
+    check: |term, zero| assert_eq!(term.context(), zero.context());
+    zero_poly: |zero| IntModPoly::zero(zero.context());
+}
+
+impl IntModBerlekampMassey {
+    /// Start a computation over the integers modulo `ctx`. Panics if the
+    /// modulus is not prime.
+    pub fn new(ctx: &IntModCtx) -> Self {
+        assert!(ctx.modulus().is_prime(), "Berlekamp-Massey requires a prime modulus.");
+        IntModBerlekampMassey::start(IntMod::zero(ctx), IntMod::one(ctx))
+    }
+}
+
+impl IntModPoly {
+    /// Return the connection polynomial of the shortest linear recurrence
+    /// satisfied by `seq`, computed with [IntModBerlekampMassey]. Panics if
+    /// the modulus is not prime.
+    ///
+    /// ```
+    /// use rufl::intmod::{IntMod, IntModCtx};
+    /// use rufl::intmodpoly::IntModPoly;
+    /// use rufl::ops::NewCtx;
+    ///
+    /// // An LFSR over GF(2) with s_n = s_{n-1} + s_{n-3}.
+    /// let ctx = IntModCtx::new(2);
+    /// let seq: Vec<IntMod> = [1, 0, 0, 1, 1, 1, 0, 1, 0, 0]
+    ///     .iter()
+    ///     .map(|&s| IntMod::new(s, &ctx))
+    ///     .collect();
+    /// let c = IntModPoly::berlekamp_massey(&seq, &ctx);
+    /// assert_eq!(c, IntModPoly::new([1, 1, 0, 1], &ctx));
+    /// ```
+    pub fn berlekamp_massey(seq: &[IntMod], ctx: &IntModCtx) -> IntModPoly {
+        let mut bm = IntModBerlekampMassey::new(ctx);
+        bm.extend(seq);
+        bm.connection_polynomial()
+    }
+
+    /// Return the term `s_n` of the sequence with characteristic polynomial
+    /// `self` and initial terms `init = [s_0, ..., s_{d-1}]`.
+    ///
+    /// If `self = x^d + p_{d-1} x^{d-1} + ... + p_0`, the sequence satisfies
+    /// `s_{k+d} = -(p_{d-1} s_{k+d-1} + ... + p_0 s_k)`. The term is computed
+    /// from `x^n mod self` (Fiduccia's algorithm) in `O(M(d) log n)`
+    /// operations, so `n` may be as large as `u64::MAX`. Panics if `self` is
+    /// not monic of positive degree `d` or if `init` does not have length
+    /// `d`.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    /// use rufl::intmod::{IntMod, IntModCtx};
+    /// use rufl::intmodpoly::IntModPoly;
+    /// use rufl::ops::NewCtx;
+    ///
+    /// let ctx = IntModCtx::new(1000000007);
+    /// let f = IntModPoly::new([-1, -1, 1], &ctx);
+    /// let init = [IntMod::new(0, &ctx), IntMod::new(1, &ctx)];
+    /// assert_eq!(
+    ///     f.recurrence_term(&init, 1000),
+    ///     IntMod::new(Integer::fibonacci(1000u32), &ctx)
+    /// );
+    ///
+    /// // Exponents far beyond what the integer sequence allows: the
+    /// // Fibonacci numbers modulo 10 have period 60.
+    /// let ctx = IntModCtx::new(10);
+    /// let f = IntModPoly::new([-1, -1, 1], &ctx);
+    /// let init = [IntMod::new(0, &ctx), IntMod::new(1, &ctx)];
+    /// let n = (1u64 << 62) + 7;
+    /// assert_eq!(
+    ///     f.recurrence_term(&init, n),
+    ///     IntMod::new(Integer::fibonacci(n % 60), &ctx)
+    /// );
+    /// ```
+    pub fn recurrence_term(&self, init: &[IntMod], n: u64) -> IntMod {
+        let d = self.check_recurrence(init);
+        if n < d as u64 {
+            return init[n as usize].clone();
+        }
+        let mut x = IntModPoly::zero(self.context());
+        x.set_coeff(1, IntMod::one(self.context()));
+        let mut r = IntModPoly::zero(self.context());
+        unsafe {
+            fmpz_mod_poly::fmpz_mod_poly_powmod_ui_binexp(
+                r.as_mut_ptr(),
+                x.as_ptr(),
+                n,
+                self.as_ptr(),
+                self.ctx_as_ptr()
+            );
+        }
+        let mut res = IntMod::zero(self.context());
+        for (i, s) in init.iter().enumerate().take(r.len()) {
+            res += r.get_coeff(i) * s;
+        }
+        res
+    }
+}
+
+impl_recurrence_terms! {
+    IntModPoly, IntMod
+    check: |s, f| assert_eq!(s.context(), f.context());
+    zero: |f| IntMod::zero(f.context());
+    /// Return an iterator over the terms `s_0, s_1, ...` of the sequence with
+    /// characteristic polynomial `self` and initial terms `init`, as in
+    /// [IntModPoly::recurrence_term]. The iterator never ends.
+    ///
+    /// ```
+    /// use rufl::intmod::{IntMod, IntModCtx};
+    /// use rufl::intmodpoly::IntModPoly;
+    /// use rufl::ops::NewCtx;
+    ///
+    /// let ctx = IntModCtx::new(10);
+    /// let f = IntModPoly::new([-1, -1, 1], &ctx);
+    /// let init = [IntMod::new(0, &ctx), IntMod::new(1, &ctx)];
+    /// let digits: Vec<IntMod> = f.recurrence_terms(&init).skip(60).take(3).collect();
+    /// assert_eq!(digits, f.recurrence_terms(&init).take(3).collect::<Vec<_>>());
+    /// ```
+    fn recurrence_terms;
+    /// An iterator over the terms of a linear recurrence modulo `n`, created by
+    /// [IntModPoly::recurrence_terms].
+    struct IntModRecurrenceTerms;
+}
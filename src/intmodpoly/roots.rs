@@ -0,0 +1,183 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Roots of polynomials over prime fields.
+
+use crate::{Error, IntMod, IntModPoly, Integer, Result};
+use flint_sys::{fmpz_mod_poly, fmpz_mod_poly_factor, fmpz_mod_types};
+use std::mem::MaybeUninit;
+
+impl IntModPoly {
+    fn check_prime_modulus(&self) -> Result<()> {
+        if self.modulus().is_prime() {
+            Ok(())
+        } else {
+            Err(Error::Msg(format!(
+                "Root finding requires a prime modulus, got {}.",
+                self.modulus()
+            )))
+        }
+    }
+
+    // `x^(p^d)` reduced modulo `self`, which has degree at least two, by
+    // repeated `p`-th powering.
+    fn frobenius_x(&self, d: u64) -> IntModPoly {
+        let p = self.modulus();
+        let mut h = IntModPoly::zero(self.context());
+        h.set_coeff(1, IntMod::one(self.context()));
+        for _ in 0..d {
+            let mut t = IntModPoly::zero(self.context());
+            unsafe {
+                fmpz_mod_poly::fmpz_mod_poly_powmod_fmpz_binexp(
+                    t.as_mut_ptr(),
+                    h.as_ptr(),
+                    p.as_ptr(),
+                    self.as_ptr(),
+                    self.ctx_as_ptr()
+                );
+            }
+            h = t;
+        }
+        h
+    }
+
+    // The degree of `gcd(x^(p^d) - x, self)`, the number of distinct roots
+    // of `self` in the field with `p^d` elements.
+    fn count_distinct_roots(&self, d: u64) -> u64 {
+        let deg = self.degree();
+        if deg < 2 {
+            return deg as u64;
+        }
+        let mut h = self.frobenius_x(d);
+        let mut x = IntModPoly::zero(self.context());
+        x.set_coeff(1, IntMod::one(self.context()));
+        h -= x;
+
+        let mut g = IntModPoly::zero(self.context());
+        unsafe {
+            fmpz_mod_poly::fmpz_mod_poly_gcd(
+                g.as_mut_ptr(),
+                h.as_ptr(),
+                self.as_ptr(),
+                self.ctx_as_ptr()
+            );
+        }
+        g.degree() as u64
+    }
+
+    /// Return the roots of the polynomial modulo a prime `p` with their
+    /// multiplicities, sorted by root. Returns an error if the modulus is not
+    /// prime or the polynomial is zero.
+    ///
+    /// The roots are found by equal-degree splitting of the product of the
+    /// distinct linear factors.
+    ///
+    /// ```
+    /// use rufl::intmod::{IntMod, IntModCtx};
+    /// use rufl::intmodpoly::IntModPoly;
+    /// use rufl::ops::{NewCtx, Pow};
+    ///
+    /// // (x - 1)^2 (x - 3) (x^2 + 1) over GF(7).
+    /// let ctx = IntModCtx::new(7);
+    /// let f = IntModPoly::new([-1, 1], &ctx).pow(2u64)
+    ///     * IntModPoly::new([-3, 1], &ctx)
+    ///     * IntModPoly::new([1, 0, 1], &ctx);
+    /// let roots = f.roots().unwrap();
+    /// assert_eq!(roots, vec![(IntMod::new(1, &ctx), 2), (IntMod::new(3, &ctx), 1)]);
+    ///
+    /// let ctx = IntModCtx::new(15);
+    /// assert!(IntModPoly::new([1, 1], &ctx).roots().is_err());
+    /// ```
+    pub fn roots(&self) -> Result<Vec<(IntMod, u64)>> {
+        self.check_prime_modulus()?;
+        if self.is_zero() {
+            return Err(Error::Msg("The zero polynomial has every element as a root.".to_string()));
+        }
+
+        let ctx = self.context();
+        let mut res = vec![];
+        unsafe {
+            let mut fac = MaybeUninit::<fmpz_mod_types::fmpz_mod_poly_factor_struct>::uninit();
+            fmpz_mod_poly_factor::fmpz_mod_poly_factor_init(fac.as_mut_ptr(), ctx.as_ptr());
+            let mut fac = fac.assume_init();
+            fmpz_mod_poly_factor::fmpz_mod_poly_roots(&mut fac, self.as_ptr(), 1, ctx.as_ptr());
+            for i in 0..fac.num as usize {
+                // Each factor is monic and linear, x - r.
+                let mut r = Integer::default();
+                fmpz_mod_poly::fmpz_mod_poly_get_coeff_fmpz(
+                    r.as_mut_ptr(),
+                    fac.poly.add(i),
+                    0,
+                    ctx.as_ptr()
+                );
+                res.push((IntMod::new(-r, ctx), *fac.exp.add(i) as u64));
+            }
+            fmpz_mod_poly_factor::fmpz_mod_poly_factor_clear(&mut fac, ctx.as_ptr());
+        }
+        res.sort_by_key(|(r, _)| Integer::from(r));
+        Ok(res)
+    }
+
+    /// Return true if the polynomial has a root modulo a prime `p`, checking
+    /// whether `gcd(x^p - x, f)` is nontrivial without finding the roots.
+    /// Returns an error if the modulus is not prime or the polynomial is
+    /// zero.
+    ///
+    /// ```
+    /// use rufl::intmod::IntModCtx;
+    /// use rufl::intmodpoly::IntModPoly;
+    /// use rufl::ops::NewCtx;
+    ///
+    /// let ctx = IntModCtx::new(7);
+    /// assert!(!IntModPoly::new([1, 0, 1], &ctx).has_root().unwrap());
+    /// assert!(IntModPoly::new([-2, 0, 1], &ctx).has_root().unwrap());
+    /// assert!(IntModPoly::zero(&ctx).has_root().is_err());
+    /// ```
+    pub fn has_root(&self) -> Result<bool> {
+        self.check_prime_modulus()?;
+        if self.is_zero() {
+            return Err(Error::Msg("The zero polynomial has every element as a root.".to_string()));
+        }
+        Ok(self.count_distinct_roots(1) > 0)
+    }
+
+    /// Return the number of distinct roots of the polynomial in the field
+    /// with `p^d` elements, where `p` is the prime modulus, as the degree of
+    /// `gcd(x^(p^d) - x, f)`. Returns an error if the modulus is not prime or
+    /// the polynomial is zero. Panics if `d` is zero.
+    ///
+    /// ```
+    /// use rufl::intmod::IntModCtx;
+    /// use rufl::intmodpoly::IntModPoly;
+    /// use rufl::ops::NewCtx;
+    ///
+    /// // x^2 + 1 is irreducible over GF(7) and splits over GF(49).
+    /// let ctx = IntModCtx::new(7);
+    /// let f = IntModPoly::new([1, 0, 1], &ctx);
+    /// assert_eq!(f.roots_in_extension_degree(1).unwrap(), 0);
+    /// assert_eq!(f.roots_in_extension_degree(2).unwrap(), 2);
+    /// assert_eq!(f.roots_in_extension_degree(3).unwrap(), 0);
+    /// ```
+    pub fn roots_in_extension_degree(&self, d: u64) -> Result<u64> {
+        assert!(d > 0, "Extension degree must be positive.");
+        self.check_prime_modulus()?;
+        if self.is_zero() {
+            return Err(Error::Msg("The zero polynomial has every element as a root.".to_string()));
+        }
+        Ok(self.count_distinct_roots(d))
+    }
+}
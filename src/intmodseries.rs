@@ -0,0 +1,395 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Truncated power series over the integers modulo `n`.
+
+mod ops;
+
+use crate::{IntMod, IntModCtx, IntModPoly, IntPoly, Integer};
+use crate::intseries::prec_si;
+use crate::ops::{Inv, NewCtx};
+use flint_sys::fmpz_mod_poly;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// A power series `f + O(x^n)` over the integers modulo `m`, stored as the
+/// polynomial `f` of length at most `n` together with the precision `n`.
+///
+/// Arithmetic tracks precision in the same way as
+/// [IntSeries](crate::IntSeries). The transcendental functions divide by the
+/// integers below the precision, which must therefore be invertible modulo
+/// `m`.
+///
+/// ```
+/// use rufl::intmod::{IntMod, IntModCtx};
+/// use rufl::intmodseries::IntModSeries;
+///
+/// let ctx = IntModCtx::new(101);
+/// let x = IntModSeries::gen(6, &ctx);
+/// assert_eq!(x.exp().log(), x);
+///
+/// // exp(x) = 1 + x + x^2/2 + ..., and 1/2 = 51 mod 101.
+/// assert_eq!(x.exp().get_coeff(2), IntMod::new(51, &ctx));
+/// ```
+#[derive(Clone, Debug)]
+pub struct IntModSeries {
+    poly: IntModPoly,
+    prec: usize,
+}
+
+impl AsRef<IntModSeries> for IntModSeries {
+    fn as_ref(&self) -> &IntModSeries {
+        self
+    }
+}
+
+impl fmt::Display for IntModSeries {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.poly.is_zero() {
+            write!(f, "O(x^{})", self.prec)
+        } else {
+            write!(f, "{} + O(x^{})", self.poly, self.prec)
+        }
+    }
+}
+
+impl Hash for IntModSeries {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.poly.hash(state);
+        self.prec.hash(state);
+    }
+}
+
+// Truncate `poly` to length `n`.
+#[inline]
+fn truncate(poly: &mut IntModPoly, n: usize) {
+    let ctx = poly.context().clone();
+    unsafe { fmpz_mod_poly::fmpz_mod_poly_truncate(poly.as_mut_ptr(), prec_si(n), ctx.as_ptr()); }
+}
+
+// The product `a b` truncated to length `n`.
+fn mullow(a: &IntModPoly, b: &IntModPoly, n: usize) -> IntModPoly {
+    let mut res = IntModPoly::zero(a.context());
+    unsafe {
+        fmpz_mod_poly::fmpz_mod_poly_mullow(
+            res.as_mut_ptr(),
+            a.as_ptr(),
+            b.as_ptr(),
+            prec_si(n),
+            a.ctx_as_ptr()
+        );
+    }
+    res
+}
+
+// The quotient `a / b` truncated to length `n`, where `b` has an invertible
+// constant term.
+fn div_series(a: &IntModPoly, b: &IntModPoly, n: usize) -> IntModPoly {
+    let mut res = IntModPoly::zero(a.context());
+    unsafe {
+        fmpz_mod_poly::fmpz_mod_poly_div_series(
+            res.as_mut_ptr(),
+            a.as_ptr(),
+            b.as_ptr(),
+            prec_si(n),
+            a.ctx_as_ptr()
+        );
+    }
+    res
+}
+
+// The composition `f(g)` truncated to length `n` by Horner's rule, where `g`
+// has zero constant term.
+fn compose_series(f: &IntModPoly, g: &IntModPoly, n: usize) -> IntModPoly {
+    let mut res = IntModPoly::zero(f.context());
+    for i in (0..f.len()).rev() {
+        res = mullow(&res, g, n);
+        res = res + IntModPoly::from(f.get_coeff(i));
+    }
+    truncate(&mut res, n);
+    res
+}
+
+fn derivative(f: &IntModPoly) -> IntModPoly {
+    let mut res = IntModPoly::zero(f.context());
+    unsafe {
+        fmpz_mod_poly::fmpz_mod_poly_derivative(res.as_mut_ptr(), f.as_ptr(), f.ctx_as_ptr());
+    }
+    res
+}
+
+// The inverse of `k` modulo `n`, panicking if it does not exist.
+fn inv_small(k: usize, ctx: &IntModCtx) -> IntMod {
+    let k = IntMod::new(Integer::from(k), ctx);
+    assert!(k.is_unit(), "Division by {} is not defined modulo {}.", k, ctx.modulus());
+    k.inv()
+}
+
+// The antiderivative of `f` with zero constant term, truncated to length `n`.
+fn integral(f: &IntModPoly, n: usize) -> IntModPoly {
+    let ctx = f.context();
+    let mut res = IntModPoly::zero(ctx);
+    for k in 1..std::cmp::min(f.len() + 1, n) {
+        res.set_coeff(k, f.get_coeff(k - 1) * inv_small(k, ctx));
+    }
+    res
+}
+
+impl IntModSeries {
+    /// Construct the series `poly + O(x^prec)`, truncating `poly`. Panics if
+    /// the precision is zero.
+    #[inline]
+    pub fn new<T: Into<IntModPoly>>(poly: T, prec: usize) -> Self {
+        assert!(prec > 0, "Precision must be positive.");
+        let mut poly = poly.into();
+        truncate(&mut poly, prec);
+        IntModSeries { poly, prec }
+    }
+
+    /// The zero series `O(x^prec)`.
+    #[inline]
+    pub fn zero(prec: usize, ctx: &IntModCtx) -> Self {
+        IntModSeries::new(IntModPoly::zero(ctx), prec)
+    }
+
+    /// The series `1 + O(x^prec)`.
+    #[inline]
+    pub fn one(prec: usize, ctx: &IntModCtx) -> Self {
+        IntModSeries::new(IntModPoly::one(ctx), prec)
+    }
+
+    /// The series `x + O(x^prec)`.
+    #[inline]
+    pub fn gen(prec: usize, ctx: &IntModCtx) -> Self {
+        IntModSeries::new(IntModPoly::new(IntPoly::from([0, 1]), ctx), prec)
+    }
+
+    #[inline]
+    pub fn context(&self) -> &IntModCtx {
+        self.poly.context()
+    }
+
+    #[inline]
+    pub fn modulus(&self) -> Integer {
+        self.poly.modulus()
+    }
+
+    /// Return the precision `n` of the series `f + O(x^n)`.
+    #[inline]
+    pub fn prec(&self) -> usize {
+        self.prec
+    }
+
+    /// Return a reference to the underlying polynomial.
+    #[inline]
+    pub fn poly(&self) -> &IntModPoly {
+        &self.poly
+    }
+
+    /// Return the underlying polynomial, discarding the precision.
+    #[inline]
+    pub fn into_poly(self) -> IntModPoly {
+        self.poly
+    }
+
+    /// Return true if the series is zero to its precision.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.poly.is_zero()
+    }
+
+    /// Return the index of the first nonzero coefficient, or the precision
+    /// if the series is zero to its precision.
+    pub fn valuation(&self) -> usize {
+        (0..self.poly.len())
+            .find(|&i| !self.poly.get_coeff(i).is_zero())
+            .unwrap_or(self.prec)
+    }
+
+    /// Return the coefficient of `x^i`. Panics if `i` is not below the
+    /// precision.
+    #[inline]
+    pub fn get_coeff(&self, i: usize) -> IntMod {
+        assert!(i < self.prec, "Coefficient is beyond the precision of the series.");
+        self.poly.get_coeff(i)
+    }
+
+    /// Return the coefficients of `x^0, ..., x^(n-1)`, where `n` is the
+    /// precision.
+    #[inline]
+    pub fn get_coeffs(&self) -> Vec<IntMod> {
+        (0..self.prec).map(|i| self.poly.get_coeff(i)).collect()
+    }
+
+    /// Reduce the precision of the series to `prec`. Does nothing if `prec`
+    /// is not smaller than the current precision.
+    #[inline]
+    pub fn truncate(&mut self, prec: usize) {
+        if prec < self.prec {
+            assert!(prec > 0, "Precision must be positive.");
+            truncate(&mut self.poly, prec);
+            self.prec = prec;
+        }
+    }
+
+    /// Return the multiplicative inverse of the series. Panics unless the
+    /// constant term is a unit.
+    #[inline]
+    pub fn inv(&self) -> IntModSeries {
+        assert!(self.poly.get_coeff(0).is_unit(), "Constant term must be a unit.");
+        let one = IntModPoly::one(self.context());
+        IntModSeries { poly: div_series(&one, &self.poly, self.prec), prec: self.prec }
+    }
+
+    /// Return the square root of the series with constant term one. Panics
+    /// unless the constant term is one and two is invertible.
+    ///
+    /// ```
+    /// use rufl::intmod::IntModCtx;
+    /// use rufl::intmodseries::IntModSeries;
+    /// use rufl::intmodpoly::IntModPoly;
+    /// use rufl::ops::NewCtx;
+    ///
+    /// let ctx = IntModCtx::new(7);
+    /// let f = IntModSeries::new(IntModPoly::new([1, 1], &ctx), 5);
+    /// let s = f.sqrt();
+    /// assert_eq!(&s * &s, f);
+    /// ```
+    pub fn sqrt(&self) -> IntModSeries {
+        let ctx = self.context();
+        assert!(self.poly.get_coeff(0).is_one(), "Constant term must be one.");
+        let half = inv_small(2, ctx);
+
+        // Match coefficients of g^2 = f with g_0 = 1.
+        let mut g = vec![IntMod::one(ctx)];
+        for k in 1..self.prec {
+            let mut t = self.poly.get_coeff(k);
+            for j in 1..k {
+                t -= &g[j] * &g[k - j];
+            }
+            g.push(t * &half);
+        }
+        IntModSeries::from_coeffs(&g, self.prec, ctx)
+    }
+
+    /// Return the exponential of the series. Panics unless the constant term
+    /// is zero.
+    pub fn exp(&self) -> IntModSeries {
+        let ctx = self.context();
+        assert!(self.poly.get_coeff(0).is_zero(), "Constant term must be zero.");
+
+        // From g' = f' g, k g_k is the sum of j f_j g_{k-j}.
+        let mut g = vec![IntMod::one(ctx)];
+        for k in 1..self.prec {
+            let mut t = IntMod::zero(ctx);
+            for j in 1..=k {
+                t += self.poly.get_coeff(j) * j as u64 * &g[k - j];
+            }
+            g.push(t * inv_small(k, ctx));
+        }
+        IntModSeries::from_coeffs(&g, self.prec, ctx)
+    }
+
+    /// Return the logarithm of the series. Panics unless the constant term
+    /// is one.
+    pub fn log(&self) -> IntModSeries {
+        assert!(self.poly.get_coeff(0).is_one(), "Constant term must be one.");
+        if self.prec == 1 {
+            return IntModSeries::zero(1, self.context());
+        }
+        let q = div_series(&derivative(&self.poly), &self.poly, self.prec - 1);
+        IntModSeries { poly: integral(&q, self.prec), prec: self.prec }
+    }
+
+    /// Return the arctangent of the series. Panics unless the constant term
+    /// is zero.
+    pub fn atan(&self) -> IntModSeries {
+        assert!(self.poly.get_coeff(0).is_zero(), "Constant term must be zero.");
+        if self.prec == 1 {
+            return IntModSeries::zero(1, self.context());
+        }
+        let n = self.prec - 1;
+        let den = mullow(&self.poly, &self.poly, n) + IntModPoly::one(self.context());
+        let q = div_series(&derivative(&self.poly), &den, n);
+        IntModSeries { poly: integral(&q, self.prec), prec: self.prec }
+    }
+
+    /// Return the composition `self(other)`. Panics unless the constant term
+    /// of `other` is zero.
+    ///
+    /// The result is known to precision `min(n_f v_g, n_g)`, where `v_g` is
+    /// the valuation of `other`.
+    pub fn compose<T: AsRef<IntModSeries>>(&self, other: T) -> IntModSeries {
+        let other = other.as_ref();
+        assert_eq!(self.context(), other.context());
+        assert!(
+            other.poly.get_coeff(0).is_zero(),
+            "Inner series must have zero constant term."
+        );
+        let prec = std::cmp::min(
+            self.prec.saturating_mul(other.valuation()),
+            other.prec
+        );
+        IntModSeries { poly: compose_series(&self.poly, &other.poly, prec), prec }
+    }
+
+    /// Return the compositional inverse `g` of the series `f`, so that
+    /// `f(g) = g(f) = x`. Panics unless the constant term is zero and the
+    /// coefficient of `x` is a unit.
+    ///
+    /// ```
+    /// use rufl::intmod::IntModCtx;
+    /// use rufl::intmodseries::IntModSeries;
+    /// use rufl::intmodpoly::IntModPoly;
+    /// use rufl::ops::NewCtx;
+    ///
+    /// let ctx = IntModCtx::new(10);
+    /// let f = IntModSeries::new(IntModPoly::new([0, 3, 1, 4], &ctx), 8);
+    /// let g = f.revert();
+    /// assert_eq!(f.compose(&g), IntModSeries::gen(8, &ctx));
+    /// ```
+    pub fn revert(&self) -> IntModSeries {
+        let ctx = self.context();
+        assert!(self.poly.get_coeff(0).is_zero(), "Constant term must be zero.");
+        let c = self.poly.get_coeff(1);
+        assert!(c.is_unit(), "Linear coefficient must be a unit.");
+
+        // Newton iteration g <- g - (f(g) - x)/f'(g), doubling the precision.
+        let n = self.prec;
+        let x = IntModPoly::new(IntPoly::from([0, 1]), ctx);
+        let df = derivative(&self.poly);
+        let mut g = IntModPoly::zero(ctx);
+        g.set_coeff(1, c.inv());
+        let mut m = 2;
+        while m < n {
+            m = std::cmp::min(2 * m, n);
+            let h = compose_series(&self.poly, &g, m) - &x;
+            let d = compose_series(&df, &g, m);
+            g = g - div_series(&h, &d, m);
+        }
+        truncate(&mut g, n);
+        IntModSeries { poly: g, prec: n }
+    }
+
+    fn from_coeffs(coeffs: &[IntMod], prec: usize, ctx: &IntModCtx) -> IntModSeries {
+        let mut poly = IntModPoly::zero(ctx);
+        for (i, c) in coeffs.iter().enumerate() {
+            poly.set_coeff(i, c);
+        }
+        IntModSeries::new(poly, prec)
+    }
+}
@@ -0,0 +1,103 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::IntModSeries;
+use crate::intmodseries::{div_series, mullow};
+use crate::ops::*;
+use std::cmp::min;
+
+impl_cmp! {
+    eq
+    IntModSeries
+    {
+        fn eq(&self, rhs: &IntModSeries) -> bool {
+            self.prec() == rhs.prec() && self.poly() == rhs.poly()
+        }
+    }
+}
+
+impl_unop! {
+    IntModSeries
+    Neg {neg}
+    {
+        fn neg(self) -> IntModSeries {
+            IntModSeries::new(-self.poly(), self.prec())
+        }
+    }
+    NegAssign {neg_assign}
+    {
+        fn neg_assign(&mut self) {
+            *self = -&*self;
+        }
+    }
+}
+
+fn add(lhs: &IntModSeries, rhs: &IntModSeries) -> IntModSeries {
+    assert_eq!(lhs.context(), rhs.context());
+    IntModSeries::new(lhs.poly() + rhs.poly(), min(lhs.prec(), rhs.prec()))
+}
+
+fn sub(lhs: &IntModSeries, rhs: &IntModSeries) -> IntModSeries {
+    assert_eq!(lhs.context(), rhs.context());
+    IntModSeries::new(lhs.poly() - rhs.poly(), min(lhs.prec(), rhs.prec()))
+}
+
+fn mul(lhs: &IntModSeries, rhs: &IntModSeries) -> IntModSeries {
+    assert_eq!(lhs.context(), rhs.context());
+    let prec = min(
+        lhs.prec() + rhs.valuation(),
+        rhs.prec() + lhs.valuation()
+    );
+    IntModSeries::new(mullow(lhs.poly(), rhs.poly(), prec), prec)
+}
+
+fn div(lhs: &IntModSeries, rhs: &IntModSeries) -> IntModSeries {
+    assert_eq!(lhs.context(), rhs.context());
+    assert!(
+        rhs.poly().get_coeff(0).is_unit(),
+        "Constant term of the divisor must be a unit."
+    );
+    let prec = min(lhs.prec(), rhs.prec() + lhs.valuation());
+    IntModSeries::new(div_series(lhs.poly(), rhs.poly(), prec), prec)
+}
+
+impl_binop_series! {
+    IntModSeries
+    Add {add}
+    AddAssign {add_assign}
+    AddFrom {add_from}
+    AssignAdd {assign_add}
+    add;
+
+    Sub {sub}
+    SubAssign {sub_assign}
+    SubFrom {sub_from}
+    AssignSub {assign_sub}
+    sub;
+
+    Mul {mul}
+    MulAssign {mul_assign}
+    MulFrom {mul_from}
+    AssignMul {assign_mul}
+    mul;
+
+    Div {div}
+    DivAssign {div_assign}
+    DivFrom {div_from}
+    AssignDiv {assign_div}
+    div;
+}
@@ -0,0 +1,243 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Integers modulo a word-size `n`, backed by FLINT's `nmod` layer.
+//!
+//! These avoid the bignum residues of [IntMod](crate::IntMod) when the modulus fits in a
+//! machine word, using a precomputed inverse of the modulus for reduction.
+
+mod ops;
+mod conv;
+
+use crate::Integer;
+use flint_sys::{flint, fmpz, nmod, ulong_extras};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::mem::MaybeUninit;
+
+/// Context for the ring of integers modulo a word-size `n`.
+#[derive(Clone, Copy, Debug)]
+pub struct IntModWordCtx {
+    inner: flint::nmod_t,
+}
+
+impl Eq for IntModWordCtx {}
+
+impl PartialEq for IntModWordCtx {
+    #[inline]
+    fn eq(&self, rhs: &IntModWordCtx) -> bool {
+        self.modulus() == rhs.modulus()
+    }
+}
+
+impl fmt::Display for IntModWordCtx {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Context for ring of integers modulo {}", self.modulus())
+    }
+}
+
+impl Hash for IntModWordCtx {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.modulus().hash(state)
+    }
+}
+
+impl IntModWordCtx {
+    /// Construct the context for the integers modulo `modulus`. Panics if the
+    /// modulus is zero.
+    ///
+    /// ```
+    /// use rufl::intmodword::IntModWordCtx;
+    ///
+    /// let ctx = IntModWordCtx::new(17);
+    /// assert_eq!(ctx.modulus(), 17);
+    /// ```
+    #[inline]
+    pub fn new(modulus: u64) -> Self {
+        assert!(modulus > 0);
+        let mut z = MaybeUninit::uninit();
+        unsafe {
+            nmod::nmod_init(z.as_mut_ptr(), modulus);
+            IntModWordCtx { inner: z.assume_init() }
+        }
+    }
+
+    /// Returns a pointer to the inner [FLINT modulus][flint::nmod_t].
+    #[inline]
+    pub const fn as_ptr(&self) -> *const flint::nmod_t {
+        &self.inner
+    }
+
+    /// Returns a copy of the inner [FLINT modulus][flint::nmod_t], as taken
+    /// by value by the `nmod` functions.
+    #[inline]
+    pub const fn nmod(&self) -> flint::nmod_t {
+        self.inner
+    }
+
+    #[inline]
+    pub const fn modulus(&self) -> u64 {
+        self.inner.n
+    }
+
+    // Reduce a word modulo n using the precomputed inverse.
+    #[inline]
+    pub(crate) fn reduce(&self, x: u64) -> u64 {
+        unsafe { ulong_extras::n_mod2_preinv(x, self.inner.n, self.inner.ninv) }
+    }
+
+    // Reduce an integer modulo n.
+    #[inline]
+    pub(crate) fn reduce_fmpz(&self, x: &Integer) -> u64 {
+        unsafe { fmpz::fmpz_get_nmod(x.as_ptr(), self.inner) }
+    }
+}
+
+/// An integer modulo a word-size `n`.
+///
+/// Inversion, division and negative powers panic unless the element being
+/// inverted is a unit.
+///
+/// ```
+/// use rufl::intmodword::{IntModWord, IntModWordCtx};
+/// use rufl::ops::Pow;
+///
+/// let ctx = IntModWordCtx::new(101);
+/// let x = IntModWord::new(10, &ctx);
+/// let y = IntModWord::new(99, &ctx);
+/// assert_eq!((x + y).get_ui(), 8);
+/// assert_eq!((x * y).get_ui(), 81);
+/// assert_eq!((x / y * y), x);
+/// assert_eq!(x.pow(100u64).get_ui(), 1);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct IntModWord {
+    pub(crate) inner: u64,
+    pub(crate) ctx: IntModWordCtx,
+}
+
+impl AsRef<IntModWord> for IntModWord {
+    #[inline]
+    fn as_ref(&self) -> &IntModWord {
+        self
+    }
+}
+
+impl fmt::Display for IntModWord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl Hash for IntModWord {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.context().hash(state);
+        self.inner.hash(state);
+    }
+}
+
+impl IntModWord {
+    /// Construct an `IntModWord` from anything convertible to an [Integer],
+    /// reducing it modulo `n`.
+    ///
+    /// ```
+    /// use rufl::intmodword::{IntModWord, IntModWordCtx};
+    ///
+    /// let ctx = IntModWordCtx::new(7);
+    /// assert_eq!(IntModWord::new(-1, &ctx).get_ui(), 6);
+    /// ```
+    #[inline]
+    pub fn new<T: Into<Integer>>(src: T, ctx: &IntModWordCtx) -> Self {
+        IntModWord { inner: ctx.reduce_fmpz(&src.into()), ctx: *ctx }
+    }
+
+    /// Construct an `IntModWord` from a word, reducing it modulo `n`.
+    #[inline]
+    pub fn from_ui(src: u64, ctx: &IntModWordCtx) -> Self {
+        IntModWord { inner: ctx.reduce(src), ctx: *ctx }
+    }
+
+    /// Construct an `IntModWord` from a raw word and a context. This does not
+    /// reduce the input!
+    #[inline]
+    pub const unsafe fn from_raw(inner: u64, ctx: IntModWordCtx) -> Self {
+        IntModWord { inner, ctx }
+    }
+
+    #[inline]
+    pub fn zero(ctx: &IntModWordCtx) -> IntModWord {
+        IntModWord { inner: 0, ctx: *ctx }
+    }
+
+    #[inline]
+    pub fn one(ctx: &IntModWordCtx) -> IntModWord {
+        IntModWord::from_ui(1, ctx)
+    }
+
+    #[inline]
+    pub fn zero_mut(&mut self) {
+        self.inner = 0;
+    }
+
+    #[inline]
+    pub fn one_mut(&mut self) {
+        self.inner = self.ctx.reduce(1);
+    }
+
+    #[inline]
+    pub const fn context(&self) -> &IntModWordCtx {
+        &self.ctx
+    }
+
+    /// Return the modulus of `IntModWord`.
+    #[inline]
+    pub const fn modulus(&self) -> u64 {
+        self.ctx.modulus()
+    }
+
+    /// Return the canonical representative in `[0, n)`.
+    #[inline]
+    pub const fn get_ui(&self) -> u64 {
+        self.inner
+    }
+
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.inner == 0
+    }
+
+    #[inline]
+    pub fn is_one(&self) -> bool {
+        self.inner == self.ctx.reduce(1)
+    }
+
+    /// Return true if the residue is invertible, that is, coprime to the
+    /// modulus.
+    ///
+    /// ```
+    /// use rufl::intmodword::{IntModWord, IntModWordCtx};
+    ///
+    /// let ctx = IntModWordCtx::new(10);
+    /// assert!(IntModWord::from_ui(3, &ctx).is_unit());
+    /// assert!(!IntModWord::from_ui(4, &ctx).is_unit());
+    /// ```
+    #[inline]
+    pub fn is_unit(&self) -> bool {
+        unsafe { ulong_extras::n_gcd(self.inner, self.modulus()) == 1 }
+    }
+}
@@ -0,0 +1,71 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::{Error, IntMod, IntModCtx, IntModWord, IntModWordCtx, Integer};
+
+impl_from! {
+    u64, IntModWord
+    {
+        fn from(src: &IntModWord) -> u64 {
+            src.get_ui()
+        }
+    }
+}
+
+impl_from! {
+    IntMod, IntModWord
+    {
+        fn from(src: &IntModWord) -> IntMod {
+            IntMod::new(src.get_ui(), &IntModCtx::from(src.context()))
+        }
+    }
+}
+
+impl From<&IntModWordCtx> for IntModCtx {
+    #[inline]
+    fn from(src: &IntModWordCtx) -> IntModCtx {
+        IntModCtx::new(src.modulus())
+    }
+}
+
+impl TryFrom<&IntModCtx> for IntModWordCtx {
+    type Error = Error;
+    fn try_from(src: &IntModCtx) -> crate::Result<Self> {
+        let n = src.modulus();
+        n.get_ui().map(IntModWordCtx::new).ok_or_else(|| Error::ConversionError {
+            val: n.to_string(),
+            in_type: "IntModCtx".to_string(),
+            out_type: "IntModWordCtx".to_string(),
+        })
+    }
+}
+
+impl TryFrom<&IntMod> for IntModWord {
+    type Error = Error;
+    fn try_from(src: &IntMod) -> crate::Result<Self> {
+        let ctx = IntModWordCtx::try_from(src.context())?;
+        Ok(IntModWord::new(Integer::from(src), &ctx))
+    }
+}
+
+impl TryFrom<IntMod> for IntModWord {
+    type Error = Error;
+    #[inline]
+    fn try_from(src: IntMod) -> crate::Result<Self> {
+        IntModWord::try_from(&src)
+    }
+}
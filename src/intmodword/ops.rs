@@ -0,0 +1,283 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::{IntModWord, Integer};
+use crate::ops::*;
+use flint_sys::{nmod, ulong_extras};
+
+impl_cmp! {
+    eq
+    IntModWord
+    {
+        fn eq(&self, rhs: &IntModWord) -> bool {
+            self.context() == rhs.context() && self.inner == rhs.inner
+        }
+    }
+}
+
+impl_cmp! {
+    partial_eq
+    IntModWord, u64
+    {
+        fn eq(&self, rhs: &u64) -> bool {
+            self.inner == *rhs
+        }
+    }
+}
+
+impl_unop! {
+    IntModWord
+    Neg {neg}
+    {
+        fn neg(self) -> IntModWord {
+            let mut res = *self;
+            res.neg_assign();
+            res
+        }
+    }
+    NegAssign {neg_assign}
+    {
+        fn neg_assign(&mut self) {
+            self.inner = unsafe { nmod::nmod_neg(self.inner, self.ctx.nmod()) };
+        }
+    }
+}
+
+impl_unop! {
+    IntModWord
+    Inv {inv}
+    {
+        fn inv(self) -> IntModWord {
+            let mut res = *self;
+            res.inv_assign();
+            res
+        }
+    }
+    InvAssign {inv_assign}
+    {
+        fn inv_assign(&mut self) {
+            assert!(self.is_unit(), "IntModWord is not invertible.");
+            self.inner = unsafe { nmod::nmod_inv(self.inner, self.ctx.nmod()) };
+        }
+    }
+}
+
+// `nmod_div` aborts when the divisor is not a unit, so check it first.
+unsafe fn nmod_div_checked(a: u64, b: u64, modulus: flint_sys::flint::nmod_t) -> u64 {
+    assert!(
+        ulong_extras::n_gcd(b, modulus.n) == 1,
+        "Divisor is not invertible."
+    );
+    nmod::nmod_div(a, b, modulus)
+}
+
+// Arithmetic between two `IntModWord`s with a word-level `nmod` function.
+macro_rules! impl_binop_nmod {
+    (
+        $(
+            $op:ident {$meth:ident}
+            $op_assign:ident {$meth_assign:ident}
+            $op_from:ident {$meth_from:ident}
+            $assign_op:ident {$assign_meth:ident}
+            $func:path;
+        )+
+    ) => ($(
+        impl_binop! {
+            IntModWord, IntModWord, IntModWord
+            $op {$meth}
+            {
+                fn $meth(self, rhs: &IntModWord) -> IntModWord {
+                    let mut res = *self;
+                    res.$meth_assign(rhs);
+                    res
+                }
+            }
+            $op_assign {$meth_assign}
+            {
+                fn $meth_assign(&mut self, rhs: &IntModWord) {
+                    assert_eq!(self.context(), rhs.context());
+                    self.inner = unsafe { $func(self.inner, rhs.inner, self.ctx.nmod()) };
+                }
+            }
+            $op_from {$meth_from}
+            {
+                fn $meth_from(&mut self, lhs: &IntModWord) {
+                    assert_eq!(lhs.context(), self.context());
+                    self.inner = unsafe { $func(lhs.inner, self.inner, self.ctx.nmod()) };
+                }
+            }
+            $assign_op {$assign_meth}
+            {
+                fn $assign_meth(&mut self, lhs: &IntModWord, rhs: &IntModWord) {
+                    assert_eq!(lhs.context(), rhs.context());
+                    self.ctx = lhs.ctx;
+                    self.inner = unsafe { $func(lhs.inner, rhs.inner, lhs.ctx.nmod()) };
+                }
+            }
+        }
+    )+);
+}
+
+// Arithmetic of an `IntModWord` with a word, reduced before the operation.
+macro_rules! impl_binop_nmod_ui {
+    (
+        $(
+            $op:ident {$meth:ident}
+            $op_assign:ident {$meth_assign:ident}
+            $assign_op:ident {$assign_meth:ident}
+            $func:path;
+        )+
+    ) => ($(
+        impl_binop! {
+            op_assign
+            IntModWord, u64, IntModWord
+            $op {$meth}
+            {
+                fn $meth(self, rhs: &u64) -> IntModWord {
+                    let mut res = *self;
+                    res.$meth_assign(rhs);
+                    res
+                }
+            }
+            $op_assign {$meth_assign}
+            {
+                fn $meth_assign(&mut self, rhs: &u64) {
+                    let rhs = self.ctx.reduce(*rhs);
+                    self.inner = unsafe { $func(self.inner, rhs, self.ctx.nmod()) };
+                }
+            }
+            $assign_op {$assign_meth}
+            {
+                fn $assign_meth(&mut self, lhs: &IntModWord, rhs: &u64) {
+                    *self = *lhs;
+                    self.$meth_assign(rhs);
+                }
+            }
+        }
+    )+);
+}
+
+impl_binop_nmod! {
+    Add {add}
+    AddAssign {add_assign}
+    AddFrom {add_from}
+    AssignAdd {assign_add}
+    nmod::nmod_add;
+
+    Sub {sub}
+    SubAssign {sub_assign}
+    SubFrom {sub_from}
+    AssignSub {assign_sub}
+    nmod::nmod_sub;
+
+    Mul {mul}
+    MulAssign {mul_assign}
+    MulFrom {mul_from}
+    AssignMul {assign_mul}
+    nmod::nmod_mul;
+
+    Div {div}
+    DivAssign {div_assign}
+    DivFrom {div_from}
+    AssignDiv {assign_div}
+    nmod_div_checked;
+}
+
+impl_binop_nmod_ui! {
+    Add {add}
+    AddAssign {add_assign}
+    AssignAdd {assign_add}
+    nmod::nmod_add;
+
+    Sub {sub}
+    SubAssign {sub_assign}
+    AssignSub {assign_sub}
+    nmod::nmod_sub;
+
+    Mul {mul}
+    MulAssign {mul_assign}
+    AssignMul {assign_mul}
+    nmod::nmod_mul;
+
+    Div {div}
+    DivAssign {div_assign}
+    AssignDiv {assign_div}
+    nmod_div_checked;
+}
+
+impl_binop! {
+    op_assign
+    IntModWord, u64, IntModWord
+    Pow {pow}
+    {
+        fn pow(self, rhs: &u64) -> IntModWord {
+            let mut res = *self;
+            res.pow_assign(rhs);
+            res
+        }
+    }
+    PowAssign {pow_assign}
+    {
+        fn pow_assign(&mut self, rhs: &u64) {
+            self.inner = unsafe { nmod::nmod_pow_ui(self.inner, *rhs, self.ctx.nmod()) };
+        }
+    }
+    AssignPow {assign_pow}
+    {
+        fn assign_pow(&mut self, lhs: &IntModWord, rhs: &u64) {
+            *self = *lhs;
+            self.pow_assign(rhs);
+        }
+    }
+}
+
+impl_binop! {
+    op_assign
+    IntModWord, Integer, IntModWord
+    Pow {pow}
+    {
+        fn pow(self, rhs: &Integer) -> IntModWord {
+            let mut res = *self;
+            res.pow_assign(rhs);
+            res
+        }
+    }
+    PowAssign {pow_assign}
+    {
+        fn pow_assign(&mut self, rhs: &Integer) {
+            // Negative exponents require the base to be invertible.
+            if rhs < &0 {
+                self.inv_assign();
+                let e = -rhs;
+                self.inner = unsafe {
+                    nmod::nmod_pow_fmpz(self.inner, e.as_ptr(), self.ctx.nmod())
+                };
+            } else {
+                self.inner = unsafe {
+                    nmod::nmod_pow_fmpz(self.inner, rhs.as_ptr(), self.ctx.nmod())
+                };
+            }
+        }
+    }
+    AssignPow {assign_pow}
+    {
+        fn assign_pow(&mut self, lhs: &IntModWord, rhs: &Integer) {
+            *self = *lhs;
+            self.pow_assign(rhs);
+        }
+    }
+}
@@ -0,0 +1,359 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Matrices over the integers modulo a word-size `n`, backed by FLINT's
+//! `nmod_mat`.
+
+mod ops;
+
+use crate::*;
+use flint_sys::nmod_types::nmod_mat_struct;
+use flint_sys::nmod_mat::*;
+use flint_sys::ulong_extras;
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::mem::{ManuallyDrop, MaybeUninit};
+
+
+#[derive(Debug)]
+pub struct IntModWordMat {
+    inner: nmod_mat_struct,
+    ctx: IntModWordCtx,
+}
+
+impl AsRef<IntModWordMat> for IntModWordMat {
+    fn as_ref(&self) -> &IntModWordMat {
+        self
+    }
+}
+
+impl Clone for IntModWordMat {
+    #[inline]
+    fn clone(&self) -> Self {
+        let mut z = MaybeUninit::uninit();
+        unsafe {
+            nmod_mat_init_set(z.as_mut_ptr(), self.as_ptr());
+            IntModWordMat::from_raw(z.assume_init(), *self.context())
+        }
+    }
+}
+
+impl fmt::Display for IntModWordMat {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", IntMat::from(self))
+    }
+}
+
+impl Drop for IntModWordMat {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { nmod_mat_clear(self.as_mut_ptr()) }
+    }
+}
+
+impl Hash for IntModWordMat {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.context().hash(state);
+        self.get_entries_ui().hash(state);
+    }
+}
+
+impl IntModWordMat {
+    /// Returns a pointer to the inner [FLINT matrix][nmod_mat_struct].
+    #[inline]
+    pub const fn as_ptr(&self) -> *const nmod_mat_struct {
+        &self.inner
+    }
+
+    /// Returns a mutable pointer to the inner [FLINT matrix][nmod_mat_struct].
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut nmod_mat_struct {
+        &mut self.inner
+    }
+
+    #[inline]
+    pub const unsafe fn from_raw(inner: nmod_mat_struct, ctx: IntModWordCtx) -> Self {
+        IntModWordMat { inner, ctx }
+    }
+
+    #[inline]
+    pub const fn into_raw(self) -> nmod_mat_struct {
+        let inner = self.inner;
+        let _ = ManuallyDrop::new(self);
+        inner
+    }
+
+    #[inline]
+    fn check_indices(&self, i: usize, j: usize) -> (i64, i64) {
+        let i = i.try_into().expect("Cannot convert index to a signed long.");
+        let j = j.try_into().expect("Cannot convert index to a signed long.");
+        assert!(i < self.nrows_si());
+        assert!(j < self.ncols_si());
+        (i, j)
+    }
+
+    #[inline]
+    fn check_prime(&self) {
+        let prime = unsafe { ulong_extras::n_is_prime(self.modulus()) } != 0;
+        assert!(prime, "IntModWordMat modulus is not prime.");
+    }
+
+    /// Construct a matrix from a slice of entries in row-major order,
+    /// reducing each modulo `n`.
+    ///
+    /// ```
+    /// use rufl::intmodword::IntModWordCtx;
+    /// use rufl::intmodwordmat::IntModWordMat;
+    ///
+    /// let ctx = IntModWordCtx::new(7);
+    /// let m = IntModWordMat::new(&[1, 2, 3, -1], 2, 2, &ctx);
+    /// assert_eq!(m.get_entry(1, 1).get_ui(), 6);
+    /// ```
+    pub fn new<'a, T>(src: &'a [T], nrows: i64, ncols: i64, ctx: &IntModWordCtx) -> Self
+    where
+        &'a T: Into<Integer>
+    {
+        let ncols_ui: usize = ncols.try_into().expect(
+            "Cannot convert signed long to usize.");
+        let nrows_ui: usize = nrows.try_into().expect(
+            "Cannot convert signed long to usize.");
+        assert_eq!(src.len(), nrows_ui * ncols_ui);
+
+        let mut res = IntModWordMat::zero(nrows, ncols, ctx);
+        for (k, x) in src.iter().enumerate() {
+            let e = ctx.reduce_fmpz(&x.into());
+            unsafe {
+                nmod_mat_set_entry(
+                    res.as_mut_ptr(),
+                    (k / ncols_ui) as i64,
+                    (k % ncols_ui) as i64,
+                    e
+                );
+            }
+        }
+        res
+    }
+
+    #[inline]
+    pub fn zero(nrows: i64, ncols: i64, ctx: &IntModWordCtx) -> IntModWordMat {
+        let mut z = MaybeUninit::uninit();
+        unsafe {
+            nmod_mat_init(z.as_mut_ptr(), nrows, ncols, ctx.modulus());
+            IntModWordMat::from_raw(z.assume_init(), *ctx)
+        }
+    }
+
+    #[inline]
+    pub fn one(dim: i64, ctx: &IntModWordCtx) -> IntModWordMat {
+        let mut res = IntModWordMat::zero(dim, dim, ctx);
+        unsafe { nmod_mat_one(res.as_mut_ptr()); }
+        res
+    }
+
+    #[inline]
+    pub fn context(&self) -> &IntModWordCtx {
+        &self.ctx
+    }
+
+    #[inline]
+    pub fn modulus(&self) -> u64 {
+        self.context().modulus()
+    }
+
+    /// Return the number of rows.
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.nrows_si().try_into().expect("Cannot convert signed long to usize.")
+    }
+
+    /// Return the number of rows.
+    #[inline]
+    pub fn nrows_si(&self) -> i64 {
+        self.inner.r
+    }
+
+    /// Return the number of columns.
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.ncols_si().try_into().expect("Cannot convert signed long to usize.")
+    }
+
+    /// Return the number of columns.
+    #[inline]
+    pub fn ncols_si(&self) -> i64 {
+        self.inner.c
+    }
+
+    #[inline]
+    pub fn is_square(&self) -> bool {
+        self.nrows_si() == self.ncols_si()
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        unsafe { nmod_mat_is_zero(self.as_ptr()) != 0 }
+    }
+
+    #[inline]
+    pub fn is_one(&self) -> bool {
+        unsafe { nmod_mat_is_one(self.as_ptr()) != 0 }
+    }
+
+    /// Get the `(i, j)`-th entry of the matrix.
+    #[inline]
+    pub fn get_entry(&self, i: usize, j: usize) -> IntModWord {
+        let (i, j) = self.check_indices(i, j);
+        unsafe {
+            IntModWord::from_raw(nmod_mat_get_entry(self.as_ptr(), i, j), *self.context())
+        }
+    }
+
+    /// Set the `(i, j)`-th entry of the matrix.
+    #[inline]
+    pub fn set_entry<T: AsRef<IntModWord>>(&mut self, i: usize, j: usize, e: T) {
+        let e = e.as_ref();
+        assert_eq!(self.context(), e.context());
+        let (i, j) = self.check_indices(i, j);
+        unsafe { nmod_mat_set_entry(self.as_mut_ptr(), i, j, e.get_ui()); }
+    }
+
+    /// Get a vector with all of the entries of the matrix in row-major order.
+    pub fn get_entries(&self) -> Vec<IntModWord> {
+        let (r, c) = (self.nrows(), self.ncols());
+        let mut out = Vec::with_capacity(r * c);
+        for i in 0..r {
+            for j in 0..c {
+                out.push(self.get_entry(i, j));
+            }
+        }
+        out
+    }
+
+    /// Get a vector with all of the entries of the matrix as words in
+    /// `[0, n)`, in row-major order.
+    #[inline]
+    pub fn get_entries_ui(&self) -> Vec<u64> {
+        self.get_entries().iter().map(IntModWord::get_ui).collect()
+    }
+
+    #[inline]
+    pub fn transpose(&self) -> IntModWordMat {
+        let mut res = IntModWordMat::zero(self.ncols_si(), self.nrows_si(), self.context());
+        unsafe { nmod_mat_transpose(res.as_mut_ptr(), self.as_ptr()); }
+        res
+    }
+
+    /// Return the trace of a square matrix.
+    #[inline]
+    pub fn trace(&self) -> IntModWord {
+        assert!(self.is_square());
+        unsafe { IntModWord::from_raw(nmod_mat_trace(self.as_ptr()), *self.context()) }
+    }
+
+    /// Return the determinant of a square matrix.
+    ///
+    /// ```
+    /// use rufl::intmodword::IntModWordCtx;
+    /// use rufl::intmodwordmat::IntModWordMat;
+    ///
+    /// let ctx = IntModWordCtx::new(7);
+    /// let m = IntModWordMat::new(&[1, 2, 3, 4], 2, 2, &ctx);
+    /// assert_eq!(m.det().get_ui(), 5);
+    /// ```
+    #[inline]
+    pub fn det(&self) -> IntModWord {
+        assert!(self.is_square());
+        unsafe { IntModWord::from_raw(nmod_mat_det(self.as_ptr()), *self.context()) }
+    }
+
+    /// Return the rank of the matrix. Panics if the modulus is not prime.
+    ///
+    /// ```
+    /// use rufl::intmodword::IntModWordCtx;
+    /// use rufl::intmodwordmat::IntModWordMat;
+    ///
+    /// let ctx = IntModWordCtx::new(7);
+    /// let m = IntModWordMat::new(&[1, 2, 2, 4], 2, 2, &ctx);
+    /// assert_eq!(m.rank(), 1);
+    /// ```
+    #[inline]
+    pub fn rank(&self) -> i64 {
+        self.check_prime();
+        unsafe { nmod_mat_rank(self.as_ptr()) }
+    }
+
+    /// Return the inverse of a square matrix, or `None` if it is singular.
+    /// Panics if the modulus is not prime.
+    ///
+    /// ```
+    /// use rufl::intmodword::IntModWordCtx;
+    /// use rufl::intmodwordmat::IntModWordMat;
+    ///
+    /// let ctx = IntModWordCtx::new(7);
+    /// let m = IntModWordMat::new(&[1, 2, 3, 4], 2, 2, &ctx);
+    /// let inv = m.inv().unwrap();
+    /// assert!((&m * &inv).is_one());
+    /// ```
+    pub fn inv(&self) -> Option<IntModWordMat> {
+        assert!(self.is_square());
+        self.check_prime();
+        let mut res = IntModWordMat::zero(self.nrows_si(), self.ncols_si(), self.context());
+        if unsafe { nmod_mat_inv(res.as_mut_ptr(), self.as_ptr()) } != 0 {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Return the rank and reduced row echelon form of the matrix. Panics if
+    /// the modulus is not prime.
+    #[inline]
+    pub fn rref(&self) -> (i64, IntModWordMat) {
+        self.check_prime();
+        let mut res = self.clone();
+        let rank = unsafe { nmod_mat_rref(res.as_mut_ptr()) };
+        (rank, res)
+    }
+
+    /// Return `X` such that `self * X = rhs`, or `None` if `self` is
+    /// singular. Panics if the modulus is not prime.
+    pub fn solve<T: AsRef<IntModWordMat>>(&self, rhs: T) -> Option<IntModWordMat> {
+        let rhs = rhs.as_ref();
+        assert!(self.is_square());
+        self.check_prime();
+        assert_eq!(self.context(), rhs.context());
+        assert_eq!(self.nrows_si(), rhs.nrows_si());
+        let mut res = IntModWordMat::zero(self.ncols_si(), rhs.ncols_si(), self.context());
+        if unsafe { nmod_mat_solve(res.as_mut_ptr(), self.as_ptr(), rhs.as_ptr()) } != 0 {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Return the characteristic polynomial of a square matrix.
+    #[inline]
+    pub fn charpoly(&self) -> IntModWordPoly {
+        assert!(self.is_square());
+        let mut res = IntModWordPoly::zero(self.context());
+        unsafe { nmod_mat_charpoly(res.as_mut_ptr(), self.as_ptr()); }
+        res
+    }
+}
@@ -0,0 +1,117 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::{IntModWord, IntModWordMat};
+use crate::ops::*;
+use flint_sys::nmod_mat;
+
+impl_cmp! {
+    eq
+    IntModWordMat
+    {
+        fn eq(&self, rhs: &IntModWordMat) -> bool {
+            self.context() == rhs.context() &&
+                unsafe { nmod_mat::nmod_mat_equal(self.as_ptr(), rhs.as_ptr()) != 0 }
+        }
+    }
+}
+
+impl_unop_unsafe! {
+    matrix_ctx_new_only
+    IntModWordMat
+    Neg {neg}
+    NegAssign {neg_assign}
+    nmod_mat::nmod_mat_neg
+}
+
+impl_binop_unsafe! {
+    matrix_ctx_new_only
+    IntModWordMat, IntModWordMat, IntModWordMat
+
+    Add {add}
+    AddAssign {add_assign}
+    AddFrom {add_from}
+    AssignAdd {assign_add}
+    nmod_mat::nmod_mat_add;
+
+    Sub {sub}
+    SubAssign {sub_assign}
+    SubFrom {sub_from}
+    AssignSub {assign_sub}
+    nmod_mat::nmod_mat_sub;
+
+    Mul {mul}
+    MulAssign {mul_assign}
+    MulFrom {mul_from}
+    AssignMul {assign_mul}
+    nmod_mat::nmod_mat_mul;
+}
+
+impl_binop! {
+    op_assign
+    IntModWordMat, IntModWord, IntModWordMat
+    Mul {mul}
+    {
+        fn mul(self, rhs: &IntModWord) -> IntModWordMat {
+            let mut res = self.clone();
+            res.mul_assign(rhs);
+            res
+        }
+    }
+    MulAssign {mul_assign}
+    {
+        fn mul_assign(&mut self, rhs: &IntModWord) {
+            assert_eq!(self.context(), rhs.context());
+            unsafe {
+                nmod_mat::nmod_mat_scalar_mul(self.as_mut_ptr(), self.as_ptr(), rhs.get_ui());
+            }
+        }
+    }
+    AssignMul {assign_mul}
+    {
+        fn assign_mul(&mut self, lhs: &IntModWordMat, rhs: &IntModWord) {
+            *self = lhs.clone();
+            self.mul_assign(rhs);
+        }
+    }
+}
+
+impl_binop! {
+    op_assign
+    IntModWordMat, u64, IntModWordMat
+    Pow {pow}
+    {
+        fn pow(self, rhs: &u64) -> IntModWordMat {
+            assert!(self.is_square());
+            let mut res = IntModWordMat::zero(self.nrows_si(), self.ncols_si(), self.context());
+            unsafe { nmod_mat::nmod_mat_pow(res.as_mut_ptr(), self.as_ptr(), *rhs); }
+            res
+        }
+    }
+    PowAssign {pow_assign}
+    {
+        fn pow_assign(&mut self, rhs: &u64) {
+            *self = (&*self).pow(rhs);
+        }
+    }
+    AssignPow {assign_pow}
+    {
+        fn assign_pow(&mut self, lhs: &IntModWordMat, rhs: &u64) {
+            *self = lhs.pow(rhs);
+        }
+    }
+}
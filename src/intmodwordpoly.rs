@@ -0,0 +1,274 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Polynomials over the integers modulo a word-size `n`, backed by FLINT's
+//! `nmod_poly`.
+
+mod ops;
+mod conv;
+
+use crate::*;
+use flint_sys::nmod_types::nmod_poly_struct;
+use flint_sys::nmod_poly::*;
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::mem::{ManuallyDrop, MaybeUninit};
+
+
+#[derive(Debug)]
+pub struct IntModWordPoly {
+    inner: nmod_poly_struct,
+    ctx: IntModWordCtx,
+}
+
+impl AsRef<IntModWordPoly> for IntModWordPoly {
+    #[inline]
+    fn as_ref(&self) -> &IntModWordPoly {
+        self
+    }
+}
+
+impl Clone for IntModWordPoly {
+    #[inline]
+    fn clone(&self) -> Self {
+        let mut res = IntModWordPoly::zero(self.context());
+        unsafe { nmod_poly_set(res.as_mut_ptr(), self.as_ptr()); }
+        res
+    }
+}
+
+impl fmt::Display for IntModWordPoly {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", IntPoly::from(self))
+    }
+}
+
+impl Drop for IntModWordPoly {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { nmod_poly_clear(self.as_mut_ptr()) }
+    }
+}
+
+impl Hash for IntModWordPoly {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.context().hash(state);
+        self.get_coeffs_ui().hash(state);
+    }
+}
+
+impl<T: Into<IntPoly>> NewCtx<T, IntModWordCtx> for IntModWordPoly {
+    fn new(src: T, ctx: &IntModWordCtx) -> Self {
+        let mut res = IntModWordPoly::zero(ctx);
+        unsafe {
+            flint_sys::fmpz_poly::fmpz_poly_get_nmod_poly(
+                res.as_mut_ptr(),
+                src.into().as_ptr()
+            );
+        }
+        res
+    }
+}
+
+impl IntModWordPoly {
+    pub fn with_capacity(capacity: usize, ctx: &IntModWordCtx) -> Self {
+        let mut z = MaybeUninit::uninit();
+        let n = ctx.nmod();
+        unsafe {
+            nmod_poly_init2_preinv(
+                z.as_mut_ptr(),
+                n.n,
+                n.ninv,
+                capacity.try_into().expect("Cannot convert input to a signed long.")
+            );
+            IntModWordPoly::from_raw(z.assume_init(), *ctx)
+        }
+    }
+
+    #[inline]
+    pub fn zero(ctx: &IntModWordCtx) -> IntModWordPoly {
+        let mut z = MaybeUninit::uninit();
+        let n = ctx.nmod();
+        unsafe {
+            nmod_poly_init_preinv(z.as_mut_ptr(), n.n, n.ninv);
+            IntModWordPoly::from_raw(z.assume_init(), *ctx)
+        }
+    }
+
+    #[inline]
+    pub fn one(ctx: &IntModWordCtx) -> IntModWordPoly {
+        let mut res = IntModWordPoly::zero(ctx);
+        unsafe { nmod_poly_one(res.as_mut_ptr()); }
+        res
+    }
+
+    #[inline]
+    pub const fn as_ptr(&self) -> *const nmod_poly_struct {
+        &self.inner
+    }
+
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut nmod_poly_struct {
+        &mut self.inner
+    }
+
+    #[inline]
+    pub const unsafe fn from_raw(
+        inner: nmod_poly_struct,
+        ctx: IntModWordCtx
+    ) -> Self {
+        IntModWordPoly { inner, ctx }
+    }
+
+    #[inline]
+    pub const fn into_raw(self) -> nmod_poly_struct {
+        let inner = self.inner;
+        let _ = ManuallyDrop::new(self);
+        inner
+    }
+
+    #[inline]
+    pub fn context(&self) -> &IntModWordCtx {
+        &self.ctx
+    }
+
+    #[inline]
+    pub fn modulus(&self) -> u64 {
+        self.context().modulus()
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        unsafe { nmod_poly_is_zero(self.as_ptr()) == 1 }
+    }
+
+    #[inline]
+    pub fn is_one(&self) -> bool {
+        unsafe { nmod_poly_is_one(self.as_ptr()) == 1 }
+    }
+
+    #[inline]
+    pub fn is_gen(&self) -> bool {
+        unsafe { nmod_poly_is_gen(self.as_ptr()) == 1 }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        unsafe { nmod_poly_length(self.as_ptr()).try_into().unwrap() }
+    }
+
+    #[inline]
+    pub fn degree(&self) -> i64 {
+        unsafe { nmod_poly_degree(self.as_ptr()) }
+    }
+
+    pub fn get_coeff(&self, i: usize) -> IntModWord {
+        let c = unsafe {
+            nmod_poly_get_coeff_ui(
+                self.as_ptr(),
+                i.try_into().expect("Cannot convert index to a signed long.")
+            )
+        };
+        unsafe { IntModWord::from_raw(c, *self.context()) }
+    }
+
+    pub fn set_coeff<T: AsRef<IntModWord>>(&mut self, i: usize, coeff: T) {
+        let coeff = coeff.as_ref();
+        assert_eq!(self.context(), coeff.context());
+        unsafe {
+            nmod_poly_set_coeff_ui(
+                self.as_mut_ptr(),
+                i.try_into().expect("Cannot convert index to a signed long."),
+                coeff.get_ui()
+            );
+        }
+    }
+
+    #[inline]
+    pub fn get_coeffs(&self) -> Vec<IntModWord> {
+        (0..self.len()).map(|i| self.get_coeff(i)).collect()
+    }
+
+    /// Return the coefficients as words in `[0, n)`, constant term first.
+    ///
+    /// ```
+    /// use rufl::intmodword::IntModWordCtx;
+    /// use rufl::intmodwordpoly::IntModWordPoly;
+    /// use rufl::ops::NewCtx;
+    ///
+    /// let ctx = IntModWordCtx::new(5);
+    /// let f = IntModWordPoly::new([1, -1, 7], &ctx);
+    /// assert_eq!(f.get_coeffs_ui(), vec![1, 4, 2]);
+    /// ```
+    #[inline]
+    pub fn get_coeffs_ui(&self) -> Vec<u64> {
+        (0..self.len()).map(|i| self.get_coeff(i).get_ui()).collect()
+    }
+
+    /// Evaluate the polynomial at `x`.
+    ///
+    /// ```
+    /// use rufl::intmodword::{IntModWord, IntModWordCtx};
+    /// use rufl::intmodwordpoly::IntModWordPoly;
+    /// use rufl::ops::NewCtx;
+    ///
+    /// let ctx = IntModWordCtx::new(7);
+    /// let f = IntModWordPoly::new([1, 0, 1], &ctx);
+    /// assert_eq!(f.evaluate(&IntModWord::new(3, &ctx)).get_ui(), 3);
+    /// ```
+    #[inline]
+    pub fn evaluate<T: AsRef<IntModWord>>(&self, x: T) -> IntModWord {
+        let x = x.as_ref();
+        assert_eq!(self.context(), x.context());
+        unsafe {
+            let c = nmod_poly_evaluate_nmod(self.as_ptr(), x.get_ui());
+            IntModWord::from_raw(c, *self.context())
+        }
+    }
+
+    /// Return the monic polynomial whose roots are `roots`, with
+    /// multiplicity.
+    ///
+    /// ```
+    /// use rufl::intmodword::{IntModWord, IntModWordCtx};
+    /// use rufl::intmodwordpoly::IntModWordPoly;
+    /// use rufl::ops::NewCtx;
+    ///
+    /// let ctx = IntModWordCtx::new(7);
+    /// let roots = [IntModWord::new(1, &ctx), IntModWord::new(2, &ctx)];
+    /// let f = IntModWordPoly::from_roots(&roots, &ctx);
+    /// assert_eq!(f, IntModWordPoly::new([2, 4, 1], &ctx));
+    /// ```
+    pub fn from_roots(roots: &[IntModWord], ctx: &IntModWordCtx) -> IntModWordPoly {
+        let xs: Vec<u64> = roots.iter().map(|r| {
+            assert_eq!(r.context(), ctx);
+            r.get_ui()
+        }).collect();
+        let mut res = IntModWordPoly::zero(ctx);
+        unsafe {
+            nmod_poly_product_roots_nmod_vec(
+                res.as_mut_ptr(),
+                xs.as_ptr(),
+                xs.len().try_into().expect("Cannot convert length to a signed long.")
+            );
+        }
+        res
+    }
+}
@@ -0,0 +1,30 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::{IntModWord, IntModWordPoly};
+use flint_sys::nmod_poly;
+
+impl_from! {
+    IntModWordPoly, IntModWord
+    {
+        fn from(src: &IntModWord) -> IntModWordPoly {
+            let mut res = IntModWordPoly::zero(src.context());
+            unsafe { nmod_poly::nmod_poly_set_coeff_ui(res.as_mut_ptr(), 0, src.get_ui()); }
+            res
+        }
+    }
+}
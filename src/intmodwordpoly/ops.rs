@@ -0,0 +1,143 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::{IntModWord, IntModWordPoly};
+use crate::ops::*;
+use flint_sys::nmod_poly;
+
+impl_cmp! {
+    eq
+    IntModWordPoly
+    {
+        fn eq(&self, rhs: &IntModWordPoly) -> bool {
+            self.context() == rhs.context() &&
+                unsafe { nmod_poly::nmod_poly_equal(self.as_ptr(), rhs.as_ptr()) != 0 }
+        }
+    }
+}
+
+impl_cmp! {
+    partial_eq
+    IntModWordPoly, IntModWord
+    {
+        fn eq(&self, rhs: &IntModWord) -> bool {
+            self.context() == rhs.context() &&
+                self.degree() <= 0 && &self.get_coeff(0) == rhs
+        }
+    }
+}
+
+impl_unop_unsafe! {
+    ctx_new_only
+    IntModWordPoly
+    Neg {neg}
+    NegAssign {neg_assign}
+    nmod_poly::nmod_poly_neg
+}
+
+impl_binop_unsafe! {
+    ctx_new_only
+    IntModWordPoly, IntModWordPoly, IntModWordPoly
+
+    Add {add}
+    AddAssign {add_assign}
+    AddFrom {add_from}
+    AssignAdd {assign_add}
+    nmod_poly::nmod_poly_add;
+
+    Sub {sub}
+    SubAssign {sub_assign}
+    SubFrom {sub_from}
+    AssignSub {assign_sub}
+    nmod_poly::nmod_poly_sub;
+
+    Mul {mul}
+    MulAssign {mul_assign}
+    MulFrom {mul_from}
+    AssignMul {assign_mul}
+    nmod_poly::nmod_poly_mul;
+}
+
+impl_binop! {
+    op_assign
+    IntModWordPoly, IntModWord, IntModWordPoly
+    Mul {mul}
+    {
+        fn mul(self, rhs: &IntModWord) -> IntModWordPoly {
+            let mut res = IntModWordPoly::zero(self.context());
+            res.assign_mul(self, rhs);
+            res
+        }
+    }
+    MulAssign {mul_assign}
+    {
+        fn mul_assign(&mut self, rhs: &IntModWord) {
+            assert_eq!(self.context(), rhs.context());
+            unsafe {
+                nmod_poly::nmod_poly_scalar_mul_nmod(
+                    self.as_mut_ptr(),
+                    self.as_ptr(),
+                    rhs.get_ui()
+                );
+            }
+        }
+    }
+    AssignMul {assign_mul}
+    {
+        fn assign_mul(&mut self, lhs: &IntModWordPoly, rhs: &IntModWord) {
+            assert_eq!(lhs.context(), rhs.context());
+            assert_eq!(self.context(), lhs.context());
+            unsafe {
+                nmod_poly::nmod_poly_scalar_mul_nmod(
+                    self.as_mut_ptr(),
+                    lhs.as_ptr(),
+                    rhs.get_ui()
+                );
+            }
+        }
+    }
+}
+
+impl_binop! {
+    op_assign
+    IntModWordPoly, u64, IntModWordPoly
+    Pow {pow}
+    {
+        fn pow(self, rhs: &u64) -> IntModWordPoly {
+            let mut res = IntModWordPoly::zero(self.context());
+            res.assign_pow(self, rhs);
+            res
+        }
+    }
+    PowAssign {pow_assign}
+    {
+        fn pow_assign(&mut self, rhs: &u64) {
+            unsafe {
+                nmod_poly::nmod_poly_pow(self.as_mut_ptr(), self.as_ptr(), *rhs);
+            }
+        }
+    }
+    AssignPow {assign_pow}
+    {
+        fn assign_pow(&mut self, lhs: &IntModWordPoly, rhs: &u64) {
+            assert_eq!(self.context(), lhs.context());
+            unsafe {
+                nmod_poly::nmod_poly_pow(self.as_mut_ptr(), lhs.as_ptr(), *rhs);
+            }
+        }
+    }
+}
@@ -18,11 +18,22 @@
 mod ops;
 mod conv;
 mod extras;
+mod families;
+mod division;
+mod interpolate;
+mod recurrence;
+mod index;
+
+#[cfg(feature = "num-traits")]
+mod num;
 
 #[cfg(feature = "serde")]
 mod serde;
 
-use crate::Integer;
+pub use index::IntPolyCoeffMut;
+pub use recurrence::IntRecurrenceTerms;
+
+use crate::{Integer, IntegerRef, PolyFormat};
 use flint_sys::fmpz_types::*;
 use flint_sys::fmpz_poly::*;
 
@@ -30,6 +41,12 @@ use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::mem::{ManuallyDrop, MaybeUninit};
 
+/// A polynomial with integer coefficients.
+///
+/// Coefficients can be read by reference with `f[i]`. There is no
+/// `IndexMut`, since writing a coefficient may change the length of the
+/// polynomial; [IntPoly::coeff_mut] returns a guard which writes the
+/// coefficient back when dropped.
 #[derive(Debug)]
 pub struct IntPoly {
     inner: fmpz_poly_struct,
@@ -67,67 +84,7 @@ impl Default for IntPoly {
 impl fmt::Display for IntPoly {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let deg = self.degree();
-        if deg < 0 {
-            return write!(f, "0");
-        } else if deg == 0 {
-            return write!(f, "{}", self.get_coeff(0).to_string());
-        }
-
-        let deg: usize = deg.try_into().unwrap();
-        let mut out = String::new();
-        let coeffs = self.get_coeffs();
-
-        let sign = |s| {
-            if s > 0 { " + " }
-            else if s < 0 { " - " }
-            else { unreachable!() }
-        };
-       
-        for (k, c) in coeffs.iter().enumerate().rev() {
-            let s = c.sign();
-            if s == 0 {
-                continue;
-            }
-
-            let abs = c.abs();
-            if k == 0 {
-                out.push_str(&format!("{}{}", sign(s), abs));
-            } else if k == deg {
-                if abs.is_one() && s > 0 {
-                    if k == 1 {
-                        out.push_str("x")
-                    } else {
-                        out.push_str(&format!("x^{}", k));
-                    }
-                } else if abs.is_one() && s < 0 {
-                    if k == 1 {
-                        out.push_str("-x")
-                    } else {
-                        out.push_str(&format!("-x^{}", k));
-                    }
-                } else {
-                    if k == 1 {
-                        out.push_str(&format!("{}*x", c));
-                    } else {
-                        out.push_str(&format!("{}*x^{}", c, k));
-                    }
-                }
-            } else if k == 1 {
-                if abs.is_one() {
-                    out.push_str(&format!("{}x", sign(s)));
-                } else {
-                    out.push_str(&format!("{}{}*x", sign(s), abs));
-                }
-            } else {
-                if abs.is_one() {
-                    out.push_str(&format!("{}x^{}", sign(s), k));
-                } else {
-                    out.push_str(&format!("{}{}*x^{}", sign(s), abs, k));
-                }
-            }
-        }
-        write!(f, "{}", out)
+        write!(f, "{}", self.to_str_format(&PolyFormat::default()))
     }
 }
 
@@ -331,6 +288,62 @@ impl IntPoly {
         res
     }
 
+    /// Return a reference to the coefficient of `x^i` without copying it.
+    /// Coefficients beyond the length of the polynomial are zero.
+    ///
+    /// ```
+    /// use rufl::intpoly::IntPoly;
+    ///
+    /// let f = IntPoly::from([1, 0, -3]);
+    /// assert_eq!(f.coeff(2), -3);
+    /// assert!(f.coeff(10).is_zero());
+    /// ```
+    #[inline]
+    pub fn coeff(&self, i: usize) -> IntegerRef<'_> {
+        let i = i.try_into().expect("Cannot convert index to a signed long.");
+        unsafe {
+            let ptr = fmpz_poly_get_coeff_ptr(self.as_ptr(), i);
+            if ptr.is_null() {
+                IntegerRef::zero()
+            } else {
+                IntegerRef::from_ptr(ptr)
+            }
+        }
+    }
+
+    /// Return an iterator over references to the coefficients, constant
+    /// term first.
+    ///
+    /// ```
+    /// use rufl::intpoly::IntPoly;
+    ///
+    /// let f = IntPoly::from([1, 0, -3]);
+    /// let height = f.iter_coeffs().map(|c| c.abs()).max().unwrap();
+    /// assert_eq!(height, 3);
+    /// ```
+    pub fn iter_coeffs(&self)
+        -> impl ExactSizeIterator<Item = IntegerRef<'_>> + DoubleEndedIterator + '_
+    {
+        (0..self.len()).map(move |i| self.coeff(i))
+    }
+
+    /// Return the polynomial printed according to `fmt`.
+    ///
+    /// ```
+    /// use rufl::intpoly::IntPoly;
+    /// use rufl::polyfmt::PolyFormat;
+    ///
+    /// let fmt = PolyFormat { var: "y".to_string(), explicit_mul: false, ..Default::default() };
+    /// assert_eq!(IntPoly::from([-1, 0, 2]).to_str_format(&fmt), "2y^2 - 1");
+    /// ```
+    pub fn to_str_format(&self, fmt: &PolyFormat) -> String {
+        let coeffs: Vec<_> = self.get_coeffs()
+            .iter()
+            .map(|c| (c.sign(), c.abs().to_string()))
+            .collect();
+        fmt.format_terms(&coeffs)
+    }
+
     pub fn cyclotomic(n: u64) -> Self {
         let mut res = IntPoly::default();
         unsafe {
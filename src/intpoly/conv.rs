@@ -15,7 +15,10 @@
  *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
-use crate::{Integer, IntMod, IntPoly, IntModPoly};
+use crate::{Error, Integer, IntMod, IntPoly, IntModPoly, IntModWordPoly, Result};
+use crate::ops::NegAssign;
+use crate::polyfmt::parse_terms;
+use std::str::FromStr;
 use flint_sys::{
     fmpz_poly,
     fmpz_mod_poly, 
@@ -23,6 +26,62 @@ use flint_sys::{
 };
 
 
+/// Parses a polynomial written as a sum of terms `c*x^k`, as printed by
+/// `Display` or [IntPoly::to_str_format]. The terms may come in any order,
+/// the `*` may be omitted, `**` may be used for `^`, and any variable name
+/// is accepted as long as only one is used.
+///
+/// ```
+/// use rufl::intpoly::IntPoly;
+///
+/// let f: IntPoly = "3*t^5 - t + 2".parse().unwrap();
+/// assert_eq!(f, IntPoly::from([2, -1, 0, 0, 0, 3]));
+/// assert_eq!(f.to_string().parse::<IntPoly>().unwrap(), f);
+///
+/// let g: IntPoly = "1 + 2y + y**2".parse().unwrap();
+/// assert_eq!(g, IntPoly::from([1, 2, 1]));
+///
+/// assert!("x + y".parse::<IntPoly>().is_err());
+/// assert!("x/2".parse::<IntPoly>().is_err());
+/// ```
+impl FromStr for IntPoly {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        parse_int_poly(s, None)
+    }
+}
+
+impl IntPoly {
+    /// Parse a polynomial as for [FromStr], requiring the variable to be
+    /// `var`.
+    ///
+    /// ```
+    /// use rufl::intpoly::IntPoly;
+    ///
+    /// assert_eq!(IntPoly::from_str_var("z^2 - 1", "z").unwrap(), IntPoly::from([-1, 0, 1]));
+    /// assert!(IntPoly::from_str_var("x^2 - 1", "z").is_err());
+    /// ```
+    pub fn from_str_var(s: &str, var: &str) -> Result<IntPoly> {
+        parse_int_poly(s, Some(var))
+    }
+}
+
+fn parse_int_poly(s: &str, var: Option<&str>) -> Result<IntPoly> {
+    let mut res = IntPoly::zero();
+    for term in parse_terms(s, var)? {
+        let mut c = match term.coeff {
+            Some(c) => Integer::from_str(&c)?,
+            None => Integer::one(),
+        };
+        if term.neg {
+            c.neg_assign();
+        }
+        let c = res.get_coeff(term.exp) + c;
+        res.set_coeff(term.exp, c);
+    }
+    Ok(res)
+}
+
 impl_from_unsafe! {
     None
     IntPoly, u64 {usize u64 u32 u16 u8}
@@ -53,6 +112,12 @@ impl_from_unsafe! {
     fmpz_mod_poly::fmpz_mod_poly_get_fmpz_poly
 }
 
+impl_from_unsafe! {
+    None
+    IntPoly, IntModWordPoly
+    fmpz_poly::fmpz_poly_set_nmod_poly_unsigned
+}
+
 /*
 impl_from_unsafe! {
     ctx_in
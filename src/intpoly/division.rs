@@ -0,0 +1,143 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Euclidean division, exact division and pseudo-division of integer
+//! polynomials.
+
+use crate::{Error, IntPoly, Result};
+use flint_sys::fmpz_poly;
+
+impl IntPoly {
+    /// Return the quotient and remainder `(q, r)` with `self = q*rhs + r`.
+    ///
+    /// If the leading coefficient of `rhs` is `1` or `-1`, or the division is
+    /// exact, this agrees with division over the rationals and `r` has
+    /// smaller degree than `rhs`. Otherwise only the coefficients of `r` from
+    /// the degree of `rhs` onwards are reduced modulo the leading
+    /// coefficient. Panics if `rhs` is zero.
+    ///
+    /// ```
+    /// use rufl::intpoly::IntPoly;
+    ///
+    /// let a = IntPoly::from([1, 0, 0, 1]);
+    /// let b = IntPoly::from([1, 1]);
+    /// let (q, r) = a.div_rem(&b);
+    /// assert_eq!(q, IntPoly::from([1, -1, 1]));
+    /// assert!(r.is_zero());
+    ///
+    /// let (q, r) = IntPoly::from([3, 0, 1]).div_rem(IntPoly::from([0, 1]));
+    /// assert_eq!(q, IntPoly::from([0, 1]));
+    /// assert_eq!(r, 3);
+    /// ```
+    pub fn div_rem<T: AsRef<IntPoly>>(&self, rhs: T) -> (IntPoly, IntPoly) {
+        let rhs = rhs.as_ref();
+        assert!(!rhs.is_zero(), "Division by zero.");
+        let mut q = IntPoly::default();
+        let mut r = IntPoly::default();
+        unsafe {
+            fmpz_poly::fmpz_poly_divrem(
+                q.as_mut_ptr(),
+                r.as_mut_ptr(),
+                self.as_ptr(),
+                rhs.as_ptr()
+            );
+        }
+        (q, r)
+    }
+
+    /// Return the quotient `self/rhs` if `rhs` divides `self` exactly, and
+    /// `None` otherwise. Panics if `rhs` is zero.
+    ///
+    /// ```
+    /// use rufl::intpoly::IntPoly;
+    ///
+    /// let a = IntPoly::from([-4, 0, 1]);
+    /// assert_eq!(a.divides(IntPoly::from([2, 1])), Some(IntPoly::from([-2, 1])));
+    /// assert!(a.divides(IntPoly::from([3, 1])).is_none());
+    /// assert!(a.divides(IntPoly::from([-4, 0, 2])).is_none());
+    /// ```
+    pub fn divides<T: AsRef<IntPoly>>(&self, rhs: T) -> Option<IntPoly> {
+        let rhs = rhs.as_ref();
+        assert!(!rhs.is_zero(), "Division by zero.");
+        let mut q = IntPoly::default();
+        let ok = unsafe {
+            fmpz_poly::fmpz_poly_divides(q.as_mut_ptr(), self.as_ptr(), rhs.as_ptr())
+        };
+        if ok != 0 {
+            Some(q)
+        } else {
+            None
+        }
+    }
+
+    /// Return the exact quotient `self/rhs`, or an error if `rhs` is zero or
+    /// does not divide `self`.
+    ///
+    /// ```
+    /// use rufl::intpoly::IntPoly;
+    ///
+    /// let a = IntPoly::from([-1, 0, 0, 1]);
+    /// assert_eq!(a.div_exact(IntPoly::from([-1, 1])).unwrap(), IntPoly::from([1, 1, 1]));
+    /// assert!(a.div_exact(IntPoly::from([1, 1])).is_err());
+    /// assert!(a.div_exact(IntPoly::zero()).is_err());
+    /// ```
+    pub fn div_exact<T: AsRef<IntPoly>>(&self, rhs: T) -> Result<IntPoly> {
+        let rhs = rhs.as_ref();
+        if rhs.is_zero() {
+            return Err(Error::DivisionError("Division by zero.".to_string()));
+        }
+        self.divides(rhs).ok_or_else(|| Error::DivisionError(
+            "Divisor does not divide the dividend exactly.".to_string()
+        ))
+    }
+
+    /// Return `(q, r, d)` such that `lc(rhs)^d * self = q*rhs + r`, where the
+    /// degree of `r` is less than that of `rhs` and `d` is at most
+    /// `deg(self) - deg(rhs) + 1`. Panics if `rhs` is zero.
+    ///
+    /// ```
+    /// use rufl::intpoly::IntPoly;
+    /// use rufl::ops::Pow;
+    /// use rufl::integer::Integer;
+    ///
+    /// let a = IntPoly::from([1, 2, 3, 4]);
+    /// let b = IntPoly::from([1, 2]);
+    /// let (q, r, d) = a.pseudo_div_rem(&b);
+    /// assert!(r.degree() < b.degree());
+    /// assert_eq!(&a * Integer::from(2).pow(d), q * &b + r);
+    /// ```
+    pub fn pseudo_div_rem<T: AsRef<IntPoly>>(&self, rhs: T) -> (IntPoly, IntPoly, u64) {
+        let rhs = rhs.as_ref();
+        assert!(!rhs.is_zero(), "Division by zero.");
+        if self.degree() < rhs.degree() {
+            return (IntPoly::zero(), self.clone(), 0);
+        }
+        let mut q = IntPoly::default();
+        let mut r = IntPoly::default();
+        let mut d = 0u64;
+        unsafe {
+            fmpz_poly::fmpz_poly_pseudo_divrem_divconquer(
+                q.as_mut_ptr(),
+                r.as_mut_ptr(),
+                &mut d,
+                self.as_ptr(),
+                rhs.as_ptr()
+            );
+        }
+        (q, r, d)
+    }
+}
@@ -0,0 +1,147 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Classical families of integer polynomials.
+
+use crate::IntPoly;
+use flint_sys::fmpz_poly;
+
+impl IntPoly {
+    /// Return the Chebyshev polynomial of the first kind `T_n`, defined by
+    /// `T_n(cos(t)) = cos(n t)`.
+    ///
+    /// ```
+    /// use rufl::intpoly::IntPoly;
+    ///
+    /// assert_eq!(IntPoly::chebyshev_t(3), IntPoly::from([0, -3, 0, 4]));
+    /// ```
+    #[inline]
+    pub fn chebyshev_t(n: u64) -> IntPoly {
+        let mut res = IntPoly::default();
+        unsafe { fmpz_poly::fmpz_poly_chebyshev_t(res.as_mut_ptr(), n); }
+        res
+    }
+
+    /// Return the Chebyshev polynomial of the second kind `U_n`, defined by
+    /// `U_n(cos(t)) sin(t) = sin((n + 1) t)`.
+    ///
+    /// ```
+    /// use rufl::intpoly::IntPoly;
+    ///
+    /// assert_eq!(IntPoly::chebyshev_u(2), IntPoly::from([-1, 0, 4]));
+    /// ```
+    #[inline]
+    pub fn chebyshev_u(n: u64) -> IntPoly {
+        let mut res = IntPoly::default();
+        unsafe { fmpz_poly::fmpz_poly_chebyshev_u(res.as_mut_ptr(), n); }
+        res
+    }
+
+    /// Return the shifted Legendre polynomial `P_n(2x - 1)`, which has
+    /// integer coefficients. See [RatPoly::legendre_p](crate::RatPoly::legendre_p)
+    /// for the unshifted polynomial.
+    ///
+    /// ```
+    /// use rufl::intpoly::IntPoly;
+    ///
+    /// assert_eq!(IntPoly::legendre_shifted(2), IntPoly::from([1, -6, 6]));
+    /// ```
+    #[inline]
+    pub fn legendre_shifted(n: u64) -> IntPoly {
+        let mut res = IntPoly::default();
+        unsafe { fmpz_poly::fmpz_poly_legendre_pt(res.as_mut_ptr(), n); }
+        res
+    }
+
+    /// Return the physicists' Hermite polynomial `H_n`.
+    ///
+    /// ```
+    /// use rufl::intpoly::IntPoly;
+    ///
+    /// assert_eq!(IntPoly::hermite_h(3), IntPoly::from([0, -12, 0, 8]));
+    /// ```
+    #[inline]
+    pub fn hermite_h(n: u64) -> IntPoly {
+        let mut res = IntPoly::default();
+        unsafe { fmpz_poly::fmpz_poly_hermite_h(res.as_mut_ptr(), n); }
+        res
+    }
+
+    /// Return the probabilists' Hermite polynomial `He_n`.
+    ///
+    /// ```
+    /// use rufl::intpoly::IntPoly;
+    ///
+    /// assert_eq!(IntPoly::hermite_he(3), IntPoly::from([0, -3, 0, 1]));
+    /// ```
+    #[inline]
+    pub fn hermite_he(n: u64) -> IntPoly {
+        let mut res = IntPoly::default();
+        unsafe { fmpz_poly::fmpz_poly_hermite_he(res.as_mut_ptr(), n); }
+        res
+    }
+
+    /// Return the Swinnerton-Dyer polynomial `S_n`, the product of
+    /// `x ± sqrt(2) ± sqrt(3) ± ... ± sqrt(p_n)` over all sign choices, where
+    /// `p_n` is the `n`-th prime. It has degree `2^n`, so only small `n` are
+    /// practical.
+    ///
+    /// ```
+    /// use rufl::intpoly::IntPoly;
+    ///
+    /// assert_eq!(IntPoly::swinnerton_dyer(1), IntPoly::from([-2, 0, 1]));
+    /// assert_eq!(IntPoly::swinnerton_dyer(2), IntPoly::from([1, 0, -10, 0, 1]));
+    /// ```
+    #[inline]
+    pub fn swinnerton_dyer(n: u64) -> IntPoly {
+        let mut res = IntPoly::default();
+        unsafe { fmpz_poly::fmpz_poly_swinnerton_dyer(res.as_mut_ptr(), n); }
+        res
+    }
+
+    /// Return the minimal polynomial of `2 cos(2 pi/n)`.
+    ///
+    /// ```
+    /// use rufl::intpoly::IntPoly;
+    ///
+    /// assert_eq!(IntPoly::cos_minpoly(5), IntPoly::from([-1, 1, 1]));
+    /// ```
+    #[inline]
+    pub fn cos_minpoly(n: u64) -> IntPoly {
+        let mut res = IntPoly::default();
+        unsafe { fmpz_poly::fmpz_poly_cos_minpoly(res.as_mut_ptr(), n); }
+        res
+    }
+
+    /// Return `Some(n)` if the polynomial is the `n`-th cyclotomic polynomial
+    /// and `None` otherwise.
+    ///
+    /// ```
+    /// use rufl::intpoly::IntPoly;
+    ///
+    /// assert_eq!(IntPoly::cyclotomic(12).is_cyclotomic(), Some(12));
+    /// assert_eq!(IntPoly::from([1, 1, 1]).is_cyclotomic(), Some(3));
+    /// assert!(IntPoly::from([1, 0, 1, 1]).is_cyclotomic().is_none());
+    /// ```
+    #[inline]
+    pub fn is_cyclotomic(&self) -> Option<u64> {
+        match unsafe { fmpz_poly::fmpz_poly_is_cyclotomic(self.as_ptr()) } {
+            0 => None,
+            n => Some(n)
+        }
+    }
+}
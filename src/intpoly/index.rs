@@ -0,0 +1,114 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Indexing of integer polynomials by coefficient, and a guard for
+//! modifying a single coefficient in place.
+
+use crate::{IntPoly, Integer};
+use std::fmt;
+use std::ops::{Deref, DerefMut, Index};
+
+/// Read the coefficient of `x^i` by reference. Coefficients beyond the
+/// length of the polynomial are zero.
+///
+/// There is no `IndexMut`, since changing the leading coefficient may change
+/// the length of the polynomial; use [IntPoly::coeff_mut] instead.
+///
+/// ```
+/// use rufl::intpoly::IntPoly;
+///
+/// let f = IntPoly::from([1, 0, -3]);
+/// assert_eq!(f[2], -3);
+/// assert_eq!(f[7], 0);
+/// ```
+impl Index<usize> for IntPoly {
+    type Output = Integer;
+    #[inline]
+    fn index(&self, i: usize) -> &Integer {
+        self.coeff(i).as_integer()
+    }
+}
+
+/// A mutable handle to one coefficient of an [IntPoly], created by
+/// [IntPoly::coeff_mut].
+///
+/// The handle dereferences to a copy of the coefficient, which is written
+/// back when the handle is dropped. The polynomial is normalised at that
+/// point, so it is never observed with a zero leading coefficient.
+pub struct IntPolyCoeffMut<'a> {
+    poly: &'a mut IntPoly,
+    i: usize,
+    value: Integer,
+}
+
+impl Deref for IntPolyCoeffMut<'_> {
+    type Target = Integer;
+    #[inline]
+    fn deref(&self) -> &Integer {
+        &self.value
+    }
+}
+
+impl DerefMut for IntPolyCoeffMut<'_> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Integer {
+        &mut self.value
+    }
+}
+
+impl Drop for IntPolyCoeffMut<'_> {
+    #[inline]
+    fn drop(&mut self) {
+        self.poly.set_coeff(self.i, &self.value);
+    }
+}
+
+impl fmt::Debug for IntPolyCoeffMut<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.value, f)
+    }
+}
+
+impl fmt::Display for IntPolyCoeffMut<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)
+    }
+}
+
+impl IntPoly {
+    /// Return a handle for modifying the coefficient of `x^i` in place. The
+    /// polynomial grows or shrinks as needed when the handle is dropped.
+    ///
+    /// ```
+    /// use rufl::intpoly::IntPoly;
+    ///
+    /// let mut f = IntPoly::from([1, 2, 3]);
+    /// *f.coeff_mut(0) += 5;
+    /// assert_eq!(f, IntPoly::from([6, 2, 3]));
+    ///
+    /// *f.coeff_mut(2) -= 3;
+    /// assert_eq!(f.degree(), 1);
+    ///
+    /// *f.coeff_mut(4) = 1.into();
+    /// assert_eq!(f, IntPoly::from([6, 2, 0, 0, 1]));
+    /// ```
+    #[inline]
+    pub fn coeff_mut(&mut self, i: usize) -> IntPolyCoeffMut<'_> {
+        let value = self.get_coeff(i);
+        IntPolyCoeffMut { poly: self, i, value }
+    }
+}
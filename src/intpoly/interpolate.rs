@@ -0,0 +1,81 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Interpolation and construction from roots for integer polynomials.
+
+use crate::{Error, IntPoly, Integer, RatPoly, Rational, Result};
+use flint_sys::{fmpz, fmpz_poly, fmpz_vec};
+
+impl IntPoly {
+    /// Return the monic polynomial whose roots are `roots`, with
+    /// multiplicity.
+    ///
+    /// ```
+    /// use rufl::intpoly::IntPoly;
+    /// use rufl::integer::Integer;
+    ///
+    /// let f = IntPoly::from_roots(&[Integer::from(2), Integer::from(-3)]);
+    /// assert_eq!(f, IntPoly::from([-6, 1, 1]));
+    /// ```
+    pub fn from_roots(roots: &[Integer]) -> IntPoly {
+        let n: i64 = roots.len().try_into().expect("Cannot convert length to a signed long.");
+        let mut res = IntPoly::default();
+        unsafe {
+            let xs = fmpz_vec::_fmpz_vec_init(n);
+            for (i, r) in roots.iter().enumerate() {
+                fmpz::fmpz_set(xs.add(i), r.as_ptr());
+            }
+            fmpz_poly::fmpz_poly_product_roots_fmpz_vec(res.as_mut_ptr(), xs, n);
+            fmpz_vec::_fmpz_vec_clear(xs, n);
+        }
+        res
+    }
+
+    /// Return the unique polynomial of degree less than the number of points
+    /// passing through `points`. Returns an error if two points share an `x`
+    /// value or the interpolating polynomial does not have integer
+    /// coefficients.
+    ///
+    /// ```
+    /// use rufl::intpoly::IntPoly;
+    /// use rufl::integer::Integer;
+    ///
+    /// let points: Vec<(Integer, Integer)> = [(0, 1), (1, 3), (2, 7)].iter()
+    ///     .map(|&(x, y)| (Integer::from(x), Integer::from(y)))
+    ///     .collect();
+    /// assert_eq!(IntPoly::interpolate(&points).unwrap(), IntPoly::from([1, 1, 1]));
+    ///
+    /// // x(x - 1)/2 is integer valued but not in ZZ[x].
+    /// let points: Vec<(Integer, Integer)> = [(0, 0), (1, 0), (2, 1)].iter()
+    ///     .map(|&(x, y)| (Integer::from(x), Integer::from(y)))
+    ///     .collect();
+    /// assert!(IntPoly::interpolate(&points).is_err());
+    /// ```
+    pub fn interpolate(points: &[(Integer, Integer)]) -> Result<IntPoly> {
+        let points: Vec<(Rational, Rational)> = points.iter()
+            .map(|(x, y)| (Rational::from(x), Rational::from(y)))
+            .collect();
+        let f = RatPoly::interpolate(&points)?;
+        if f.denominator().is_one() {
+            Ok(f.numerator())
+        } else {
+            Err(Error::DivisionError(
+                "Interpolating polynomial does not have integer coefficients.".to_string()
+            ))
+        }
+    }
+}
@@ -0,0 +1,67 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! [num-traits](https://docs.rs/num-traits) implementations for [IntPoly].
+
+use crate::IntPoly;
+use num_traits::{One, Pow, Zero};
+
+impl Zero for IntPoly {
+    #[inline]
+    fn zero() -> Self {
+        IntPoly::zero()
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        IntPoly::is_zero(self)
+    }
+}
+
+impl One for IntPoly {
+    #[inline]
+    fn one() -> Self {
+        IntPoly::one()
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        IntPoly::is_one(self)
+    }
+}
+
+macro_rules! impl_pow {
+    ($($t:ident)+) => ($(
+        impl Pow<$t> for IntPoly {
+            type Output = IntPoly;
+            #[inline]
+            fn pow(self, rhs: $t) -> IntPoly {
+                crate::ops::Pow::pow(self, rhs)
+            }
+        }
+
+        impl Pow<$t> for &IntPoly {
+            type Output = IntPoly;
+            #[inline]
+            fn pow(self, rhs: $t) -> IntPoly {
+                crate::ops::Pow::pow(self, rhs)
+            }
+        }
+    )+);
+}
+
+impl_pow! { u64 u32 u16 u8 }
@@ -0,0 +1,93 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Linear recurrences with integer coefficients.
+
+use crate::{IntPoly, Integer};
+use flint_sys::fmpz_poly;
+
+impl IntPoly {
+    // `x^n` reduced modulo `self`, which is monic, by left-to-right binary
+    // exponentiation.
+    fn powmod_x(&self, n: u64) -> IntPoly {
+        let mut res = IntPoly::one();
+        for i in (0..u64::BITS - n.leading_zeros()).rev() {
+            res = &res * &res;
+            if (n >> i) & 1 == 1 {
+                let mut t = IntPoly::default();
+                unsafe { fmpz_poly::fmpz_poly_shift_left(t.as_mut_ptr(), res.as_ptr(), 1); }
+                res = t;
+            }
+            res %= self;
+        }
+        res
+    }
+
+    /// Return the term `s_n` of the sequence with characteristic polynomial
+    /// `self` and initial terms `init = [s_0, ..., s_{d-1}]`.
+    ///
+    /// If `self = x^d + p_{d-1} x^{d-1} + ... + p_0`, the sequence satisfies
+    /// `s_{k+d} = -(p_{d-1} s_{k+d-1} + ... + p_0 s_k)`. The term is computed
+    /// as `x^n mod self` by binary exponentiation (Fiduccia's algorithm),
+    /// using `O(log n)` polynomial multiplications. Panics if `self` is not
+    /// monic of positive degree `d` or if `init` does not have length `d`.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    /// use rufl::intpoly::IntPoly;
+    ///
+    /// // The Fibonacci numbers, s_{k+2} = s_{k+1} + s_k.
+    /// let f = IntPoly::from([-1, -1, 1]);
+    /// let init = [Integer::from(0), Integer::from(1)];
+    /// assert_eq!(f.recurrence_term(&init, 300), Integer::fibonacci(300u32));
+    /// ```
+    pub fn recurrence_term(&self, init: &[Integer], n: u64) -> Integer {
+        let d = self.check_recurrence(init);
+        if n < d as u64 {
+            return init[n as usize].clone();
+        }
+        let r = self.powmod_x(n);
+        let mut res = Integer::zero();
+        for (i, s) in init.iter().enumerate().take(r.len()) {
+            res += r.get_coeff(i) * s;
+        }
+        res
+    }
+}
+
+impl_recurrence_terms! {
+    IntPoly, Integer
+    zero: |_f| Integer::zero();
+    /// Return an iterator over the terms `s_0, s_1, ...` of the sequence with
+    /// characteristic polynomial `self` and initial terms `init`, as in
+    /// [IntPoly::recurrence_term]. The iterator never ends.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    /// use rufl::intpoly::IntPoly;
+    ///
+    /// // s_{k+3} = 2 s_{k+2} + s_{k+1} - 2 s_k.
+    /// let f = IntPoly::from([2, -1, -2, 1]);
+    /// let init = [Integer::from(3), Integer::from(2), Integer::from(6)];
+    /// let terms: Vec<Integer> = f.recurrence_terms(&init).take(6).collect();
+    /// assert_eq!(terms, [3, 2, 6, 8, 18, 32].map(Integer::from));
+    /// ```
+    fn recurrence_terms;
+    /// An iterator over the terms of an integer linear recurrence, created by
+    /// [IntPoly::recurrence_terms].
+    struct IntRecurrenceTerms;
+}
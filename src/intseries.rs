@@ -0,0 +1,314 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Truncated power series with integer coefficients.
+
+mod ops;
+
+use crate::{IntPoly, Integer, RatSeries};
+use flint_sys::fmpz_poly;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// A power series `f + O(x^n)` over the integers, stored as the polynomial
+/// `f` of length at most `n` together with the precision `n`.
+///
+/// Arithmetic tracks precision: sums are known to the smaller of the two
+/// precisions, and products to `min(n_a + v_b, n_b + v_a)` where `v` is the
+/// valuation.
+///
+/// ```
+/// use rufl::intseries::IntSeries;
+///
+/// // 1/(1 - x) = 1 + x + x^2 + ...
+/// let f = IntSeries::new([1, -1], 6);
+/// let g = f.inv();
+/// assert_eq!(g.get_coeffs(), vec![1, 1, 1, 1, 1, 1]);
+/// assert_eq!(g.prec(), 6);
+///
+/// // Multiplying by x^2 gains precision.
+/// let x2 = IntSeries::new([0, 0, 1], 10);
+/// assert_eq!((&g * &x2).prec(), 8);
+/// ```
+#[derive(Clone, Debug)]
+pub struct IntSeries {
+    poly: IntPoly,
+    prec: usize,
+}
+
+impl AsRef<IntSeries> for IntSeries {
+    fn as_ref(&self) -> &IntSeries {
+        self
+    }
+}
+
+impl fmt::Display for IntSeries {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.poly.is_zero() {
+            write!(f, "O(x^{})", self.prec)
+        } else {
+            write!(f, "{} + O(x^{})", self.poly, self.prec)
+        }
+    }
+}
+
+impl Hash for IntSeries {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.poly.hash(state);
+        self.prec.hash(state);
+    }
+}
+
+// Convert a precision to a signed long for FLINT.
+#[inline]
+pub(crate) fn prec_si(prec: usize) -> i64 {
+    prec.try_into().expect("Cannot convert precision to a signed long.")
+}
+
+impl IntSeries {
+    /// Construct the series `poly + O(x^prec)`, truncating `poly`. Panics if
+    /// the precision is zero.
+    #[inline]
+    pub fn new<T: Into<IntPoly>>(poly: T, prec: usize) -> Self {
+        assert!(prec > 0, "Precision must be positive.");
+        let mut poly = poly.into();
+        unsafe { fmpz_poly::fmpz_poly_truncate(poly.as_mut_ptr(), prec_si(prec)); }
+        IntSeries { poly, prec }
+    }
+
+    /// The zero series `O(x^prec)`.
+    #[inline]
+    pub fn zero(prec: usize) -> Self {
+        IntSeries::new(IntPoly::zero(), prec)
+    }
+
+    /// The series `1 + O(x^prec)`.
+    #[inline]
+    pub fn one(prec: usize) -> Self {
+        IntSeries::new(IntPoly::one(), prec)
+    }
+
+    /// The series `x + O(x^prec)`.
+    #[inline]
+    pub fn gen(prec: usize) -> Self {
+        IntSeries::new([0, 1], prec)
+    }
+
+    /// Return the precision `n` of the series `f + O(x^n)`.
+    #[inline]
+    pub fn prec(&self) -> usize {
+        self.prec
+    }
+
+    /// Return a reference to the underlying polynomial.
+    #[inline]
+    pub fn poly(&self) -> &IntPoly {
+        &self.poly
+    }
+
+    /// Return the underlying polynomial, discarding the precision.
+    #[inline]
+    pub fn into_poly(self) -> IntPoly {
+        self.poly
+    }
+
+    /// Return true if the series is zero to its precision.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.poly.is_zero()
+    }
+
+    /// Return the index of the first nonzero coefficient, or the precision
+    /// if the series is zero to its precision.
+    ///
+    /// ```
+    /// use rufl::intseries::IntSeries;
+    ///
+    /// assert_eq!(IntSeries::new([0, 0, 3, 1], 5).valuation(), 2);
+    /// assert_eq!(IntSeries::zero(5).valuation(), 5);
+    /// ```
+    pub fn valuation(&self) -> usize {
+        (0..self.poly.len())
+            .find(|&i| !self.poly.get_coeff(i).is_zero())
+            .unwrap_or(self.prec)
+    }
+
+    /// Return the coefficient of `x^i`. Panics if `i` is not below the
+    /// precision.
+    #[inline]
+    pub fn get_coeff(&self, i: usize) -> Integer {
+        assert!(i < self.prec, "Coefficient is beyond the precision of the series.");
+        self.poly.get_coeff(i)
+    }
+
+    /// Return the coefficients of `x^0, ..., x^(n-1)`, where `n` is the
+    /// precision.
+    #[inline]
+    pub fn get_coeffs(&self) -> Vec<Integer> {
+        (0..self.prec).map(|i| self.poly.get_coeff(i)).collect()
+    }
+
+    /// Reduce the precision of the series to `prec`. Does nothing if `prec`
+    /// is not smaller than the current precision.
+    #[inline]
+    pub fn truncate(&mut self, prec: usize) {
+        if prec < self.prec {
+            assert!(prec > 0, "Precision must be positive.");
+            unsafe { fmpz_poly::fmpz_poly_truncate(self.poly.as_mut_ptr(), prec_si(prec)); }
+            self.prec = prec;
+        }
+    }
+
+    // Panics unless the constant term is one of `values`.
+    fn assert_constant_term(&self, values: &[i64], msg: &str) {
+        let c = self.poly.get_coeff(0);
+        assert!(values.iter().any(|v| c == *v), "{}", msg);
+    }
+
+    /// Return the multiplicative inverse of the series. Panics unless the
+    /// constant term is `1` or `-1`.
+    #[inline]
+    pub fn inv(&self) -> IntSeries {
+        self.assert_constant_term(&[1, -1], "Constant term must be a unit.");
+        let mut res = IntPoly::default();
+        unsafe {
+            fmpz_poly::fmpz_poly_inv_series(
+                res.as_mut_ptr(),
+                self.poly.as_ptr(),
+                prec_si(self.prec)
+            );
+        }
+        IntSeries { poly: res, prec: self.prec }
+    }
+
+    /// Return the square root of the series, or `None` if it is not the
+    /// square of an integer series. Panics if the constant term is zero.
+    ///
+    /// ```
+    /// use rufl::intseries::IntSeries;
+    ///
+    /// let f = IntSeries::new([1, 2, 1], 5);
+    /// assert_eq!(f.sqrt().unwrap(), IntSeries::new([1, 1], 5));
+    /// assert!(IntSeries::new([1, 1], 5).sqrt().is_none());
+    /// ```
+    pub fn sqrt(&self) -> Option<IntSeries> {
+        assert!(!self.poly.get_coeff(0).is_zero(), "Constant term must be nonzero.");
+        let mut res = IntPoly::default();
+        let ok = unsafe {
+            fmpz_poly::fmpz_poly_sqrt_series(
+                res.as_mut_ptr(),
+                self.poly.as_ptr(),
+                prec_si(self.prec)
+            )
+        };
+        if ok == 0 {
+            None
+        } else {
+            Some(IntSeries { poly: res, prec: self.prec })
+        }
+    }
+
+    /// Return the exponential of the series, which has rational coefficients
+    /// in general. Panics unless the constant term is zero.
+    ///
+    /// ```
+    /// use rufl::intseries::IntSeries;
+    /// use rufl::rational::Rational;
+    ///
+    /// let e = IntSeries::gen(4).exp();
+    /// assert_eq!(e.get_coeff(3), Rational::from([1, 6]));
+    /// ```
+    #[inline]
+    pub fn exp(&self) -> RatSeries {
+        RatSeries::from(self).exp()
+    }
+
+    /// Return the logarithm of the series, which has rational coefficients
+    /// in general. Panics unless the constant term is one.
+    #[inline]
+    pub fn log(&self) -> RatSeries {
+        RatSeries::from(self).log()
+    }
+
+    /// Return the arctangent of the series, which has rational coefficients
+    /// in general. Panics unless the constant term is zero.
+    #[inline]
+    pub fn atan(&self) -> RatSeries {
+        RatSeries::from(self).atan()
+    }
+
+    /// Return the composition `self(other)`. Panics unless the constant term
+    /// of `other` is zero.
+    ///
+    /// The result is known to precision `min(n_f v_g, n_g)`, where `v_g` is
+    /// the valuation of `other`.
+    ///
+    /// ```
+    /// use rufl::intseries::IntSeries;
+    ///
+    /// // 1/(1 - x) composed with x^2 is 1/(1 - x^2).
+    /// let f = IntSeries::new([1, -1], 4).inv();
+    /// let g = IntSeries::new([0, 0, 1], 10);
+    /// let h = f.compose(&g);
+    /// assert_eq!(h.prec(), 8);
+    /// assert_eq!(h.get_coeffs(), vec![1, 0, 1, 0, 1, 0, 1, 0]);
+    /// ```
+    pub fn compose<T: AsRef<IntSeries>>(&self, other: T) -> IntSeries {
+        let other = other.as_ref();
+        other.assert_constant_term(&[0], "Inner series must have zero constant term.");
+        let prec = std::cmp::min(
+            self.prec.saturating_mul(other.valuation()),
+            other.prec
+        );
+        let mut res = IntPoly::default();
+        unsafe {
+            fmpz_poly::fmpz_poly_compose_series(
+                res.as_mut_ptr(),
+                self.poly.as_ptr(),
+                other.poly.as_ptr(),
+                prec_si(prec)
+            );
+        }
+        IntSeries { poly: res, prec }
+    }
+
+    /// Return the compositional inverse `g` of the series `f`, so that
+    /// `f(g) = g(f) = x`. Panics unless the constant term is zero and the
+    /// coefficient of `x` is `1` or `-1`.
+    ///
+    /// ```
+    /// use rufl::intseries::IntSeries;
+    ///
+    /// let f = IntSeries::new([0, 1, 1], 6);
+    /// let g = f.revert();
+    /// assert_eq!(f.compose(&g), IntSeries::gen(6));
+    /// ```
+    pub fn revert(&self) -> IntSeries {
+        self.assert_constant_term(&[0], "Constant term must be zero.");
+        let c = self.poly.get_coeff(1);
+        assert!(c == 1 || c == -1, "Linear coefficient must be a unit.");
+        let mut res = IntPoly::default();
+        unsafe {
+            fmpz_poly::fmpz_poly_revert_series(
+                res.as_mut_ptr(),
+                self.poly.as_ptr(),
+                prec_si(self.prec)
+            );
+        }
+        IntSeries { poly: res, prec: self.prec }
+    }
+}
@@ -0,0 +1,116 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::{IntPoly, IntSeries};
+use crate::intseries::prec_si;
+use crate::ops::*;
+use flint_sys::fmpz_poly;
+use std::cmp::min;
+
+impl_cmp! {
+    eq
+    IntSeries
+    {
+        fn eq(&self, rhs: &IntSeries) -> bool {
+            self.prec() == rhs.prec() && self.poly() == rhs.poly()
+        }
+    }
+}
+
+impl_unop! {
+    IntSeries
+    Neg {neg}
+    {
+        fn neg(self) -> IntSeries {
+            IntSeries::new(-self.poly(), self.prec())
+        }
+    }
+    NegAssign {neg_assign}
+    {
+        fn neg_assign(&mut self) {
+            *self = -&*self;
+        }
+    }
+}
+
+fn add(lhs: &IntSeries, rhs: &IntSeries) -> IntSeries {
+    IntSeries::new(lhs.poly() + rhs.poly(), min(lhs.prec(), rhs.prec()))
+}
+
+fn sub(lhs: &IntSeries, rhs: &IntSeries) -> IntSeries {
+    IntSeries::new(lhs.poly() - rhs.poly(), min(lhs.prec(), rhs.prec()))
+}
+
+fn mul(lhs: &IntSeries, rhs: &IntSeries) -> IntSeries {
+    let prec = min(
+        lhs.prec() + rhs.valuation(),
+        rhs.prec() + lhs.valuation()
+    );
+    let mut res = IntPoly::default();
+    unsafe {
+        fmpz_poly::fmpz_poly_mullow(
+            res.as_mut_ptr(),
+            lhs.poly().as_ptr(),
+            rhs.poly().as_ptr(),
+            prec_si(prec)
+        );
+    }
+    IntSeries::new(res, prec)
+}
+
+fn div(lhs: &IntSeries, rhs: &IntSeries) -> IntSeries {
+    let c = rhs.poly().get_coeff(0);
+    assert!(c == 1 || c == -1, "Constant term of the divisor must be a unit.");
+    let prec = min(lhs.prec(), rhs.prec() + lhs.valuation());
+    let mut res = IntPoly::default();
+    unsafe {
+        fmpz_poly::fmpz_poly_div_series(
+            res.as_mut_ptr(),
+            lhs.poly().as_ptr(),
+            rhs.poly().as_ptr(),
+            prec_si(prec)
+        );
+    }
+    IntSeries::new(res, prec)
+}
+
+impl_binop_series! {
+    IntSeries
+    Add {add}
+    AddAssign {add_assign}
+    AddFrom {add_from}
+    AssignAdd {assign_add}
+    add;
+
+    Sub {sub}
+    SubAssign {sub_assign}
+    SubFrom {sub_from}
+    AssignSub {assign_sub}
+    sub;
+
+    Mul {mul}
+    MulAssign {mul_assign}
+    MulFrom {mul_from}
+    AssignMul {assign_mul}
+    mul;
+
+    Div {div}
+    DivAssign {div_assign}
+    DivFrom {div_from}
+    AssignDiv {assign_div}
+    div;
+}
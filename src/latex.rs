@@ -0,0 +1,413 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! LaTeX and MathML output for typesetting values in papers and notebooks.
+//!
+//! With the `evcxr` feature, every type implementing [ToLatex] and
+//! [ToMathML] also gets an `evcxr_display` method, so values render as
+//! typeset mathematics in Jupyter notebooks using the evcxr Rust kernel.
+
+use crate::*;
+
+const MATHML_NS: &str = "http://www.w3.org/1998/Math/MathML";
+
+/// Conversion to LaTeX source for use in math mode.
+///
+/// ```
+/// use rufl::intpoly::IntPoly;
+/// use rufl::latex::ToLatex;
+/// use rufl::rational::Rational;
+///
+/// assert_eq!(Rational::from([-3, 4]).to_latex(), "-\\frac{3}{4}");
+/// assert_eq!(IntPoly::from([1, -1, 0, 2]).to_latex(), "2x^{3} - x + 1");
+/// ```
+pub trait ToLatex {
+    fn to_latex(&self) -> String;
+}
+
+/// Conversion to presentation MathML.
+///
+/// ```
+/// use rufl::integer::Integer;
+/// use rufl::latex::ToMathML;
+///
+/// let z = Integer::from(-12);
+/// assert_eq!(z.to_mathml_inner(), "<mrow><mo>-</mo><mn>12</mn></mrow>");
+/// assert!(z.to_mathml().starts_with("<math xmlns="));
+/// ```
+pub trait ToMathML {
+    /// Return the MathML of `self` without the enclosing `<math>` element,
+    /// for embedding in a larger expression.
+    fn to_mathml_inner(&self) -> String;
+
+    /// Return a complete `<math>` element.
+    fn to_mathml(&self) -> String {
+        format!("<math xmlns=\"{}\">{}</math>", MATHML_NS, self.to_mathml_inner())
+    }
+}
+
+// A coefficient given by its sign, whether its absolute value is one, and
+// its rendered absolute value.
+type Coeff = (i32, bool, String);
+
+// A polynomial in `x` in LaTeX, leading term first, given its coefficients
+// constant term first.
+fn latex_poly(coeffs: &[Coeff]) -> String {
+    let mut out = String::new();
+    for (k, (s, one, abs)) in coeffs.iter().enumerate().rev() {
+        if *s == 0 {
+            continue;
+        }
+        if out.is_empty() {
+            if *s < 0 {
+                out.push('-');
+            }
+        } else if *s < 0 {
+            out.push_str(" - ");
+        } else {
+            out.push_str(" + ");
+        }
+        if k == 0 || !one {
+            out.push_str(abs);
+        }
+        match k {
+            0 => {}
+            1 => out.push('x'),
+            _ => out.push_str(&format!("x^{{{}}}", k)),
+        }
+    }
+    if out.is_empty() {
+        out.push('0');
+    }
+    out
+}
+
+// A polynomial in `x` in MathML, as for `latex_poly`.
+fn mathml_poly(coeffs: &[Coeff]) -> String {
+    let mut out = String::new();
+    for (k, (s, one, abs)) in coeffs.iter().enumerate().rev() {
+        if *s == 0 {
+            continue;
+        }
+        if *s < 0 {
+            out.push_str("<mo>-</mo>");
+        } else if !out.is_empty() {
+            out.push_str("<mo>+</mo>");
+        }
+        if k == 0 {
+            out.push_str(abs);
+            continue;
+        }
+        if !one {
+            // An invisible times between the coefficient and the variable.
+            out.push_str(abs);
+            out.push_str("<mo>&#x2062;</mo>");
+        }
+        if k == 1 {
+            out.push_str("<mi>x</mi>");
+        } else {
+            out.push_str(&format!("<msup><mi>x</mi><mn>{}</mn></msup>", k));
+        }
+    }
+    if out.is_empty() {
+        out.push_str("<mn>0</mn>");
+    }
+    format!("<mrow>{}</mrow>", out)
+}
+
+fn latex_matrix<F: Fn(usize, usize) -> String>(nrows: usize, ncols: usize, entry: F) -> String {
+    let rows: Vec<String> = (0..nrows)
+        .map(|i| (0..ncols).map(|j| entry(i, j)).collect::<Vec<_>>().join(" & "))
+        .collect();
+    format!("\\begin{{pmatrix}}{}\\end{{pmatrix}}", rows.join(" \\\\ "))
+}
+
+fn mathml_matrix<F: Fn(usize, usize) -> String>(nrows: usize, ncols: usize, entry: F) -> String {
+    let mut out = String::from("<mrow><mo>(</mo><mtable>");
+    for i in 0..nrows {
+        out.push_str("<mtr>");
+        for j in 0..ncols {
+            out.push_str(&format!("<mtd>{}</mtd>", entry(i, j)));
+        }
+        out.push_str("</mtr>");
+    }
+    out.push_str("</mtable><mo>)</mo></mrow>");
+    out
+}
+
+fn latex_pmod<T: std::fmt::Display>(body: &str, modulus: T) -> String {
+    format!("{} \\pmod{{{}}}", body, modulus)
+}
+
+fn mathml_pmod<T: std::fmt::Display>(body: &str, modulus: T) -> String {
+    format!(
+        "<mrow>{}<mspace width=\"1em\"/><mo>(</mo><mo>mod</mo>\
+         <mspace width=\"0.333em\"/><mn>{}</mn><mo>)</mo></mrow>",
+        body, modulus
+    )
+}
+
+fn int_coeff(c: &Integer) -> (i32, bool, Integer) {
+    let abs = c.abs();
+    (c.sign(), abs.is_one(), abs)
+}
+
+impl ToLatex for Integer {
+    fn to_latex(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl ToMathML for Integer {
+    fn to_mathml_inner(&self) -> String {
+        if self.sign() < 0 {
+            format!("<mrow><mo>-</mo><mn>{}</mn></mrow>", self.abs())
+        } else {
+            format!("<mn>{}</mn>", self)
+        }
+    }
+}
+
+impl ToLatex for Rational {
+    fn to_latex(&self) -> String {
+        let den = self.denominator();
+        if den.is_one() {
+            return self.numerator().to_latex();
+        }
+        let num = self.numerator();
+        let sign = if num.sign() < 0 { "-" } else { "" };
+        format!("{}\\frac{{{}}}{{{}}}", sign, num.abs(), den)
+    }
+}
+
+impl ToMathML for Rational {
+    fn to_mathml_inner(&self) -> String {
+        let den = self.denominator();
+        if den.is_one() {
+            return self.numerator().to_mathml_inner();
+        }
+        let num = self.numerator();
+        let frac = format!("<mfrac><mn>{}</mn><mn>{}</mn></mfrac>", num.abs(), den);
+        if num.sign() < 0 {
+            format!("<mrow><mo>-</mo>{}</mrow>", frac)
+        } else {
+            frac
+        }
+    }
+}
+
+/// ```
+/// use rufl::intmod::{IntMod, IntModCtx};
+/// use rufl::latex::ToLatex;
+///
+/// let ctx = IntModCtx::new(7);
+/// assert_eq!(IntMod::new(-4, &ctx).to_latex(), "3 \\pmod{7}");
+/// ```
+impl ToLatex for IntMod {
+    fn to_latex(&self) -> String {
+        latex_pmod(&self.to_string(), self.modulus())
+    }
+}
+
+impl ToMathML for IntMod {
+    fn to_mathml_inner(&self) -> String {
+        mathml_pmod(&format!("<mn>{}</mn>", self), self.modulus())
+    }
+}
+
+impl ToLatex for IntModWord {
+    fn to_latex(&self) -> String {
+        latex_pmod(&self.get_ui().to_string(), self.modulus())
+    }
+}
+
+impl ToMathML for IntModWord {
+    fn to_mathml_inner(&self) -> String {
+        mathml_pmod(&format!("<mn>{}</mn>", self.get_ui()), self.modulus())
+    }
+}
+
+impl ToLatex for IntPoly {
+    fn to_latex(&self) -> String {
+        let coeffs: Vec<Coeff> = self.get_coeffs()
+            .iter()
+            .map(|c| {
+                let (s, one, abs) = int_coeff(c);
+                (s, one, abs.to_latex())
+            })
+            .collect();
+        latex_poly(&coeffs)
+    }
+}
+
+impl ToMathML for IntPoly {
+    fn to_mathml_inner(&self) -> String {
+        let coeffs: Vec<Coeff> = self.get_coeffs()
+            .iter()
+            .map(|c| {
+                let (s, one, abs) = int_coeff(c);
+                (s, one, abs.to_mathml_inner())
+            })
+            .collect();
+        mathml_poly(&coeffs)
+    }
+}
+
+/// ```
+/// use rufl::latex::ToLatex;
+/// use rufl::rational::Rational;
+/// use rufl::ratpoly::RatPoly;
+///
+/// let f = RatPoly::from([Rational::from([1, 2]), Rational::from([-2, 3])]);
+/// assert_eq!(f.to_latex(), "-\\frac{2}{3}x + \\frac{1}{2}");
+/// ```
+impl ToLatex for RatPoly {
+    fn to_latex(&self) -> String {
+        let coeffs: Vec<Coeff> = self.get_coeffs()
+            .iter()
+            .map(|c| {
+                let abs = c.abs();
+                (c.sign(), abs.is_one(), abs.to_latex())
+            })
+            .collect();
+        latex_poly(&coeffs)
+    }
+}
+
+impl ToMathML for RatPoly {
+    fn to_mathml_inner(&self) -> String {
+        let coeffs: Vec<Coeff> = self.get_coeffs()
+            .iter()
+            .map(|c| {
+                let abs = c.abs();
+                (c.sign(), abs.is_one(), abs.to_mathml_inner())
+            })
+            .collect();
+        mathml_poly(&coeffs)
+    }
+}
+
+/// ```
+/// use rufl::intmod::IntModCtx;
+/// use rufl::intmodpoly::IntModPoly;
+/// use rufl::latex::ToLatex;
+/// use rufl::ops::NewCtx;
+///
+/// let ctx = IntModCtx::new(5);
+/// let f = IntModPoly::new([-1, 0, 1], &ctx);
+/// assert_eq!(f.to_latex(), "x^{2} + 4 \\pmod{5}");
+/// ```
+impl ToLatex for IntModPoly {
+    fn to_latex(&self) -> String {
+        latex_pmod(&IntPoly::from(self).to_latex(), self.modulus())
+    }
+}
+
+impl ToMathML for IntModPoly {
+    fn to_mathml_inner(&self) -> String {
+        mathml_pmod(&IntPoly::from(self).to_mathml_inner(), self.modulus())
+    }
+}
+
+impl ToLatex for IntModWordPoly {
+    fn to_latex(&self) -> String {
+        latex_pmod(&IntPoly::from(self).to_latex(), self.modulus())
+    }
+}
+
+impl ToMathML for IntModWordPoly {
+    fn to_mathml_inner(&self) -> String {
+        mathml_pmod(&IntPoly::from(self).to_mathml_inner(), self.modulus())
+    }
+}
+
+/// ```
+/// use rufl::intmat::IntMat;
+/// use rufl::latex::ToLatex;
+///
+/// let m = IntMat::new([1, 2, 3, -4], 2, 2);
+/// assert_eq!(m.to_latex(), "\\begin{pmatrix}1 & 2 \\\\ 3 & -4\\end{pmatrix}");
+/// ```
+impl ToLatex for IntMat {
+    fn to_latex(&self) -> String {
+        latex_matrix(self.nrows(), self.ncols(), |i, j| self.get_entry(i, j).to_latex())
+    }
+}
+
+impl ToMathML for IntMat {
+    fn to_mathml_inner(&self) -> String {
+        mathml_matrix(self.nrows(), self.ncols(), |i, j| self.get_entry(i, j).to_mathml_inner())
+    }
+}
+
+impl ToLatex for RatMat {
+    fn to_latex(&self) -> String {
+        latex_matrix(self.nrows(), self.ncols(), |i, j| self.get_entry(i, j).to_latex())
+    }
+}
+
+impl ToMathML for RatMat {
+    fn to_mathml_inner(&self) -> String {
+        mathml_matrix(self.nrows(), self.ncols(), |i, j| self.get_entry(i, j).to_mathml_inner())
+    }
+}
+
+impl ToLatex for IntModWordMat {
+    fn to_latex(&self) -> String {
+        let m = latex_matrix(self.nrows(), self.ncols(), |i, j| {
+            self.get_entry(i, j).get_ui().to_string()
+        });
+        latex_pmod(&m, self.modulus())
+    }
+}
+
+impl ToMathML for IntModWordMat {
+    fn to_mathml_inner(&self) -> String {
+        let m = mathml_matrix(self.nrows(), self.ncols(), |i, j| {
+            format!("<mn>{}</mn>", self.get_entry(i, j).get_ui())
+        });
+        mathml_pmod(&m, self.modulus())
+    }
+}
+
+// Jupyter picks the richest of the two representations it can display.
+#[cfg(feature = "evcxr")]
+macro_rules! impl_evcxr_display {
+    ($($t:ident)*) => ($(
+        impl $t {
+            /// Display the value as typeset mathematics in a Jupyter
+            /// notebook running the evcxr kernel.
+            pub fn evcxr_display(&self) {
+                println!(
+                    "EVCXR_BEGIN_CONTENT text/latex\n$${}$$\nEVCXR_END_CONTENT",
+                    self.to_latex()
+                );
+                println!(
+                    "EVCXR_BEGIN_CONTENT text/html\n{}\nEVCXR_END_CONTENT",
+                    self.to_mathml()
+                );
+            }
+        }
+    )*)
+}
+
+#[cfg(feature = "evcxr")]
+impl_evcxr_display! {
+    Integer Rational IntMod IntModWord
+    IntPoly RatPoly IntModPoly IntModWordPoly
+    IntMat RatMat IntModWordMat
+}
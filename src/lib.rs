@@ -39,6 +39,9 @@ pub use rational::*;
 pub mod intmod;
 pub use intmod::*;
 
+pub mod polyfmt;
+pub use polyfmt::*;
+
 pub mod intpoly;
 pub use intpoly::*;
 
@@ -48,15 +51,48 @@ pub use ratpoly::*;
 pub mod intmodpoly;
 pub use intmodpoly::*;
 
+pub mod intmodword;
+pub use intmodword::*;
+
+pub mod intmodwordpoly;
+pub use intmodwordpoly::*;
+
+pub mod intseries;
+pub use intseries::*;
+
+pub mod ratseries;
+pub use ratseries::*;
+
+pub mod intmodseries;
+pub use intmodseries::*;
+
+pub mod matio;
+pub use matio::*;
+
 pub mod intmat;
 pub use intmat::*;
 
 pub mod ratmat;
 pub use ratmat::*;
 
+pub mod intmodwordmat;
+pub use intmodwordmat::*;
+
+pub mod sparseintmat;
+pub use sparseintmat::*;
+
+pub mod sparseintmodmat;
+pub use sparseintmodmat::*;
+
+pub mod latex;
+pub use latex::*;
+
 pub mod gr;
 pub use gr::*;
 
+pub mod rand;
+pub use rand::*;
+
 /*
 mod intpoly;
 mod intmat;
@@ -124,12 +160,19 @@ pub use binquad::*;
 pub use numfld::*;
 */
 mod util {
+    /// The value of the digit `c` in base `base`, following FLINT's
+    /// convention: bases up to 36 are case-insensitive, while larger bases
+    /// use `0-9`, then `A-Z`, then `a-z`.
     #[must_use]
     #[inline]
-    pub fn is_digit(c: char) -> bool {
-        match c {
-            '0'..='9' => true,
-            _ => false,
-        }
+    pub fn digit_value(c: char, base: u32) -> Option<u32> {
+        let v = match c {
+            '0'..='9' => c as u32 - '0' as u32,
+            'A'..='Z' => c as u32 - 'A' as u32 + 10,
+            'a'..='z' if base <= 36 => c as u32 - 'a' as u32 + 10,
+            'a'..='z' => c as u32 - 'a' as u32 + 36,
+            _ => return None,
+        };
+        if v < base { Some(v) } else { None }
     }
 }
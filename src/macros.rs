@@ -26,6 +26,9 @@ macro_rules! default {
     ($op:ident, ctx_in, $out_ty:ident, $in:ident) => {
         $out_ty::zero()
     };
+    ($op:ident, ctx_new_only, $out_ty:ident, $in:ident) => {
+        $out_ty::zero($in.context())
+    };
     ($op:ident, matrix, $out_ty:ident, $in:ident) => {
         $out_ty::zero($in.nrows_si(), $in.ncols_si())
     };
@@ -49,6 +52,9 @@ macro_rules! default {
     ($op:ident, ctx_rhs, $out_ty:ident, $lhs:ident, $rhs:ident) => {
         $out_ty::zero($rhs.context())
     };
+    ($op:ident, ctx_new_only, $out_ty:ident, $lhs:ident, $rhs:ident) => {
+        $out_ty::zero($lhs.context())
+    };
     ($op:ident, matrix, $out_ty:ident, $lhs:ident, $rhs:ident) => {
         $out_ty::zero($lhs.nrows_si(), $rhs.ncols_si())
     };
@@ -94,6 +100,22 @@ macro_rules! op_guard {
         // check contexts agree
         assert_eq!($lhs.context(), $rhs.context())
     };
+    ($op:ident, ctx_new_only, $out_ty:ident, $lhs:ident, $rhs:ident) => {
+        assert_eq!($lhs.context(), $rhs.context())
+    };
+    (Mul, matrix_ctx_new_only, $out_ty:ident, $lhs:ident, $rhs:ident) => {
+        assert_eq!($lhs.context(), $rhs.context());
+        assert_eq!($lhs.ncols_si(), $rhs.nrows_si())
+    };
+    (MulAssign, matrix_ctx_new_only, $out_ty:ident, $lhs:ident, $rhs:ident) => {
+        assert_eq!($lhs.context(), $rhs.context());
+        assert_eq!($lhs.ncols_si(), $rhs.nrows_si())
+    };
+    ($op:ident, matrix_ctx_new_only, $out_ty:ident, $lhs:ident, $rhs:ident) => {
+        assert_eq!($lhs.context(), $rhs.context());
+        assert_eq!($lhs.nrows_si(), $rhs.nrows_si());
+        assert_eq!($lhs.ncols_si(), $rhs.ncols_si())
+    };
     (Mul, matrix, $out_ty:ident, $lhs:ident, $rhs:ident) => {
         assert_eq!($lhs.ncols_si(), $rhs.nrows_si())
     };
@@ -1371,6 +1393,312 @@ macro_rules! impl_binop_unsafe {
     ) => {};
 }
 
+/// Macros for overloading the arithmetic operators of power series through a
+/// function computing the result and its precision.
+#[macro_export]
+macro_rules! impl_binop_series {
+    (
+        $t:ident
+        $(
+            $op:ident {$meth:ident}
+            $op_assign:ident {$meth_assign:ident}
+            $op_from:ident {$meth_from:ident}
+            $assign_op:ident {$assign_meth:ident}
+            $func:ident;
+        )+
+    ) => ($(
+        impl_binop! {
+            $t, $t, $t
+            $op {$meth}
+            {
+                fn $meth(self, rhs: &$t) -> $t {
+                    $func(self, rhs)
+                }
+            }
+            $op_assign {$meth_assign}
+            {
+                fn $meth_assign(&mut self, rhs: &$t) {
+                    *self = $func(self, rhs);
+                }
+            }
+            $op_from {$meth_from}
+            {
+                fn $meth_from(&mut self, lhs: &$t) {
+                    *self = $func(lhs, self);
+                }
+            }
+            $assign_op {$assign_meth}
+            {
+                fn $assign_meth(&mut self, lhs: &$t, rhs: &$t) {
+                    *self = $func(lhs, rhs);
+                }
+            }
+        }
+    )+);
+}
+
+/// Macros for defining the subproduct tree helpers used for fast
+/// multipoint evaluation and interpolation: `product_tree`, building the
+/// levels of the tree from the leaves `$leaf(x_i)` up to the root,
+/// `evaluate_tree`, reducing a polynomial down the tree, and `combine_tree`,
+/// summing the weights `w_i` times the product of the other leaves.
+#[macro_export]
+macro_rules! impl_subproduct_tree {
+    ($poly:ident, $coeff:ident, $leaf:path) => {
+        // The levels of the subproduct tree over the points `xs`, from the
+        // leaves `x - x_i` up to the root, the product of all of them.
+        fn product_tree(xs: &[$coeff]) -> Vec<Vec<$poly>> {
+            let leaves = xs.iter().map($leaf).collect();
+            let mut tree: Vec<Vec<$poly>> = vec![leaves];
+            while tree.last().unwrap().len() > 1 {
+                let next = tree.last().unwrap()
+                    .chunks(2)
+                    .map(|c| if c.len() == 2 { &c[0] * &c[1] } else { c[0].clone() })
+                    .collect();
+                tree.push(next);
+            }
+            tree
+        }
+
+        // Evaluate `f` at the leaves of the subproduct tree by reducing down
+        // it. The tree nodes are monic, so the reductions cannot fail.
+        fn evaluate_tree(f: &$poly, tree: &[Vec<$poly>]) -> Vec<$coeff> {
+            let mut rems = vec![f.clone()];
+            for level in tree.iter().rev() {
+                rems = level.iter().enumerate().map(|(i, m)| &rems[i / 2] % m).collect();
+            }
+            rems.iter().map(|r| r.get_coeff(0)).collect()
+        }
+
+        // Combine the Lagrange weights `vals` up the tree, given without its
+        // root.
+        fn combine_tree(mut vals: Vec<$poly>, tree: &[Vec<$poly>]) -> $poly {
+            for level in tree {
+                vals = vals.chunks(2).enumerate().map(|(j, v)| {
+                    if v.len() == 2 {
+                        &v[0] * &level[2 * j + 1] + &v[1] * &level[2 * j]
+                    } else {
+                        v[0].clone()
+                    }
+                }).collect();
+            }
+            vals.pop().unwrap()
+        }
+    };
+}
+
+/// Macros for defining an incremental Berlekamp–Massey computation over a
+/// field `$elem` with polynomials `$poly`. The caller provides the type
+/// documentation, a constructor calling `start(zero, one)`, an optional
+/// check that a term `$t` belongs to the same field as `$z`, and the zero
+/// polynomial over the field of `$pz`.
+#[macro_export]
+macro_rules! impl_berlekamp_massey {
+    (
+        $(#[$attr:meta])*
+        $name:ident, $elem:ident, $poly:ident
+        $(check: |$t:ident, $z:ident| $check:expr;)?
+        zero_poly: |$pz:ident| $zero_poly:expr;
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Debug)]
+        pub struct $name {
+            seq: Vec<$elem>,
+            // The current and previous connection polynomials, low degree
+            // first.
+            c: Vec<$elem>,
+            b: Vec<$elem>,
+            l: usize,
+            // The shift of `b` and the discrepancy at which it was last
+            // updated.
+            m: usize,
+            last: $elem,
+            zero: $elem,
+        }
+
+        impl $name {
+            fn start(zero: $elem, one: $elem) -> Self {
+                $name {
+                    seq: vec![],
+                    c: vec![one.clone()],
+                    b: vec![one.clone()],
+                    l: 0,
+                    m: 1,
+                    last: one,
+                    zero,
+                }
+            }
+
+            /// Feed the next term of the sequence.
+            pub fn push<T: AsRef<$elem>>(&mut self, term: T) {
+                let term = term.as_ref();
+                $({
+                    let ($t, $z) = (term, &self.zero);
+                    $check;
+                })?
+                let n = self.seq.len();
+                self.seq.push(term.clone());
+
+                // The discrepancy between the term and the current
+                // prediction.
+                let mut d = term.clone();
+                for i in 1..=self.l {
+                    if let Some(ci) = self.c.get(i) {
+                        d += ci * &self.seq[n - i];
+                    }
+                }
+                if d.is_zero() {
+                    self.m += 1;
+                    return;
+                }
+
+                let coef = &d / &self.last;
+                let prev = if 2 * self.l <= n { Some(self.c.clone()) } else { None };
+                if self.c.len() < self.b.len() + self.m {
+                    self.c.resize(self.b.len() + self.m, self.zero.clone());
+                }
+                for (j, bj) in self.b.iter().enumerate() {
+                    self.c[j + self.m] -= &coef * bj;
+                }
+                match prev {
+                    Some(prev) => {
+                        self.l = n + 1 - self.l;
+                        self.b = prev;
+                        self.last = d;
+                        self.m = 1;
+                    }
+                    None => self.m += 1,
+                }
+            }
+
+            /// Return the number of terms fed so far.
+            #[inline]
+            pub fn len(&self) -> usize {
+                self.seq.len()
+            }
+
+            /// Return true if no terms have been fed.
+            #[inline]
+            pub fn is_empty(&self) -> bool {
+                self.seq.is_empty()
+            }
+
+            /// Return the terms fed so far.
+            #[inline]
+            pub fn terms(&self) -> &[$elem] {
+                &self.seq
+            }
+
+            /// Return the linear complexity `L`, the length of the shortest
+            /// linear recurrence generating the terms fed so far.
+            #[inline]
+            pub fn linear_complexity(&self) -> usize {
+                self.l
+            }
+
+            /// Return the connection polynomial `C(x)` of the shortest
+            /// recurrence.
+            pub fn connection_polynomial(&self) -> $poly {
+                let mut res = {
+                    let $pz = &self.zero;
+                    $zero_poly
+                };
+                for (i, ci) in self.c.iter().enumerate().take(self.l + 1) {
+                    res.set_coeff(i, ci);
+                }
+                res
+            }
+        }
+
+        impl<T: AsRef<$elem>> Extend<T> for $name {
+            fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+                for term in iter {
+                    self.push(term);
+                }
+            }
+        }
+    };
+}
+
+/// Macros for defining `check_recurrence` and the `recurrence_terms`
+/// iterator on a polynomial type `$poly` with coefficients `$elem`. The
+/// caller provides an optional check that an initial term `$s` belongs to
+/// the same ring as `$f`, the zero of the coefficient ring of `$zf`, and the
+/// documentation of the method and iterator type.
+#[macro_export]
+macro_rules! impl_recurrence_terms {
+    (
+        $poly:ident, $elem:ident
+        $(check: |$s:ident, $f:ident| $check:expr;)?
+        zero: |$zf:ident| $zero:expr;
+        $(#[$mattr:meta])*
+        fn recurrence_terms;
+        $(#[$iattr:meta])*
+        struct $iter:ident;
+    ) => {
+        impl $poly {
+            // Panic unless `self` is a valid characteristic polynomial for
+            // the initial terms `init`, returning its degree.
+            fn check_recurrence(&self, init: &[$elem]) -> usize {
+                let d = self.degree();
+                assert!(
+                    d > 0 && self.get_coeff(d as usize).is_one(),
+                    "The characteristic polynomial must be monic of positive degree."
+                );
+                let d = d as usize;
+                assert_eq!(
+                    init.len(),
+                    d,
+                    "Expected one initial term per degree of the characteristic polynomial."
+                );
+                $(
+                    for $s in init {
+                        let $f = self;
+                        $check;
+                    }
+                )?
+                d
+            }
+
+            $(#[$mattr])*
+            pub fn recurrence_terms(&self, init: &[$elem]) -> $iter {
+                let d = self.check_recurrence(init);
+                $iter {
+                    coeffs: self.get_coeffs().into_iter().take(d).collect(),
+                    window: init.iter().cloned().collect(),
+                    zero: {
+                        let $zf = self;
+                        $zero
+                    },
+                }
+            }
+        }
+
+        $(#[$iattr])*
+        #[derive(Clone, Debug)]
+        pub struct $iter {
+            // The non-leading coefficients of the characteristic polynomial.
+            coeffs: Vec<$elem>,
+            // The next `d` terms.
+            window: std::collections::VecDeque<$elem>,
+            zero: $elem,
+        }
+
+        impl Iterator for $iter {
+            type Item = $elem;
+
+            fn next(&mut self) -> Option<$elem> {
+                let mut next = self.zero.clone();
+                for (p, s) in self.coeffs.iter().zip(self.window.iter()) {
+                    next -= p * s;
+                }
+                self.window.push_back(next);
+                self.window.pop_front()
+            }
+        }
+    };
+}
+
 /// Macros for implementing `From` for conversions.
 #[macro_export]
 macro_rules! impl_from {
@@ -0,0 +1,714 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Reading and writing matrices in interchange formats.
+
+use crate::{Error, Integer, Rational, Result};
+use crate::ops::Pow;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::str::FromStr;
+
+/// A textual format for exchanging matrices with other software, used by
+/// `read_format` and `write_format` on [IntMat][crate::IntMat] and
+/// [RatMat][crate::RatMat].
+///
+/// Matrix Market has no field for rational numbers, so rational matrices
+/// are written with the nonstandard field `rational` and entries `p/q`.
+/// Reading accepts the `integer`, `rational`, `real` and `pattern` fields
+/// and the `general`, `symmetric` and `skew-symmetric` symmetries, with
+/// `real` entries read exactly into rational matrices.
+///
+/// ```
+/// use rufl::intmat::IntMat;
+/// use rufl::matio::MatrixFormat;
+///
+/// let m = IntMat::new([1, 0, -3, 4], 2, 2);
+/// assert_eq!(m.to_str_format(MatrixFormat::Pari), "[1,0;-3,4]");
+/// assert_eq!(m.to_str_format(MatrixFormat::Sage), "matrix(ZZ, [[1, 0], [-3, 4]])");
+/// assert_eq!(m.to_str_format(MatrixFormat::Magma), "Matrix(Integers(), 2, 2, [1, 0, -3, 4])");
+/// assert_eq!(m.to_str_format(MatrixFormat::Text), "1 0\n-3 4\n");
+/// assert_eq!(
+///     m.to_str_format(MatrixFormat::MatrixMarketCoordinate),
+///     "%%MatrixMarket matrix coordinate integer general\n2 2 3\n1 1 1\n2 1 -3\n2 2 4\n"
+/// );
+///
+/// for fmt in [MatrixFormat::Pari, MatrixFormat::Sage, MatrixFormat::Magma] {
+///     assert_eq!(IntMat::from_str_format(&m.to_str_format(fmt), fmt).unwrap(), m);
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MatrixFormat {
+    /// Matrix Market coordinate format, listing the nonzero entries. Either
+    /// Matrix Market layout is accepted when reading.
+    MatrixMarketCoordinate,
+    /// Matrix Market array format, listing every entry in column-major
+    /// order. Either Matrix Market layout is accepted when reading.
+    MatrixMarketArray,
+    /// One row per line with entries separated by whitespace. Blank lines
+    /// are skipped.
+    Text,
+    /// The PARI/GP literal `[a,b;c,d]`, or `matrix(m,n)` for an empty
+    /// matrix.
+    Pari,
+    /// The Sage constructor `matrix(ZZ, [[a, b], [c, d]])`. Reading also
+    /// accepts the forms `matrix(R, m, n, [...])` and `matrix([...])`.
+    Sage,
+    /// The Magma constructor `Matrix(Integers(), m, n, [a, b, c, d])`.
+    /// Reading also accepts a list of rows in place of the dimensions and
+    /// flat list.
+    Magma,
+}
+
+// The names of a ring of entries in each format.
+pub(crate) struct Ring {
+    pub mm_field: &'static str,
+    pub sage: &'static str,
+    pub magma: &'static str,
+}
+
+pub(crate) const INTEGERS: Ring = Ring {
+    mm_field: "integer",
+    sage: "ZZ",
+    magma: "Integers()",
+};
+
+pub(crate) const RATIONALS: Ring = Ring {
+    mm_field: "rational",
+    sage: "QQ",
+    magma: "Rationals()",
+};
+
+pub(crate) fn write_matrix<W, F>(
+    mut w: W,
+    fmt: MatrixFormat,
+    ring: &Ring,
+    nrows: usize,
+    ncols: usize,
+    entry: F
+) -> io::Result<()>
+where
+    W: Write,
+    F: Fn(usize, usize) -> String
+{
+    let rows: Vec<Vec<String>> = (0..nrows)
+        .map(|i| (0..ncols).map(|j| entry(i, j)).collect())
+        .collect();
+
+    match fmt {
+        MatrixFormat::MatrixMarketCoordinate => {
+            let nonzero: Vec<(usize, usize, &String)> = rows.iter()
+                .enumerate()
+                .flat_map(|(i, row)| row.iter().enumerate().map(move |(j, e)| (i, j, e)))
+                .filter(|(_, _, e)| *e != "0")
+                .collect();
+            writeln!(w, "%%MatrixMarket matrix coordinate {} general", ring.mm_field)?;
+            writeln!(w, "{} {} {}", nrows, ncols, nonzero.len())?;
+            for (i, j, e) in nonzero {
+                writeln!(w, "{} {} {}", i + 1, j + 1, e)?;
+            }
+        }
+        MatrixFormat::MatrixMarketArray => {
+            writeln!(w, "%%MatrixMarket matrix array {} general", ring.mm_field)?;
+            writeln!(w, "{} {}", nrows, ncols)?;
+            for j in 0..ncols {
+                for row in rows.iter() {
+                    writeln!(w, "{}", row[j])?;
+                }
+            }
+        }
+        MatrixFormat::Text => {
+            for row in rows.iter() {
+                writeln!(w, "{}", row.join(" "))?;
+            }
+        }
+        MatrixFormat::Pari => {
+            if nrows == 0 || ncols == 0 {
+                write!(w, "matrix({},{})", nrows, ncols)?;
+            } else {
+                let rows: Vec<String> = rows.iter().map(|row| row.join(",")).collect();
+                write!(w, "[{}]", rows.join(";"))?;
+            }
+        }
+        MatrixFormat::Sage => {
+            if nrows == 0 || ncols == 0 {
+                write!(w, "matrix({}, {}, {})", ring.sage, nrows, ncols)?;
+            } else {
+                let rows: Vec<String> = rows.iter()
+                    .map(|row| format!("[{}]", row.join(", ")))
+                    .collect();
+                write!(w, "matrix({}, [{}])", ring.sage, rows.join(", "))?;
+            }
+        }
+        MatrixFormat::Magma => {
+            write!(
+                w,
+                "Matrix({}, {}, {}, [{}])",
+                ring.magma,
+                nrows,
+                ncols,
+                rows.concat().join(", ")
+            )?;
+        }
+    }
+    Ok(())
+}
+
+// An entry read from the input, with the line it appeared on. Entries not
+// read are zero.
+pub(crate) struct Entry {
+    pub i: usize,
+    pub j: usize,
+    pub val: String,
+    // Set for the mirrored entries of a skew-symmetric matrix.
+    pub neg: bool,
+    pub line: usize,
+}
+
+pub(crate) struct ParsedMatrix {
+    pub nrows: usize,
+    pub ncols: usize,
+    // Set if the entries are decimal numbers, from a Matrix Market file
+    // with the `real` field.
+    pub real: bool,
+    pub entries: Vec<Entry>,
+    // The line giving the dimensions, for reporting dimensions that are too
+    // large.
+    pub line: usize,
+}
+
+/// The largest number of entries, `nrows * ncols`, of a dense matrix read
+/// by `read_format`. A Matrix Market coordinate header can declare a huge
+/// matrix in a few bytes, so larger dimensions are reported as an error
+/// rather than allocated.
+pub const MAX_DENSE_ENTRIES: usize = 1 << 28;
+
+impl ParsedMatrix {
+    // The dimensions as signed longs, checking that a dense matrix of this
+    // size is at most `MAX_DENSE_ENTRIES`.
+    pub fn dense_dims(&self) -> Result<(i64, i64)> {
+        let err = || line_error(self.line, "Matrix dimensions are too large.");
+        match self.nrows.checked_mul(self.ncols) {
+            Some(len) if len <= MAX_DENSE_ENTRIES => {}
+            _ => return Err(err()),
+        }
+        let nrows: i64 = self.nrows.try_into().map_err(|_| err())?;
+        let ncols: i64 = self.ncols.try_into().map_err(|_| err())?;
+        Ok((nrows, ncols))
+    }
+}
+
+pub(crate) fn line_error<E: std::fmt::Display>(line: usize, e: E) -> Error {
+    Error::Msg(format!("Line {}: {}", line, e))
+}
+
+fn io_error(e: io::Error) -> Error {
+    Error::Msg(e.to_string())
+}
+
+pub(crate) fn read_matrix<R: Read>(r: R, fmt: MatrixFormat) -> Result<ParsedMatrix> {
+    let mut r = BufReader::new(r);
+    match fmt {
+        MatrixFormat::MatrixMarketCoordinate | MatrixFormat::MatrixMarketArray => {
+            read_matrix_market(r)
+        }
+        MatrixFormat::Text => read_text(r),
+        MatrixFormat::Pari | MatrixFormat::Sage | MatrixFormat::Magma => {
+            let mut s = String::new();
+            r.read_to_string(&mut s).map_err(io_error)?;
+            let mut toks = Tokens::new(&s);
+            let res = if fmt == MatrixFormat::Pari {
+                read_pari(&mut toks)?
+            } else {
+                read_constructor(&mut toks)?
+            };
+            toks.finish()?;
+            Ok(res)
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Symmetry {
+    General,
+    Symmetric,
+    SkewSymmetric,
+}
+
+fn read_matrix_market<R: BufRead>(r: R) -> Result<ParsedMatrix> {
+    let mut lines = r.lines().enumerate().map(|(k, l)| (k + 1, l));
+    let header = match lines.next() {
+        Some((_, l)) => l.map_err(io_error)?,
+        None => return Err(Error::Msg("Input is empty.".to_string())),
+    };
+
+    let words: Vec<String> = header.split_whitespace().map(|w| w.to_lowercase()).collect();
+    if words.len() != 5 || words[0] != "%%matrixmarket" || words[1] != "matrix" {
+        return Err(line_error(1, "Expected a \"%%MatrixMarket matrix\" header."));
+    }
+    let coordinate = match words[2].as_str() {
+        "coordinate" => true,
+        "array" => false,
+        other => return Err(line_error(1, format!("Unknown layout {}.", other))),
+    };
+    let (real, pattern) = match words[3].as_str() {
+        "integer" | "rational" => (false, false),
+        "real" => (true, false),
+        "pattern" if coordinate => (false, true),
+        other => return Err(line_error(1, format!("Unsupported field {}.", other))),
+    };
+    let symmetry = match words[4].as_str() {
+        "general" => Symmetry::General,
+        "symmetric" => Symmetry::Symmetric,
+        "skew-symmetric" => Symmetry::SkewSymmetric,
+        other => return Err(line_error(1, format!("Unsupported symmetry {}.", other))),
+    };
+
+    // The remaining lines, skipping comments and blank lines.
+    let mut data = lines.filter_map(|(n, l)| match l {
+        Ok(l) => {
+            let l = l.trim();
+            if l.is_empty() || l.starts_with('%') {
+                None
+            } else {
+                Some(Ok((n, l.to_string())))
+            }
+        }
+        Err(e) => Some(Err(io_error(e))),
+    });
+
+    let (n, size) = data.next()
+        .ok_or_else(|| Error::Msg("Missing the size line.".to_string()))??;
+    let dims = size.split_whitespace()
+        .map(|w| w.parse::<usize>().map_err(|_| line_error(n, "Expected a nonnegative integer.")))
+        .collect::<Result<Vec<_>>>()?;
+    let expected = if coordinate { 3 } else { 2 };
+    if dims.len() != expected {
+        return Err(line_error(n, format!("Expected {} sizes, found {}.", expected, dims.len())));
+    }
+    let (nrows, ncols) = (dims[0], dims[1]);
+    if symmetry != Symmetry::General && nrows != ncols {
+        return Err(line_error(n, "A symmetric matrix must be square."));
+    }
+
+    let mut entries = vec![];
+    let mut push = |i: usize, j: usize, val: String, line: usize| {
+        if i != j && symmetry != Symmetry::General {
+            entries.push(Entry {
+                i: j,
+                j: i,
+                val: val.clone(),
+                neg: symmetry == Symmetry::SkewSymmetric,
+                line
+            });
+        }
+        entries.push(Entry { i, j, val, neg: false, line });
+    };
+
+    // The last line read, for reporting data that ends too early.
+    let mut last = n;
+    if coordinate {
+        let nnz = dims[2];
+        for k in 0..nnz {
+            let (n, l) = data.next().ok_or_else(|| {
+                line_error(last, format!("Expected {} entries, found {}.", nnz, k))
+            })??;
+            last = n;
+            let mut fields = l.split_whitespace();
+            let i = parse_index(fields.next(), nrows, n)?;
+            let j = parse_index(fields.next(), ncols, n)?;
+            let val = if pattern {
+                "1".to_string()
+            } else {
+                fields.next().ok_or_else(|| line_error(n, "Missing the entry."))?.to_string()
+            };
+            if fields.next().is_some() {
+                return Err(line_error(n, "Too many fields."));
+            }
+            push(i, j, val, n);
+        }
+    } else {
+        // Symmetric matrices list only the lower triangle.
+        for j in 0..ncols {
+            let start = match symmetry {
+                Symmetry::General => 0,
+                Symmetry::Symmetric => j,
+                Symmetry::SkewSymmetric => j + 1,
+            };
+            for i in start..nrows {
+                let (n, l) = data.next()
+                    .ok_or_else(|| line_error(last, "Too few entries."))??;
+                last = n;
+                let mut fields = l.split_whitespace();
+                let val = fields.next().unwrap_or_default().to_string();
+                if fields.next().is_some() {
+                    return Err(line_error(n, "Expected one entry per line."));
+                }
+                push(i, j, val, n);
+            }
+        }
+    }
+
+    if let Some(next) = data.next() {
+        let (n, _) = next?;
+        return Err(line_error(n, "Unexpected data after the last entry."));
+    }
+    Ok(ParsedMatrix { nrows, ncols, real, entries, line: n })
+}
+
+// A one-based index at most `bound`, returned zero-based.
+fn parse_index(field: Option<&str>, bound: usize, line: usize) -> Result<usize> {
+    match field.and_then(|f| f.parse::<usize>().ok()) {
+        Some(k) if (1..=bound).contains(&k) => Ok(k - 1),
+        Some(_) => Err(line_error(line, "Index out of range.")),
+        None => Err(line_error(line, "Expected an index.")),
+    }
+}
+
+fn read_text<R: BufRead>(r: R) -> Result<ParsedMatrix> {
+    let mut entries = vec![];
+    let mut nrows = 0;
+    let mut ncols = None;
+    let mut first = 1;
+    for (k, l) in r.lines().enumerate() {
+        let n = k + 1;
+        let l = l.map_err(io_error)?;
+        let row: Vec<&str> = l.split_whitespace().collect();
+        if row.is_empty() {
+            continue;
+        }
+        match ncols {
+            None => {
+                ncols = Some(row.len());
+                first = n;
+            }
+            Some(c) if c != row.len() => {
+                return Err(line_error(n, format!("Expected {} entries, found {}.", c, row.len())));
+            }
+            Some(_) => {}
+        }
+        for (j, val) in row.into_iter().enumerate() {
+            entries.push(Entry { i: nrows, j, val: val.to_string(), neg: false, line: n });
+        }
+        nrows += 1;
+    }
+    Ok(ParsedMatrix { nrows, ncols: ncols.unwrap_or(0), real: false, entries, line: first })
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Tok {
+    Open(char),
+    Close(char),
+    Comma,
+    Semi,
+    Atom(String),
+}
+
+// The tokens of a matrix literal with the lines they appear on.
+struct Tokens {
+    toks: Vec<(Tok, usize)>,
+    pos: usize,
+    last_line: usize,
+}
+
+impl Tokens {
+    fn new(s: &str) -> Self {
+        let mut toks = vec![];
+        let mut line = 1;
+        let mut atom = String::new();
+        for c in s.chars() {
+            let tok = match c {
+                '[' | '(' => Some(Tok::Open(c)),
+                ']' | ')' => Some(Tok::Close(c)),
+                ',' => Some(Tok::Comma),
+                ';' => Some(Tok::Semi),
+                _ if c.is_whitespace() => None,
+                _ => {
+                    atom.push(c);
+                    continue;
+                }
+            };
+            if !atom.is_empty() {
+                toks.push((Tok::Atom(std::mem::take(&mut atom)), line));
+            }
+            if let Some(tok) = tok {
+                toks.push((tok, line));
+            }
+            if c == '\n' {
+                line += 1;
+            }
+        }
+        if !atom.is_empty() {
+            toks.push((Tok::Atom(atom), line));
+        }
+        Tokens { toks, pos: 0, last_line: line }
+    }
+
+    fn peek(&self) -> Option<&Tok> {
+        self.toks.get(self.pos).map(|(t, _)| t)
+    }
+
+    fn line(&self) -> usize {
+        self.toks.get(self.pos).map_or(self.last_line, |(_, n)| *n)
+    }
+
+    fn error(&self, msg: &str) -> Error {
+        line_error(self.line(), msg)
+    }
+
+    fn bump(&mut self) -> Option<(Tok, usize)> {
+        let res = self.toks.get(self.pos).cloned();
+        self.pos += 1;
+        res
+    }
+
+    fn eat(&mut self, tok: &Tok) -> bool {
+        if self.peek() == Some(tok) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, tok: &Tok, msg: &str) -> Result<()> {
+        if self.eat(tok) { Ok(()) } else { Err(self.error(msg)) }
+    }
+
+    fn atom(&mut self) -> Result<(String, usize)> {
+        match self.peek() {
+            Some(Tok::Atom(_)) => match self.bump() {
+                Some((Tok::Atom(a), n)) => Ok((a, n)),
+                _ => unreachable!(),
+            },
+            _ => Err(self.error("Expected an entry.")),
+        }
+    }
+
+    fn size(&mut self) -> Result<usize> {
+        let (a, n) = self.atom()?;
+        a.parse().map_err(|_| line_error(n, "Expected a nonnegative integer."))
+    }
+
+    // Skip a balanced group of brackets, such as the `()` of `Integers()`.
+    fn skip_group(&mut self) -> Result<()> {
+        let mut depth = 0;
+        loop {
+            match self.bump() {
+                Some((Tok::Open(_), _)) => depth += 1,
+                Some((Tok::Close(_), _)) => depth -= 1,
+                Some(_) => {}
+                None => return Err(self.error("Unbalanced brackets.")),
+            }
+            if depth == 0 {
+                return Ok(());
+            }
+        }
+    }
+
+    // Allow a trailing semicolon, and nothing else.
+    fn finish(&mut self) -> Result<()> {
+        self.eat(&Tok::Semi);
+        if self.peek().is_some() {
+            Err(self.error("Unexpected input after the matrix."))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+// Assemble rows of entries into a matrix, checking they have equal length.
+fn from_rows(rows: Vec<Vec<(String, usize)>>, line: usize) -> Result<ParsedMatrix> {
+    let nrows = rows.len();
+    let ncols = rows.first().map_or(0, |row| row.len());
+    let mut entries = vec![];
+    for (i, row) in rows.into_iter().enumerate() {
+        if row.len() != ncols {
+            let line = row.first().map_or(0, |(_, n)| *n);
+            return Err(line_error(line, format!("Expected {} entries, found {}.", ncols, row.len())));
+        }
+        for (j, (val, line)) in row.into_iter().enumerate() {
+            entries.push(Entry { i, j, val, neg: false, line });
+        }
+    }
+    Ok(ParsedMatrix { nrows, ncols, real: false, entries, line })
+}
+
+fn read_pari(toks: &mut Tokens) -> Result<ParsedMatrix> {
+    let line = toks.line();
+    if toks.eat(&Tok::Atom("matrix".to_string())) {
+        toks.expect(&Tok::Open('('), "Expected \"(\".")?;
+        let nrows = toks.size()?;
+        toks.expect(&Tok::Comma, "Expected \",\".")?;
+        let ncols = toks.size()?;
+        toks.expect(&Tok::Close(')'), "Expected \")\".")?;
+        return Ok(ParsedMatrix { nrows, ncols, real: false, entries: vec![], line });
+    }
+
+    toks.expect(&Tok::Open('['), "Expected \"[\".")?;
+    if toks.eat(&Tok::Close(']')) {
+        return from_rows(vec![], line);
+    }
+    if toks.eat(&Tok::Semi) {
+        toks.expect(&Tok::Close(']'), "Expected \"]\".")?;
+        return from_rows(vec![], line);
+    }
+
+    let mut rows = vec![vec![]];
+    loop {
+        rows.last_mut().unwrap().push(toks.atom()?);
+        match toks.bump() {
+            Some((Tok::Comma, _)) => {}
+            Some((Tok::Semi, _)) => rows.push(vec![]),
+            Some((Tok::Close(']'), _)) => break,
+            _ => return Err(toks.error("Expected \",\", \";\" or \"]\".")),
+        }
+    }
+    from_rows(rows, line)
+}
+
+// A bracketed, comma-separated list of entries or of such lists.
+enum List {
+    Flat(Vec<(String, usize)>),
+    Nested(Vec<Vec<(String, usize)>>),
+}
+
+fn read_list(toks: &mut Tokens) -> Result<List> {
+    toks.expect(&Tok::Open('['), "Expected \"[\".")?;
+    if toks.eat(&Tok::Close(']')) {
+        return Ok(List::Flat(vec![]));
+    }
+    let nested = toks.peek() == Some(&Tok::Open('['));
+    let mut flat = vec![];
+    let mut rows = vec![];
+    loop {
+        if nested {
+            match read_list(toks)? {
+                List::Flat(row) => rows.push(row),
+                List::Nested(_) => return Err(toks.error("Lists are nested too deeply.")),
+            }
+        } else {
+            flat.push(toks.atom()?);
+        }
+        match toks.bump() {
+            Some((Tok::Comma, _)) => {}
+            Some((Tok::Close(']'), _)) => break,
+            _ => return Err(toks.error("Expected \",\" or \"]\".")),
+        }
+    }
+    Ok(if nested { List::Nested(rows) } else { List::Flat(flat) })
+}
+
+// The Sage and Magma constructors, `matrix(R, m, n, [...])` with the ring
+// and dimensions optional.
+fn read_constructor(toks: &mut Tokens) -> Result<ParsedMatrix> {
+    let start = toks.line();
+    match toks.bump() {
+        Some((Tok::Atom(a), _)) if a.eq_ignore_ascii_case("matrix") => {}
+        _ => return Err(line_error(start, "Expected a matrix constructor.")),
+    }
+    toks.expect(&Tok::Open('('), "Expected \"(\".")?;
+
+    // The ring, which is left to the caller, possibly with arguments.
+    if let Some(Tok::Atom(a)) = toks.peek() {
+        if a.parse::<usize>().is_err() {
+            toks.bump();
+            if toks.peek() == Some(&Tok::Open('(')) {
+                toks.skip_group()?;
+            }
+            toks.expect(&Tok::Comma, "Expected \",\".")?;
+        }
+    }
+
+    let mut dims = None;
+    if let Some(Tok::Atom(_)) = toks.peek() {
+        let nrows = toks.size()?;
+        toks.expect(&Tok::Comma, "Expected \",\".")?;
+        let ncols = toks.size()?;
+        dims = Some((nrows, ncols));
+        if !toks.eat(&Tok::Comma) {
+            toks.expect(&Tok::Close(')'), "Expected \")\".")?;
+            return Ok(ParsedMatrix { nrows, ncols, real: false, entries: vec![], line: start });
+        }
+    }
+
+    let line = toks.line();
+    let list = read_list(toks)?;
+    toks.expect(&Tok::Close(')'), "Expected \")\".")?;
+
+    let mut res = match (list, dims) {
+        (List::Nested(rows), _) => from_rows(rows, start)?,
+        (List::Flat(vals), Some((nrows, ncols))) => {
+            let expected = nrows.checked_mul(ncols)
+                .ok_or_else(|| line_error(start, "Matrix dimensions are too large."))?;
+            if vals.len() != expected {
+                return Err(line_error(line, format!(
+                    "Expected {} entries, found {}.", expected, vals.len()
+                )));
+            }
+            let entries = vals.into_iter()
+                .enumerate()
+                .map(|(k, (val, line))| Entry { i: k / ncols, j: k % ncols, val, neg: false, line })
+                .collect();
+            ParsedMatrix { nrows, ncols, real: false, entries, line: start }
+        }
+        // Without dimensions a flat list is a single row.
+        (List::Flat(vals), None) if vals.is_empty() => from_rows(vec![], start)?,
+        (List::Flat(vals), None) => from_rows(vec![vals], start)?,
+    };
+
+    if let Some((nrows, ncols)) = dims {
+        if res.entries.is_empty() {
+            res.nrows = nrows;
+            res.ncols = ncols;
+        } else if (res.nrows, res.ncols) != (nrows, ncols) {
+            return Err(line_error(line, format!(
+                "Expected a {} by {} matrix, found {} by {}.", nrows, ncols, res.nrows, res.ncols
+            )));
+        }
+    }
+    Ok(res)
+}
+
+// Parse a decimal number such as `-1.25e-3` exactly.
+pub(crate) fn parse_decimal(s: &str) -> Result<Rational> {
+    let err = || Error::Msg(format!("Cannot parse {} as a decimal number.", s));
+    let (mantissa, exp) = match s.find(['e', 'E']) {
+        Some(k) => (&s[..k], s[k + 1..].parse::<i64>().map_err(|_| err())?),
+        None => (s, 0),
+    };
+    let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let unsigned = int.strip_prefix(['+', '-']).unwrap_or(int);
+    if !(unsigned.chars().all(|c| c.is_ascii_digit()) && frac.chars().all(|c| c.is_ascii_digit()))
+        || unsigned.len() + frac.len() == 0
+    {
+        return Err(err());
+    }
+    let digits = if int.is_empty() || int == "-" || int == "+" {
+        format!("{}0{}", int, frac)
+    } else {
+        format!("{}{}", int, frac)
+    };
+    let num = Integer::from_str(&digits).map_err(|_| err())?;
+
+    let exp = exp - frac.len() as i64;
+    let scale = Integer::from(10).pow(exp.unsigned_abs());
+    if exp >= 0 {
+        Ok(Rational::from(num * scale))
+    } else {
+        Ok(Rational::from([num, scale]))
+    }
+}
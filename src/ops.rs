@@ -236,6 +236,16 @@ pub trait AssignRem<Lhs = Self, Rhs = Self> {
     fn assign_rem(&mut self, lhs: Lhs, rhs: Rhs);
 }
 
+/// Left shift with assignment into a separate argument.
+pub trait AssignShl<Lhs = Self, Rhs = Self> {
+    fn assign_shl(&mut self, lhs: Lhs, rhs: Rhs);
+}
+
+/// Right shift with assignment into a separate argument.
+pub trait AssignShr<Lhs = Self, Rhs = Self> {
+    fn assign_shr(&mut self, lhs: Lhs, rhs: Rhs);
+}
+
 /// Exponentiation.
 pub trait Pow<Rhs = Self> {
     type Output;
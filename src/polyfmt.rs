@@ -0,0 +1,262 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Configurable printing and parsing of univariate polynomials.
+
+use crate::{Error, Result};
+
+/// Options for printing a polynomial with `to_str_format`. The default
+/// matches the `Display` implementations of the polynomial types.
+///
+/// Strings printed with a format whose `pow` is `^` or `**` can be read back
+/// with the `FromStr` implementations, which accept any variable name,
+/// either order of terms, optional `*` and either `^` or `**` for exponents.
+/// Whitespace may separate tokens but is not allowed inside a coefficient,
+/// variable name or exponent.
+///
+/// ```
+/// use rufl::intpoly::IntPoly;
+/// use rufl::polyfmt::PolyFormat;
+///
+/// let f = IntPoly::from([1, 0, -2, 3]);
+/// assert_eq!(f.to_string(), "3*x^3 - 2*x^2 + 1");
+///
+/// let fmt = PolyFormat {
+///     var: "t".to_string(),
+///     ascending: true,
+///     explicit_mul: false,
+///     pow: "**".to_string(),
+///     skip_zero: false,
+/// };
+/// let s = f.to_str_format(&fmt);
+/// assert_eq!(s, "1 + 0t - 2t**2 + 3t**3");
+/// assert_eq!(s.parse::<IntPoly>().unwrap(), f);
+///
+/// assert!("1 2x".parse::<IntPoly>().is_err());
+/// assert!("x y".parse::<IntPoly>().is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PolyFormat {
+    /// The name of the variable, `x` by default.
+    pub var: String,
+    /// Print terms from the constant term up rather than from the leading
+    /// term down.
+    pub ascending: bool,
+    /// Separate coefficients from the variable with `*`, as in `3*x` rather
+    /// than `3x`.
+    pub explicit_mul: bool,
+    /// The exponent operator, `^` by default. Only `^` and `**` can be
+    /// parsed back.
+    pub pow: String,
+    /// Omit terms with a zero coefficient.
+    pub skip_zero: bool,
+}
+
+impl Default for PolyFormat {
+    fn default() -> Self {
+        PolyFormat {
+            var: "x".to_string(),
+            ascending: false,
+            explicit_mul: true,
+            pow: "^".to_string(),
+            skip_zero: true,
+        }
+    }
+}
+
+impl PolyFormat {
+    // Print a polynomial given the sign and the printed absolute value of
+    // each coefficient, constant term first.
+    pub(crate) fn format_terms(&self, coeffs: &[(i32, String)]) -> String {
+        let mut order: Vec<usize> = (0..coeffs.len()).collect();
+        if !self.ascending {
+            order.reverse();
+        }
+
+        let mut out = String::new();
+        for k in order {
+            let (s, abs) = &coeffs[k];
+            if *s == 0 && self.skip_zero {
+                continue;
+            }
+
+            if out.is_empty() {
+                if *s < 0 {
+                    out.push('-');
+                }
+            } else if *s < 0 {
+                out.push_str(" - ");
+            } else {
+                out.push_str(" + ");
+            }
+
+            if k == 0 {
+                out.push_str(abs);
+                continue;
+            }
+            if abs != "1" {
+                out.push_str(abs);
+                if self.explicit_mul {
+                    out.push('*');
+                }
+            }
+            out.push_str(&self.var);
+            if k > 1 {
+                out.push_str(&self.pow);
+                out.push_str(&k.to_string());
+            }
+        }
+
+        if out.is_empty() {
+            out.push('0');
+        }
+        out
+    }
+}
+
+// A term `c*x^k` of a parsed polynomial, with the coefficient left to be
+// parsed by the caller. A missing coefficient stands for one.
+pub(crate) struct Term {
+    pub neg: bool,
+    pub coeff: Option<String>,
+    pub exp: usize,
+}
+
+fn not_a_poly() -> Error {
+    Error::Msg("Input is not a polynomial.".to_string())
+}
+
+// A token of a polynomial string. Whitespace only separates tokens.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token {
+    // `+` or `-`, with `true` for `-`.
+    Sign(bool),
+    // A run of digits, underscores and `/`, possibly with a sign after the
+    // `/`, left to be parsed by the caller.
+    Num(String),
+    // An identifier.
+    Var(String),
+    Mul,
+    // `^` or `**`.
+    Pow,
+}
+
+fn tokenize(s: &str) -> Result<Vec<Token>> {
+    let mut tokens = vec![];
+    let mut chars = s.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c == '+' || c == '-' {
+            chars.next();
+            tokens.push(Token::Sign(c == '-'));
+        } else if c == '^' {
+            chars.next();
+            tokens.push(Token::Pow);
+        } else if c == '*' {
+            chars.next();
+            if chars.next_if_eq(&'*').is_some() {
+                tokens.push(Token::Pow);
+            } else {
+                tokens.push(Token::Mul);
+            }
+        } else if c.is_ascii_digit() {
+            let mut num = String::new();
+            while let Some(c) = chars.next_if(|&c| c.is_ascii_digit() || c == '_' || c == '/') {
+                num.push(c);
+                if c == '/' {
+                    if let Some(sign) = chars.next_if(|&c| c == '+' || c == '-') {
+                        num.push(sign);
+                    }
+                }
+            }
+            tokens.push(Token::Num(num));
+        } else if c.is_alphabetic() || c == '_' {
+            let mut name = String::new();
+            while let Some(c) = chars.next_if(|&c| c.is_alphanumeric() || c == '_') {
+                name.push(c);
+            }
+            tokens.push(Token::Var(name));
+        } else {
+            return Err(not_a_poly());
+        }
+    }
+    Ok(tokens)
+}
+
+// Split `s` into signed terms of the form `c`, `c*x^k`, `c x^k` or `x^k`,
+// where `^` may also be written `**` and `c` contains no letters. The
+// variable must be `var` if given, otherwise any identifier used
+// consistently. Terms after the first must start with a sign.
+pub(crate) fn parse_terms(s: &str, var: Option<&str>) -> Result<Vec<Term>> {
+    let tokens = tokenize(s)?;
+    if tokens.is_empty() {
+        return Err(not_a_poly());
+    }
+
+    let mut seen: Option<String> = var.map(str::to_string);
+    let mut terms = vec![];
+    let mut toks = tokens.into_iter().peekable();
+    while toks.peek().is_some() {
+        let neg = match toks.next_if(|t| matches!(t, Token::Sign(_))) {
+            Some(Token::Sign(neg)) => neg,
+            _ if terms.is_empty() => false,
+            _ => return Err(not_a_poly()),
+        };
+
+        let coeff = match toks.next_if(|t| matches!(t, Token::Num(_))) {
+            Some(Token::Num(c)) => Some(c),
+            _ => None,
+        };
+        let mul = toks.next_if_eq(&Token::Mul).is_some();
+        let name = match toks.next_if(|t| matches!(t, Token::Var(_))) {
+            Some(Token::Var(name)) => name,
+            _ if coeff.is_some() && !mul => {
+                terms.push(Term { neg, coeff, exp: 0 });
+                continue;
+            }
+            _ => return Err(not_a_poly()),
+        };
+        if mul && coeff.is_none() {
+            return Err(not_a_poly());
+        }
+
+        match &seen {
+            Some(v) if *v != name => {
+                return Err(Error::Msg(format!(
+                    "Unexpected variable {} in a polynomial in {}.",
+                    name, v
+                )));
+            }
+            Some(_) => {}
+            None => seen = Some(name),
+        }
+
+        let exp = if toks.next_if_eq(&Token::Pow).is_some() {
+            match toks.next() {
+                Some(Token::Num(k)) if k.chars().all(|c| c.is_ascii_digit()) => {
+                    k.parse().map_err(|_| not_a_poly())?
+                }
+                _ => return Err(not_a_poly()),
+            }
+        } else {
+            1
+        };
+        terms.push(Term { neg, coeff, exp });
+    }
+    Ok(terms)
+}
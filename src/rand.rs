@@ -0,0 +1,470 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Seeded random generation of rufl types.
+//!
+//! All randomness is drawn from a [RandState] wrapping a
+//! [FLINT random state][flint::flint_rand_s]. Two states created with the
+//! same seed produce the same sequence of values, so randomized tests are
+//! reproducible.
+
+use crate::*;
+use flint_sys::{
+    flint,
+    fmpz,
+    fmpz_poly,
+    fmpq_poly,
+    fmpz_mod_poly,
+    fmpz_mat,
+    ulong_extras
+};
+use std::fmt;
+use std::mem::MaybeUninit;
+
+/// A deterministic source of random rufl values.
+///
+/// ```
+/// use rufl::rand::RandState;
+///
+/// let mut a = RandState::with_seed(42);
+/// let mut b = RandState::with_seed(42);
+/// assert_eq!(a.integer_bits(200), b.integer_bits(200));
+/// ```
+pub struct RandState {
+    inner: flint::flint_rand_s,
+}
+
+impl fmt::Debug for RandState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RandState").finish_non_exhaustive()
+    }
+}
+
+impl Default for RandState {
+    #[inline]
+    fn default() -> Self {
+        let mut state = MaybeUninit::uninit();
+        unsafe {
+            flint::flint_randinit(state.as_mut_ptr());
+            RandState { inner: state.assume_init() }
+        }
+    }
+}
+
+impl Drop for RandState {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { flint::flint_randclear(self.as_mut_ptr()) }
+    }
+}
+
+impl RandState {
+    /// Returns a pointer to the inner [FLINT random state][flint::flint_rand_s].
+    #[inline]
+    pub const fn as_ptr(&self) -> *const flint::flint_rand_s {
+        &self.inner
+    }
+
+    /// Returns a mutable pointer to the inner
+    /// [FLINT random state][flint::flint_rand_s].
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut flint::flint_rand_s {
+        &mut self.inner
+    }
+}
+
+impl RandState {
+    /// Initialize a random state with FLINT's fixed default seed.
+    #[inline]
+    pub fn new() -> Self {
+        RandState::default()
+    }
+
+    /// Initialize a random state from a seed.
+    ///
+    /// ```
+    /// use rufl::rand::RandState;
+    ///
+    /// let mut a = RandState::with_seed(1);
+    /// let mut b = RandState::with_seed(2);
+    /// assert_ne!(a.integer_bits(128), b.integer_bits(128));
+    /// ```
+    #[inline]
+    pub fn with_seed(seed: u64) -> Self {
+        let mut res = RandState::default();
+        res.seed(seed);
+        res
+    }
+
+    /// Reseed the random state. Both of FLINT's internal seeds are derived
+    /// from `seed`.
+    #[inline]
+    pub fn seed(&mut self, seed: u64) {
+        self.seed2(seed, seed ^ 0x9e3779b97f4a7c15);
+    }
+
+    /// Reseed the random state with two explicit seeds.
+    #[inline]
+    pub fn seed2(&mut self, seed1: u64, seed2: u64) {
+        unsafe { flint::flint_randseed(self.as_mut_ptr(), seed1, seed2) }
+    }
+
+    /// Return a random word.
+    #[inline]
+    pub fn next_u64(&mut self) -> u64 {
+        unsafe { ulong_extras::n_randlimb(self.as_mut_ptr()) }
+    }
+
+    // Integer //
+
+    /// Return a random nonnegative `Integer` with exactly `bits` bits.
+    ///
+    /// ```
+    /// use rufl::rand::RandState;
+    ///
+    /// let mut rand = RandState::with_seed(7);
+    /// let x = rand.integer_bits(100);
+    /// assert_eq!(x.bits(), 100);
+    /// assert!(x > 0);
+    /// ```
+    pub fn integer_bits(&mut self, bits: u64) -> Integer {
+        let mut res = Integer::default();
+        unsafe {
+            fmpz::fmpz_randbits(res.as_mut_ptr(), self.as_mut_ptr(), bits);
+        }
+        res.abs_mut();
+        res
+    }
+
+    /// Return a uniformly random `Integer` in the range `[0, n)`. Panics if
+    /// `n` is not positive.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    /// use rufl::rand::RandState;
+    ///
+    /// let mut rand = RandState::with_seed(7);
+    /// let n = Integer::from(1000);
+    /// for _ in 0..100 {
+    ///     let x = rand.integer_below(&n);
+    ///     assert!(x >= 0 && x < n);
+    /// }
+    /// ```
+    pub fn integer_below<T: AsRef<Integer>>(&mut self, n: T) -> Integer {
+        let n = n.as_ref();
+        assert!(n.sign() > 0);
+        let mut res = Integer::default();
+        unsafe {
+            fmpz::fmpz_randm(res.as_mut_ptr(), self.as_mut_ptr(), n.as_ptr());
+        }
+        res
+    }
+
+    /// Return a uniformly random `Integer` in the range `[a, b)`. Panics if
+    /// the range is empty.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    /// use rufl::rand::RandState;
+    ///
+    /// let mut rand = RandState::with_seed(7);
+    /// let (a, b) = (Integer::from(-10), Integer::from(10));
+    /// for _ in 0..100 {
+    ///     let x = rand.integer_range(&a, &b);
+    ///     assert!(x >= a && x < b);
+    /// }
+    /// ```
+    pub fn integer_range<T: AsRef<Integer>>(&mut self, a: T, b: T) -> Integer {
+        let a = a.as_ref();
+        let b = b.as_ref();
+        assert!(a < b);
+        self.integer_below(b - a) + a
+    }
+
+    /// Return a random prime with exactly `bits` bits. If `proved` is true
+    /// the primality of the output is certified, otherwise it is only a
+    /// probable prime. Panics if `bits < 2`.
+    ///
+    /// ```
+    /// use rufl::rand::RandState;
+    ///
+    /// let mut rand = RandState::with_seed(7);
+    /// let p = rand.prime(64, true);
+    /// assert_eq!(p.bits(), 64);
+    /// ```
+    pub fn prime(&mut self, bits: u64, proved: bool) -> Integer {
+        assert!(bits >= 2);
+        let mut res = Integer::default();
+        unsafe {
+            fmpz::fmpz_randprime(
+                res.as_mut_ptr(),
+                self.as_mut_ptr(),
+                bits,
+                proved as i32
+            );
+        }
+        res
+    }
+
+    // IntMod //
+
+    /// Return a uniformly random element of the ring of integers modulo `n`
+    /// described by `ctx`.
+    ///
+    /// ```
+    /// use rufl::intmod::IntModCtx;
+    /// use rufl::rand::RandState;
+    ///
+    /// let ctx = IntModCtx::new(101);
+    /// let mut rand = RandState::with_seed(7);
+    /// let x = rand.intmod(&ctx);
+    /// assert_eq!(x.context(), &ctx);
+    /// ```
+    pub fn intmod(&mut self, ctx: &IntModCtx) -> IntMod {
+        let mut res = IntMod::zero(ctx);
+        unsafe {
+            fmpz::fmpz_randm(
+                res.as_mut_ptr(),
+                self.as_mut_ptr(),
+                ctx.modulus_as_ptr()
+            );
+        }
+        res
+    }
+
+    // Polynomials //
+
+    /// Return a random integer polynomial of degree exactly `degree` whose
+    /// coefficients have at most `bits` bits. Panics if `bits` is zero.
+    ///
+    /// ```
+    /// use rufl::rand::RandState;
+    ///
+    /// let mut rand = RandState::with_seed(7);
+    /// let f = rand.intpoly(10, 32);
+    /// assert_eq!(f.degree(), 10);
+    /// ```
+    pub fn intpoly(&mut self, degree: usize, bits: u64) -> IntPoly {
+        assert!(bits > 0);
+        let len = degree + 1;
+        let mut res = IntPoly::with_capacity(len);
+        unsafe {
+            fmpz_poly::fmpz_poly_randtest(
+                res.as_mut_ptr(),
+                self.as_mut_ptr(),
+                len.try_into().expect("Cannot convert length to a signed long."),
+                bits
+            );
+        }
+        if res.get_coeff(degree).is_zero() {
+            let lead = self.nonzero_integer_bits(bits);
+            res.set_coeff(degree, lead);
+        }
+        res
+    }
+
+    /// Return a random rational polynomial of degree exactly `degree` whose
+    /// numerator coefficients and denominator have at most `bits` bits. Panics
+    /// if `bits` is zero.
+    ///
+    /// ```
+    /// use rufl::rand::RandState;
+    ///
+    /// let mut rand = RandState::with_seed(7);
+    /// let f = rand.ratpoly(5, 16);
+    /// assert_eq!(f.degree(), 5);
+    /// ```
+    pub fn ratpoly(&mut self, degree: usize, bits: u64) -> RatPoly {
+        assert!(bits > 0);
+        let len = degree + 1;
+        let mut res = RatPoly::with_capacity(len);
+        unsafe {
+            fmpq_poly::fmpq_poly_randtest(
+                res.as_mut_ptr(),
+                self.as_mut_ptr(),
+                len.try_into().expect("Cannot convert length to a signed long."),
+                bits
+            );
+        }
+        if res.get_coeff(degree).is_zero() {
+            let lead = self.nonzero_integer_bits(bits);
+            res.set_coeff_int(degree, lead);
+        }
+        res
+    }
+
+    /// Return a random polynomial over the integers modulo `n` of degree
+    /// exactly `degree`. Panics if the modulus is one.
+    ///
+    /// ```
+    /// use rufl::intmod::IntModCtx;
+    /// use rufl::rand::RandState;
+    ///
+    /// let ctx = IntModCtx::new(7);
+    /// let mut rand = RandState::with_seed(7);
+    /// let f = rand.intmodpoly(20, &ctx);
+    /// assert_eq!(f.degree(), 20);
+    /// ```
+    pub fn intmodpoly(&mut self, degree: usize, ctx: &IntModCtx) -> IntModPoly {
+        assert!(!ctx.modulus().is_one());
+        let len = degree + 1;
+        let mut res = IntModPoly::with_capacity(len, ctx);
+        unsafe {
+            fmpz_mod_poly::fmpz_mod_poly_randtest(
+                res.as_mut_ptr(),
+                self.as_mut_ptr(),
+                len.try_into().expect("Cannot convert length to a signed long."),
+                ctx.as_ptr()
+            );
+        }
+        if res.get_coeff(degree).is_zero() {
+            let m = ctx.modulus() - 1u64;
+            let lead = self.integer_below(m) + 1u64;
+            res.set_coeff(degree, IntMod::new(lead, ctx));
+        }
+        res
+    }
+
+    // Matrices //
+
+    /// Return a random `nrows` by `ncols` integer matrix of rank `rank`.
+    /// The matrix starts with `rank` nonzero entries of at most `bits` bits
+    /// and is then mixed by random row and column operations, so its entries
+    /// are not bounded by `bits`. Panics if the rank exceeds either
+    /// dimension.
+    ///
+    /// ```
+    /// use rufl::rand::RandState;
+    ///
+    /// let mut rand = RandState::with_seed(7);
+    /// let m = rand.intmat_rank(6, 4, 3, 10);
+    /// assert_eq!(m.rank(), 3);
+    /// ```
+    pub fn intmat_rank(
+        &mut self,
+        nrows: i64,
+        ncols: i64,
+        rank: i64,
+        bits: u64
+    ) -> IntMat {
+        assert!(rank >= 0 && rank <= nrows && rank <= ncols);
+        let mut res = IntMat::zero(nrows, ncols);
+        unsafe {
+            fmpz_mat::fmpz_mat_randrank(
+                res.as_mut_ptr(),
+                self.as_mut_ptr(),
+                rank,
+                bits
+            );
+            fmpz_mat::fmpz_mat_randops(
+                res.as_mut_ptr(),
+                self.as_mut_ptr(),
+                (nrows + ncols) * (nrows + ncols)
+            );
+        }
+        res
+    }
+
+    /// Return a random `n` by `n` integer matrix with determinant `det`.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    /// use rufl::rand::RandState;
+    ///
+    /// let mut rand = RandState::with_seed(7);
+    /// let det = Integer::from(-360);
+    /// let m = rand.intmat_det(5, &det);
+    /// assert_eq!(m.det(), det);
+    /// ```
+    pub fn intmat_det<T: AsRef<Integer>>(&mut self, n: i64, det: T) -> IntMat {
+        let mut res = IntMat::zero(n, n);
+        unsafe {
+            fmpz_mat::fmpz_mat_randdet(
+                res.as_mut_ptr(),
+                self.as_mut_ptr(),
+                det.as_ref().as_ptr()
+            );
+            fmpz_mat::fmpz_mat_randops(
+                res.as_mut_ptr(),
+                self.as_mut_ptr(),
+                4 * n * n
+            );
+        }
+        res
+    }
+
+    /// Return a random `nrows` by `ncols` rational matrix of rank `rank` whose
+    /// numerators and common denominator have at most `bits` bits.
+    ///
+    /// ```
+    /// use rufl::rand::RandState;
+    ///
+    /// let mut rand = RandState::with_seed(7);
+    /// let m = rand.ratmat_rank(3, 5, 2, 8);
+    /// assert_eq!(m.nrows(), 3);
+    /// assert_eq!(m.ncols(), 5);
+    /// ```
+    pub fn ratmat_rank(
+        &mut self,
+        nrows: i64,
+        ncols: i64,
+        rank: i64,
+        bits: u64
+    ) -> RatMat {
+        let num = self.intmat_rank(nrows, ncols, rank, bits);
+        let den = self.nonzero_integer_bits(bits).abs();
+        RatMat::from(num) / den
+    }
+
+    /// Return a random `n` by `n` rational matrix with determinant `det`.
+    /// Panics if `n` is zero and `det` is not one.
+    ///
+    /// ```
+    /// use rufl::rational::Rational;
+    /// use rufl::rand::RandState;
+    ///
+    /// let mut rand = RandState::with_seed(7);
+    /// let det = Rational::from([-7, 12]);
+    /// let m = rand.ratmat_det(4, &det);
+    /// assert!(m.is_square());
+    /// assert_eq!(m.nrows(), 4);
+    /// ```
+    pub fn ratmat_det<T: AsRef<Rational>>(&mut self, n: i64, det: T) -> RatMat {
+        let det = det.as_ref();
+        if n == 0 {
+            assert!(det.is_one());
+            return RatMat::zero(0, 0);
+        }
+
+        // If every entry of A is divided by q then det(A/q) = det(A)/q^n, so
+        // we need an integer matrix with determinant p*q^(n-1).
+        let p = det.numerator();
+        let q = det.denominator();
+        let e: u64 = (n - 1).try_into().expect("Dimension must be nonnegative.");
+        let num = self.intmat_det(n, p * q.clone().pow(e));
+        RatMat::from(num) / q
+    }
+
+    // Return a random nonzero integer with exactly `bits` bits and random sign.
+    fn nonzero_integer_bits(&mut self, bits: u64) -> Integer {
+        let mut res = Integer::default();
+        unsafe {
+            fmpz::fmpz_randbits(res.as_mut_ptr(), self.as_mut_ptr(), bits);
+        }
+        res
+    }
+}
@@ -18,18 +18,57 @@
 mod ops;
 mod conv;
 mod extras;
+mod arith;
+mod contfrac;
+mod farey;
+mod borrowed;
+
+#[cfg(feature = "num-traits")]
+mod num;
 
 //#[cfg(feature = "serde")]
 //mod serde;
 
+pub use borrowed::RationalRef;
+pub use contfrac::Convergents;
+pub use farey::{BoundedHeight, CalkinWilf, FareySequence, SternBrocot};
+
 use crate::Integer;
+use crate::integer::{
+    Round,
+    round_float,
+    compose_f64,
+    compose_f32,
+    F64_PREC,
+    F64_EMIN,
+    F32_PREC,
+    F32_EMIN
+};
+use crate::ops::NegAssign;
 use flint_sys::{flint, fmpz, fmpq};
 use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::mem::{ManuallyDrop, MaybeUninit};
 
 
+/// An arbitrary precision rational number.
+///
+/// The remainder `a % b` by an [Integer] is the reduction of `a` modulo
+/// `b`, an `Integer`. There is no `%` between two `Rational`s; the floored
+/// remainder `a - b*floor(a/b)` is [Rational::rem_floor].
+///
+/// ```
+/// use rufl::integer::Integer;
+/// use rufl::rational::Rational;
+///
+/// let a = Rational::from([7, 2]);
+/// assert_eq!(a.rem_floor(Rational::from([3, 2])), Rational::from([1, 2]));
+/// assert_eq!(a % Integer::from(5), 1);
+/// ```
+// Transparent so that an `fmpq` stored elsewhere can be borrowed as a
+// `Rational`, see `RationalRef`.
 #[derive(Debug)]
+#[repr(transparent)]
 pub struct Rational {
     inner: flint::fmpq,
 }
@@ -63,16 +102,50 @@ impl Default for Rational {
     }
 }
 
+/// Formatting respects the width, fill, alignment, sign and zero flags, and
+/// the radix formats print the numerator and denominator in that base.
+///
+/// ```
+/// use rufl::rational::Rational;
+///
+/// let q = Rational::from([-3, 4]);
+/// assert_eq!(format!("{}", q), "-3/4");
+/// assert_eq!(format!("{:>6}", q), "  -3/4");
+/// assert_eq!(format!("{:+}", Rational::from(2)), "+2");
+/// assert_eq!(format!("{:#x}", Rational::from([255, 16])), "0xff/10");
+/// ```
 impl fmt::Display for Rational {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let n = self.numerator();
-        let d = self.denominator();
-        if d == 1 {
-            write!(f, "{}", n)
-        } else {
-            write!(f, "{}/{}", n, d)
-        }
+        self.fmt_radix(f, 10, "", false)
+    }
+}
+
+impl fmt::LowerHex for Rational {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_radix(f, 16, "0x", false)
+    }
+}
+
+impl fmt::UpperHex for Rational {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_radix(f, 16, "0x", true)
+    }
+}
+
+impl fmt::Octal for Rational {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_radix(f, 8, "0o", false)
+    }
+}
+
+impl fmt::Binary for Rational {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_radix(f, 2, "0b", false)
     }
 }
 
@@ -250,28 +323,85 @@ impl Rational {
         res
     }
 
-    /*
+    /// Return the largest integer less than or equal to the rational number.
+    ///
+    /// ```
+    /// use rufl::rational::Rational;
+    ///
+    /// assert_eq!(Rational::from([-7, 2]).floor(), -4);
+    /// assert_eq!(Rational::from([7, 2]).floor(), 3);
+    /// ```
     #[inline]
     pub fn floor(&self) -> Integer {
-        let mut res = self.numerator();
-        res.fdiv_q_assign(self.denominator());
+        let mut res = Integer::default();
+        unsafe {
+            fmpz::fmpz_fdiv_q(res.as_mut_ptr(), &self.inner.num, &self.inner.den);
+        }
         res
     }
 
+    /// Return the smallest integer greater than or equal to the rational
+    /// number.
+    ///
+    /// ```
+    /// use rufl::rational::Rational;
+    ///
+    /// assert_eq!(Rational::from([-7, 2]).ceil(), -3);
+    /// assert_eq!(Rational::from([7, 2]).ceil(), 4);
+    /// ```
     #[inline]
     pub fn ceil(&self) -> Integer {
-        let mut res = self.numerator();
-        res.cdiv_q_assign(self.denominator());
+        let mut res = Integer::default();
+        unsafe {
+            fmpz::fmpz_cdiv_q(res.as_mut_ptr(), &self.inner.num, &self.inner.den);
+        }
         res
     }
-    
+
+    /// Return the floored remainder `self - other*floor(self/other)`, which
+    /// has the sign of `other`. Panics if `other` is zero.
+    ///
+    /// ```
+    /// use rufl::rational::Rational;
+    ///
+    /// let a = Rational::from([7, 2]);
+    /// assert_eq!(a.rem_floor(Rational::from([3, 2])), Rational::from([1, 2]));
+    /// assert_eq!(a.rem_floor(Rational::from([-3, 2])), -1);
+    /// assert_eq!((-a).rem_floor(Rational::from(2)), Rational::from([1, 2]));
+    /// ```
+    pub fn rem_floor<T: AsRef<Rational>>(&self, other: T) -> Rational {
+        let other = other.as_ref();
+        assert!(!other.is_zero(), "Division by zero.");
+        let q = (self / other).floor();
+        self - other * q
+    }
+
+    /// Return the nearest integer to the rational number, rounding half-way
+    /// cases away from zero.
+    ///
+    /// ```
+    /// use rufl::rational::Rational;
+    ///
+    /// assert_eq!(Rational::from([-7, 2]).round(), -4);
+    /// assert_eq!(Rational::from([5, 3]).round(), 2);
+    /// ```
     #[inline]
     pub fn round(&self) -> Integer {
-        let mut res = self.numerator();
-        res.tdiv_q_assign(self.denominator());
+        // round(x) = sign(x) * floor(|x| + 1/2) = sign(x) * floor((2|n| + d) / 2d)
+        let mut res = Integer::default();
+        unsafe {
+            let mut n = self.numerator().abs();
+            let mut d = self.denominator();
+            fmpz::fmpz_mul_2exp(n.as_mut_ptr(), n.as_ptr(), 1);
+            fmpz::fmpz_add(n.as_mut_ptr(), n.as_ptr(), d.as_ptr());
+            fmpz::fmpz_mul_2exp(d.as_mut_ptr(), d.as_ptr(), 1);
+            fmpz::fmpz_fdiv_q(res.as_mut_ptr(), n.as_ptr(), d.as_ptr());
+        }
+        if self.sign() < 0 {
+            res.neg_assign();
+        }
         res
     }
-    */
     
     #[inline]
     pub fn sign(&self) -> i32 {
@@ -304,5 +434,200 @@ impl Rational {
             res
         }
     }
+
+    /// Convert the `Rational` to a string in base `base`, of the form `n` or
+    /// `n/d`.
+    ///
+    /// ```
+    /// use rufl::rational::Rational;
+    ///
+    /// let q = Rational::from([-5, 8]);
+    /// assert_eq!(q.to_str_radix(2), "-101/1000");
+    /// ```
+    pub fn to_str_radix(&self, base: u8) -> String {
+        let n = self.numerator();
+        let d = self.denominator();
+        if d.is_one() {
+            n.to_str_radix(base)
+        } else {
+            format!("{}/{}", n.to_str_radix(base), d.to_str_radix(base))
+        }
+    }
+
+    /// Parse a `Rational` of the form `n` or `n/d` in base `base`, which can
+    /// be between 2 and 62, inclusive. The numerator and denominator are
+    /// parsed as in [Integer::from_str_radix].
+    ///
+    /// ```
+    /// use rufl::rational::Rational;
+    ///
+    /// let q = Rational::from_str_radix("-a/1_0", 16).unwrap();
+    /// assert_eq!(q, Rational::from([-5, 8]));
+    ///
+    /// assert!(Rational::from_str_radix("1/0", 10).is_err());
+    /// ```
+    pub fn from_str_radix(s: &str, base: u32) -> crate::Result<Rational> {
+        match s.split_once('/') {
+            None => Ok(Rational::from(Integer::from_str_radix(s, base)?)),
+            Some((n, d)) => {
+                let n = Integer::from_str_radix(n, base)?;
+                let d = Integer::from_str_radix(d, base)?;
+                if d.is_zero() {
+                    return Err(crate::Error::DivisionError(
+                        "Denominator is zero.".to_string()
+                    ));
+                }
+                Ok(Rational::from([n, d]))
+            }
+        }
+    }
+
+    // Write the rational number in base `base` with padding and sign flags.
+    fn fmt_radix(
+        &self,
+        f: &mut fmt::Formatter,
+        base: u8,
+        prefix: &str,
+        upper: bool
+    ) -> fmt::Result {
+        let mut digits = self.abs().to_str_radix(base);
+        if upper {
+            digits.make_ascii_uppercase();
+        }
+        f.pad_integral(self.sign() >= 0, prefix, &digits)
+    }
+
+    /// Convert an `f64` to a `Rational` exactly. Returns `None` if the input
+    /// is infinite or NaN.
+    ///
+    /// ```
+    /// use rufl::rational::Rational;
+    ///
+    /// let q = Rational::from_f64(-0.375).unwrap();
+    /// assert_eq!(q, Rational::from([-3, 8]));
+    ///
+    /// let q = Rational::from_f64(0.1).unwrap();
+    /// assert_eq!(q.denominator(), 36028797018963968u64);
+    ///
+    /// assert!(Rational::from_f64(f64::NAN).is_none());
+    /// ```
+    pub fn from_f64(x: f64) -> Option<Rational> {
+        if !x.is_finite() {
+            return None;
+        }
+        let bits = x.to_bits();
+        let biased = ((bits >> 52) & 0x7ff) as i64;
+        let frac = bits & ((1u64 << 52) - 1);
+        let (mant, exp) = if biased == 0 {
+            (frac, -1074)
+        } else {
+            (frac | (1u64 << 52), biased - 1075)
+        };
+
+        let mut num = Integer::from(mant);
+        if x.is_sign_negative() {
+            num.neg_assign();
+        }
+        if exp >= 0 {
+            Some(Rational::from(num << exp as u64))
+        } else {
+            Some(Rational::from([num, Integer::one() << (-exp) as u64]))
+        }
+    }
+
+    /// Convert an `f32` to a `Rational` exactly. Returns `None` if the input
+    /// is infinite or NaN.
+    ///
+    /// ```
+    /// use rufl::rational::Rational;
+    ///
+    /// let q = Rational::from_f32(2.5).unwrap();
+    /// assert_eq!(q, Rational::from([5, 2]));
+    /// ```
+    #[inline]
+    pub fn from_f32(x: f32) -> Option<Rational> {
+        Rational::from_f64(x.into())
+    }
+
+    /// Convert the `Rational` to an `f64`, correctly rounded in the direction
+    /// `round`. Values too large for an `f64` round to infinity or to
+    /// `f64::MAX` according to `round`, and tiny values round to subnormals
+    /// or zero.
+    ///
+    /// ```
+    /// use rufl::integer::Round;
+    /// use rufl::rational::Rational;
+    ///
+    /// let q = Rational::from([1, 3]);
+    /// assert_eq!(q.to_f64(Round::Nearest), 1.0 / 3.0);
+    /// assert!(q.to_f64(Round::Floor) < q.to_f64(Round::Ceil));
+    ///
+    /// let q = Rational::from_f64(0.1).unwrap();
+    /// assert_eq!(q.to_f64(Round::Nearest), 0.1);
+    ///
+    /// let q = Rational::from_f64(-5e-324).unwrap();
+    /// assert_eq!(q.to_f64(Round::Nearest), -5e-324);
+    /// ```
+    #[inline]
+    pub fn to_f64(&self, round: Round) -> f64 {
+        let neg = self.sign() < 0;
+        let (x, exp) = self.abs_quotient_2exp(F64_PREC);
+        let (m, e) = round_float(&x, exp, neg, round, F64_PREC, F64_EMIN);
+        compose_f64(&m, e, neg, round)
+    }
+
+    /// Convert the `Rational` to an `f32`, correctly rounded in the direction
+    /// `round`.
+    ///
+    /// ```
+    /// use rufl::integer::Round;
+    /// use rufl::rational::Rational;
+    ///
+    /// let q = Rational::from([2, 3]);
+    /// assert_eq!(q.to_f32(Round::Nearest), 2.0f32 / 3.0);
+    /// ```
+    #[inline]
+    pub fn to_f32(&self, round: Round) -> f32 {
+        let neg = self.sign() < 0;
+        let (x, exp) = self.abs_quotient_2exp(F32_PREC);
+        let (m, e) = round_float(&x, exp, neg, round, F32_PREC, F32_EMIN);
+        compose_f32(&m, e, neg, round)
+    }
+
+    // Return `(x, exp)` where `x * 2^exp` agrees with the absolute value of
+    // the rational number to more than `prec + 1` bits, and the lowest bit of
+    // `x` is set if and only if the approximation is inexact. Rounding
+    // `x * 2^exp` to `prec` bits then gives the correctly rounded result.
+    fn abs_quotient_2exp(&self, prec: u64) -> (Integer, i64) {
+        let num = self.numerator().abs();
+        let den = self.denominator();
+        if num.is_zero() {
+            return (num, 0);
+        }
+
+        // Scale so that the quotient has at least prec + 2 bits.
+        let s = prec as i64 + 2 + den.bits() as i64 - num.bits() as i64;
+        let (num, den) = if s >= 0 {
+            (num << s as u64, den)
+        } else {
+            (num, den << (-s) as u64)
+        };
+
+        let mut q = Integer::default();
+        let mut r = Integer::default();
+        unsafe {
+            fmpz::fmpz_fdiv_qr(
+                q.as_mut_ptr(),
+                r.as_mut_ptr(),
+                num.as_ptr(),
+                den.as_ptr()
+            );
+        }
+        q <<= 1u64;
+        if !r.is_zero() {
+            q += 1u64;
+        }
+        (q, -(s + 1))
+    }
 }
 
@@ -0,0 +1,59 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Rational-valued number functions from FLINT's `arith` module.
+
+use crate::Rational;
+use flint_sys::arith;
+
+impl Rational {
+    /// Return the Bernoulli number `B_n`, with the convention `B_1 = -1/2`.
+    ///
+    /// ```
+    /// use rufl::rational::Rational;
+    ///
+    /// assert_eq!(Rational::bernoulli(1), Rational::from([-1, 2]));
+    /// assert_eq!(Rational::bernoulli(12), Rational::from([-691, 2730]));
+    /// assert!(Rational::bernoulli(13).is_zero());
+    /// ```
+    #[inline]
+    pub fn bernoulli(n: u64) -> Rational {
+        let mut res = Rational::default();
+        unsafe { arith::arith_bernoulli_number(res.as_mut_ptr(), n); }
+        res
+    }
+
+    /// Return the harmonic number `H_n = 1 + 1/2 + ... + 1/n`.
+    ///
+    /// ```
+    /// use rufl::rational::Rational;
+    ///
+    /// assert_eq!(Rational::harmonic(4), Rational::from([25, 12]));
+    /// assert!(Rational::harmonic(0).is_zero());
+    /// ```
+    #[inline]
+    pub fn harmonic(n: u64) -> Rational {
+        let mut res = Rational::default();
+        unsafe {
+            arith::arith_harmonic_number(
+                res.as_mut_ptr(),
+                n.try_into().expect("Cannot convert input to a signed long.")
+            );
+        }
+        res
+    }
+}
@@ -0,0 +1,106 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Borrowed references to rationals stored inside other objects.
+
+use crate::Rational;
+use flint_sys::flint;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+
+/// A borrowed reference to a [Rational] stored inside another object, such
+/// as an entry of a [RatMat][crate::RatMat], obtained without copying. It
+/// dereferences to [Rational], as [IntegerRef][crate::IntegerRef] does to
+/// [Integer][crate::Integer].
+///
+/// Coefficients of a [RatPoly][crate::RatPoly] share a common denominator
+/// and are not stored as rationals, so they cannot be borrowed.
+#[derive(Clone, Copy)]
+pub struct RationalRef<'a> {
+    inner: &'a Rational,
+}
+
+impl<'a> RationalRef<'a> {
+    /// Borrow the `fmpq` at `ptr` as a [Rational].
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to an initialized, canonical `fmpq` that is neither
+    /// modified nor freed for the lifetime `'a`.
+    #[inline]
+    pub unsafe fn from_ptr(ptr: *const flint::fmpq) -> Self {
+        RationalRef { inner: &*(ptr as *const Rational) }
+    }
+
+    /// Return the borrowed rational with the full lifetime `'a`.
+    #[inline]
+    pub fn as_rational(&self) -> &'a Rational {
+        self.inner
+    }
+}
+
+impl Deref for RationalRef<'_> {
+    type Target = Rational;
+    #[inline]
+    fn deref(&self) -> &Rational {
+        self.inner
+    }
+}
+
+impl AsRef<Rational> for RationalRef<'_> {
+    #[inline]
+    fn as_ref(&self) -> &Rational {
+        self.inner
+    }
+}
+
+impl fmt::Debug for RationalRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.inner, f)
+    }
+}
+
+impl fmt::Display for RationalRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self.inner, f)
+    }
+}
+
+impl Hash for RationalRef<'_> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner.hash(state);
+    }
+}
+
+impl<T> PartialEq<T> for RationalRef<'_>
+where
+    Rational: PartialEq<T>
+{
+    #[inline]
+    fn eq(&self, rhs: &T) -> bool {
+        self.inner == rhs
+    }
+}
+
+impl From<RationalRef<'_>> for Rational {
+    #[inline]
+    fn from(src: RationalRef<'_>) -> Rational {
+        src.inner.clone()
+    }
+}
@@ -0,0 +1,304 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Continued fractions and rational approximation.
+
+use crate::{Integer, Rational};
+use crate::ops::Inv;
+use flint_sys::fmpz;
+
+impl Rational {
+    /// Return the regular continued fraction `[a0; a1, ..., an]` of the
+    /// rational number, where `a0` is the floor and the remaining terms are
+    /// positive. The last term is greater than one unless the rational
+    /// number is an integer.
+    ///
+    /// ```
+    /// use rufl::rational::Rational;
+    ///
+    /// let q = Rational::from([415, 93]);
+    /// assert_eq!(q.continued_fraction(), vec![4, 2, 6, 7]);
+    ///
+    /// let q = Rational::from([-3, 2]);
+    /// assert_eq!(q.continued_fraction(), vec![-2, 2]);
+    /// ```
+    pub fn continued_fraction(&self) -> Vec<Integer> {
+        let mut num = self.numerator();
+        let mut den = self.denominator();
+        let mut res = vec![];
+        while !den.is_zero() {
+            let mut a = Integer::default();
+            let mut r = Integer::default();
+            unsafe {
+                fmpz::fmpz_fdiv_qr(
+                    a.as_mut_ptr(),
+                    r.as_mut_ptr(),
+                    num.as_ptr(),
+                    den.as_ptr()
+                );
+            }
+            res.push(a);
+            num = den;
+            den = r;
+        }
+        res
+    }
+
+    /// Return the rational number with continued fraction
+    /// `[a0; a1, ..., an]`. Panics if `terms` is empty or any term after the
+    /// first is not positive.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    /// use rufl::rational::Rational;
+    ///
+    /// let terms: Vec<Integer> = [4, 2, 6, 7].into_iter().map(Integer::from).collect();
+    /// assert_eq!(Rational::from_continued_fraction(&terms), Rational::from([415, 93]));
+    /// ```
+    pub fn from_continued_fraction(terms: &[Integer]) -> Rational {
+        assert!(!terms.is_empty());
+        assert!(terms[1..].iter().all(|a| a > &0));
+
+        let mut conv = ConvergentState::new();
+        for a in terms {
+            conv.push(a);
+        }
+        conv.current()
+    }
+
+    /// Return a lazy iterator over the convergents of the continued fraction
+    /// of the rational number. The last convergent is the rational number
+    /// itself.
+    ///
+    /// ```
+    /// use rufl::rational::Rational;
+    ///
+    /// let q = Rational::from([415, 93]);
+    /// let c: Vec<Rational> = q.convergents().collect();
+    /// assert_eq!(c, vec![
+    ///     Rational::from(4),
+    ///     Rational::from([9, 2]),
+    ///     Rational::from([58, 13]),
+    ///     Rational::from([415, 93])
+    /// ]);
+    /// ```
+    #[inline]
+    pub fn convergents(&self) -> Convergents {
+        Convergents {
+            num: self.numerator(),
+            den: self.denominator(),
+            state: ConvergentState::new(),
+        }
+    }
+
+    /// Return the rational number closest to `self` whose denominator is at
+    /// most `max_den`, choosing the smaller denominator in case of a tie.
+    /// The result is either a convergent or a semiconvergent of the continued
+    /// fraction. Panics if `max_den` is not positive.
+    ///
+    /// ```
+    /// use rufl::integer::Integer;
+    /// use rufl::rational::Rational;
+    ///
+    /// // An approximation of pi.
+    /// let pi = Rational::from([3141592653589793u64, 1000000000000000u64]);
+    /// assert_eq!(pi.best_approximation(Integer::from(10)), Rational::from([22, 7]));
+    /// assert_eq!(pi.best_approximation(Integer::from(1000)), Rational::from([355, 113]));
+    ///
+    /// // 18/7 is a semiconvergent of 31/12 = [2; 1, 1, 2, 2].
+    /// let q = Rational::from([31, 12]);
+    /// assert_eq!(q.best_approximation(Integer::from(7)), Rational::from([18, 7]));
+    /// ```
+    pub fn best_approximation<T: AsRef<Integer>>(&self, max_den: T) -> Rational {
+        let max_den = max_den.as_ref();
+        assert!(max_den > &0);
+        if &self.denominator() <= max_den {
+            return self.clone();
+        }
+
+        // Find the last convergent h/k with k <= max_den.
+        let mut num = self.numerator();
+        let mut den = self.denominator();
+        let mut state = ConvergentState::new();
+        loop {
+            let mut a = Integer::default();
+            let mut r = Integer::default();
+            unsafe {
+                fmpz::fmpz_fdiv_qr(
+                    a.as_mut_ptr(),
+                    r.as_mut_ptr(),
+                    num.as_ptr(),
+                    den.as_ptr()
+                );
+            }
+            let mut next = state.clone();
+            next.push(&a);
+            if &next.k[1] > max_den {
+                break;
+            }
+            state = next;
+            num = den;
+            den = r;
+        }
+
+        // The best semiconvergent (h' + t h)/(k' + t k) with the largest t
+        // keeping the denominator in range.
+        let [h0, h1] = &state.h;
+        let [k0, k1] = &state.k;
+        let mut t = Integer::default();
+        unsafe {
+            let diff = max_den - k0;
+            fmpz::fmpz_fdiv_q(t.as_mut_ptr(), diff.as_ptr(), k1.as_ptr());
+        }
+        let semi = Rational::from([h0 + &t * h1, k0 + &t * k1]);
+        let conv = Rational::from([h1, k1]);
+
+        let d_semi = (&semi - self).abs();
+        let d_conv = (&conv - self).abs();
+        if d_semi < d_conv || (d_semi == d_conv && semi.denominator() < conv.denominator()) {
+            semi
+        } else {
+            conv
+        }
+    }
+
+    /// Return the simplest rational number in the closed interval between `a`
+    /// and `b`, that is, the one with the smallest denominator and, among
+    /// those, the smallest absolute value.
+    ///
+    /// ```
+    /// use rufl::rational::Rational;
+    ///
+    /// let a = Rational::from([3, 10]);
+    /// let b = Rational::from([4, 10]);
+    /// assert_eq!(Rational::simplest_between(&a, &b), Rational::from([1, 3]));
+    ///
+    /// let a = Rational::from([-7, 3]);
+    /// let b = Rational::from([5, 2]);
+    /// assert_eq!(Rational::simplest_between(&a, &b), Rational::from(0));
+    ///
+    /// let a = Rational::from([-22, 7]);
+    /// let b = Rational::from([-3, 1]);
+    /// assert_eq!(Rational::simplest_between(&b, &a), Rational::from(-3));
+    /// ```
+    pub fn simplest_between<T: AsRef<Rational>>(a: T, b: T) -> Rational {
+        let (a, b) = (a.as_ref(), b.as_ref());
+        let (mut lo, mut hi) = if a <= b {
+            (a.clone(), b.clone())
+        } else {
+            (b.clone(), a.clone())
+        };
+
+        if lo.sign() <= 0 && hi.sign() >= 0 {
+            return Rational::zero();
+        }
+        let neg = hi.sign() < 0;
+        if neg {
+            (lo, hi) = (-hi, -lo);
+        }
+
+        // Now 0 < lo <= hi. Peel off continued fraction terms shared by the
+        // endpoints until an integer lies in the interval.
+        let mut terms = vec![];
+        let last = loop {
+            let c = lo.ceil();
+            if hi >= c {
+                break c;
+            }
+            let fl = lo.floor();
+            let new_lo = (&hi - &fl).inv();
+            let new_hi = (&lo - &fl).inv();
+            terms.push(fl);
+            lo = new_lo;
+            hi = new_hi;
+        };
+
+        let mut res = Rational::from(last);
+        for a in terms.iter().rev() {
+            res = res.inv() + a;
+        }
+        if neg {
+            -res
+        } else {
+            res
+        }
+    }
+}
+
+// The last two convergents h[0]/k[0], h[1]/k[1] of a continued fraction.
+#[derive(Clone, Debug)]
+struct ConvergentState {
+    h: [Integer; 2],
+    k: [Integer; 2],
+}
+
+impl ConvergentState {
+    #[inline]
+    fn new() -> Self {
+        ConvergentState {
+            h: [Integer::zero(), Integer::one()],
+            k: [Integer::one(), Integer::zero()],
+        }
+    }
+
+    #[inline]
+    fn push(&mut self, a: &Integer) {
+        let h = a * &self.h[1] + &self.h[0];
+        let k = a * &self.k[1] + &self.k[0];
+        self.h.swap(0, 1);
+        self.k.swap(0, 1);
+        self.h[1] = h;
+        self.k[1] = k;
+    }
+
+    #[inline]
+    fn current(&self) -> Rational {
+        Rational::from([&self.h[1], &self.k[1]])
+    }
+}
+
+/// A lazy iterator over the convergents of the continued fraction of a
+/// [Rational]. Created by [Rational::convergents].
+#[derive(Clone, Debug)]
+pub struct Convergents {
+    num: Integer,
+    den: Integer,
+    state: ConvergentState,
+}
+
+impl Iterator for Convergents {
+    type Item = Rational;
+
+    fn next(&mut self) -> Option<Rational> {
+        if self.den.is_zero() {
+            return None;
+        }
+        let mut a = Integer::default();
+        let mut r = Integer::default();
+        unsafe {
+            fmpz::fmpz_fdiv_qr(
+                a.as_mut_ptr(),
+                r.as_mut_ptr(),
+                self.num.as_ptr(),
+                self.den.as_ptr()
+            );
+        }
+        self.state.push(&a);
+        self.num = std::mem::replace(&mut self.den, r);
+        Some(self.state.current())
+    }
+}
@@ -26,16 +26,32 @@ use crate::{
 use flint_sys::fmpq;
 use std::str::FromStr;
 
+/// Parses a rational number of the form `x` or `x/y`, where `x` and `y` are
+/// parsed as for [Integer].
+///
+/// ```
+/// use rufl::rational::Rational;
+///
+/// let q: Rational = "+0x10/-6".parse().unwrap();
+/// assert_eq!(q, Rational::from([-8, 3]));
+///
+/// assert!("1/0".parse::<Rational>().is_err());
+/// ```
 impl FromStr for Rational {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self> {
         let r = s.split("/").collect::<Vec<_>>();
         match r.len() {
             1 => Ok(Rational::from(Integer::from_str(r[0])?)),
-            2 => Ok(Rational::from([
-                Integer::from_str(r[0])?,
-                Integer::from_str(r[1])?,
-            ])),
+            2 => {
+                let d = Integer::from_str(r[1])?;
+                if d.is_zero() {
+                    return Err(Error::DivisionError(
+                        "Denominator is zero.".to_string()
+                    ));
+                }
+                Ok(Rational::from([Integer::from_str(r[0])?, d]))
+            }
             _ => Err(Msg("Input must be of the form \"x\" or \"x/y\" where x and y are 
                      integers.".to_string())),
         }
@@ -58,6 +74,24 @@ impl_from_unsafe! {
     fmpq_set_si_den1
 }
 
+impl_from! {
+    Rational, u128
+    {
+        fn from(src: &u128) -> Rational {
+            Rational::from(Integer::from(src))
+        }
+    }
+}
+
+impl_from! {
+    Rational, i128
+    {
+        fn from(src: &i128) -> Rational {
+            Rational::from(Integer::from(src))
+        }
+    }
+}
+
 impl_from_unsafe! {
     None
     Rational, Integer
@@ -109,3 +143,29 @@ impl From<[&Integer; 2]> for Rational {
         }
     }
 }
+
+///////////////////////////////////////////////////////////////////
+// TryFrom
+///////////////////////////////////////////////////////////////////
+
+impl TryFrom<f64> for Rational {
+    type Error = Error;
+    fn try_from(src: f64) -> Result<Self> {
+        Rational::from_f64(src).ok_or_else(|| Error::ConversionError {
+            val: src.to_string(),
+            in_type: "f64".to_string(),
+            out_type: "Rational".to_string(),
+        })
+    }
+}
+
+impl TryFrom<f32> for Rational {
+    type Error = Error;
+    fn try_from(src: f32) -> Result<Self> {
+        Rational::from_f32(src).ok_or_else(|| Error::ConversionError {
+            val: src.to_string(),
+            in_type: "f32".to_string(),
+            out_type: "Rational".to_string(),
+        })
+    }
+}
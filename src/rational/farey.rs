@@ -0,0 +1,356 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Farey sequences and enumeration of rational numbers.
+
+use crate::{Integer, Rational};
+use flint_sys::{fmpq, fmpz};
+
+impl Rational {
+    /// Return the mediant `(a + c)/(b + d)` of `a/b` and `c/d`, where both
+    /// fractions are in lowest terms.
+    ///
+    /// ```
+    /// use rufl::rational::Rational;
+    ///
+    /// let x = Rational::from([1, 3]);
+    /// let y = Rational::from([1, 2]);
+    /// assert_eq!(x.mediant(&y), Rational::from([2, 5]));
+    /// ```
+    #[inline]
+    pub fn mediant<T: AsRef<Rational>>(&self, other: T) -> Rational {
+        let mut res = Rational::default();
+        unsafe {
+            fmpq::fmpq_mediant(res.as_mut_ptr(), self.as_ptr(), other.as_ref().as_ptr());
+        }
+        res
+    }
+
+    /// Return the neighbors of the rational number in the Farey sequence of
+    /// order `order`, extended to the whole real line, that is, the closest
+    /// fractions below and above it with denominator at most `order`. Panics
+    /// if `order` is less than the denominator of `self`.
+    ///
+    /// ```
+    /// use rufl::rational::Rational;
+    ///
+    /// let x = Rational::from([1, 2]);
+    /// let (l, r) = x.farey_neighbors(5);
+    /// assert_eq!(l, Rational::from([2, 5]));
+    /// assert_eq!(r, Rational::from([3, 5]));
+    /// ```
+    pub fn farey_neighbors<T: Into<Integer>>(&self, order: T) -> (Rational, Rational) {
+        let order = order.into();
+        assert!(order >= self.denominator());
+        let mut l = Rational::default();
+        let mut r = Rational::default();
+        unsafe {
+            fmpq::fmpq_farey_neighbors(
+                l.as_mut_ptr(),
+                r.as_mut_ptr(),
+                self.as_ptr(),
+                order.as_ptr()
+            );
+        }
+        (l, r)
+    }
+
+    /// Return an iterator over the Farey sequence of order `order`, the
+    /// fractions in `[0, 1]` with denominator at most `order` in increasing
+    /// order.
+    ///
+    /// ```
+    /// use rufl::rational::Rational;
+    ///
+    /// let f: Vec<Rational> = Rational::farey_sequence(4).collect();
+    /// assert_eq!(f, vec![
+    ///     Rational::from(0),
+    ///     Rational::from([1, 4]),
+    ///     Rational::from([1, 3]),
+    ///     Rational::from([1, 2]),
+    ///     Rational::from([2, 3]),
+    ///     Rational::from([3, 4]),
+    ///     Rational::from(1)
+    /// ]);
+    /// ```
+    #[inline]
+    pub fn farey_sequence<T: Into<Integer>>(order: T) -> FareySequence {
+        // In [0, 1] the height of a fraction is its denominator.
+        FareySequence(BoundedHeight::new(
+            &Rational::zero(),
+            &Rational::one(),
+            order.into()
+        ))
+    }
+
+    /// Return an iterator over the rational numbers in the closed interval
+    /// between `lo` and `hi` with [height](Rational::height) at most
+    /// `height`, in increasing order.
+    ///
+    /// ```
+    /// use rufl::rational::Rational;
+    ///
+    /// let lo = Rational::from(-1);
+    /// let hi = Rational::from(3);
+    /// let v: Vec<Rational> = Rational::bounded_height_between(&lo, &hi, 2).collect();
+    /// assert_eq!(v, vec![
+    ///     Rational::from(-1),
+    ///     Rational::from([-1, 2]),
+    ///     Rational::from(0),
+    ///     Rational::from([1, 2]),
+    ///     Rational::from(1),
+    ///     Rational::from(2)
+    /// ]);
+    /// assert!(v.iter().all(|x| x.height() <= 2));
+    /// ```
+    #[inline]
+    pub fn bounded_height_between<T, H>(lo: T, hi: T, height: H) -> BoundedHeight
+    where
+        T: AsRef<Rational>,
+        H: Into<Integer>,
+    {
+        BoundedHeight::new(lo.as_ref(), hi.as_ref(), height.into())
+    }
+
+    /// Return an iterator over the positive rational numbers in breadth-first
+    /// order of the Stern–Brocot tree. Each level of the tree is traversed in
+    /// increasing order.
+    ///
+    /// ```
+    /// use rufl::rational::Rational;
+    ///
+    /// let v: Vec<Rational> = Rational::stern_brocot().take(7).collect();
+    /// assert_eq!(v, vec![
+    ///     Rational::from(1),
+    ///     Rational::from([1, 2]),
+    ///     Rational::from(2),
+    ///     Rational::from([1, 3]),
+    ///     Rational::from([2, 3]),
+    ///     Rational::from([3, 2]),
+    ///     Rational::from(3)
+    /// ]);
+    /// ```
+    #[inline]
+    pub fn stern_brocot() -> SternBrocot {
+        SternBrocot { path: vec![] }
+    }
+
+    /// Return an iterator over the positive rational numbers in breadth-first
+    /// order of the Calkin–Wilf tree. Every positive rational number appears
+    /// exactly once.
+    ///
+    /// ```
+    /// use rufl::rational::Rational;
+    ///
+    /// let v: Vec<Rational> = Rational::calkin_wilf().take(7).collect();
+    /// assert_eq!(v, vec![
+    ///     Rational::from(1),
+    ///     Rational::from([1, 2]),
+    ///     Rational::from(2),
+    ///     Rational::from([1, 3]),
+    ///     Rational::from([3, 2]),
+    ///     Rational::from([2, 3]),
+    ///     Rational::from(3)
+    /// ]);
+    /// ```
+    #[inline]
+    pub fn calkin_wilf() -> CalkinWilf {
+        CalkinWilf { next: Rational::one() }
+    }
+}
+
+#[inline]
+fn fdiv(a: &Integer, b: &Integer) -> Integer {
+    let mut res = Integer::default();
+    unsafe {
+        fmpz::fmpz_fdiv_q(res.as_mut_ptr(), a.as_ptr(), b.as_ptr());
+    }
+    res
+}
+
+// The largest t >= 0 with |x + t*y| <= h, assuming |x| <= h, or `None` if
+// there is no bound.
+fn max_steps(x: &Integer, y: &Integer, h: &Integer) -> Option<Integer> {
+    match y.sign() {
+        1 => Some(fdiv(&(h - x), y)),
+        -1 => Some(fdiv(&(h + x), &(-y))),
+        _ => None,
+    }
+}
+
+fn min_steps(bounds: [Option<Integer>; 3]) -> Integer {
+    bounds.into_iter().flatten().min().expect("at least one bound is finite")
+}
+
+/// An iterator over the rational numbers of bounded height in an interval,
+/// in increasing order. Created by [Rational::bounded_height_between].
+#[derive(Clone, Debug)]
+pub struct BoundedHeight {
+    height: Integer,
+    hi: Rational,
+    // Consecutive fractions l = a/b < r = c/d with bc - ad = 1, where r is
+    // the next term. Either may be the point at infinity with denominator 0.
+    l: [Integer; 2],
+    r: [Integer; 2],
+}
+
+impl BoundedHeight {
+    fn new(lo: &Rational, hi: &Rational, height: Integer) -> BoundedHeight {
+        let (l, r) = if lo.sign() <= 0 {
+            ([Integer::from(-1), Integer::zero()], [Integer::zero(), Integer::one()])
+        } else {
+            ([Integer::zero(), Integer::one()], [Integer::one(), Integer::zero()])
+        };
+        let mut iter = BoundedHeight { height, hi: hi.clone(), l, r };
+        if iter.height < 1 || lo > hi || lo > &iter.height {
+            iter.r = [Integer::one(), Integer::zero()];
+            return iter;
+        }
+
+        // Descend the Stern–Brocot tree towards lo, maintaining l < lo <= r,
+        // until the mediant of l and r exceeds the height bound. Runs of
+        // steps in the same direction are taken at once.
+        let p = lo.numerator();
+        let q = lo.denominator();
+        let h = &iter.height;
+        loop {
+            let [a, b] = &iter.l;
+            let [c, d] = &iter.r;
+            let m = [a + c, b + d];
+            if &m[1] > h || &m[0].abs() > h {
+                break;
+            }
+            if &m[0] * &q < &p * &m[1] {
+                // Move l towards r.
+                let den = c * &q - &p * d;
+                let t_lo = if den.is_zero() {
+                    None
+                } else {
+                    Some(fdiv(&(&p * b - a * &q - 1), &den))
+                };
+                let t = min_steps([t_lo, max_steps(a, c, h), max_steps(b, d, h)]);
+                iter.l = [a + &t * c, b + &t * d];
+            } else {
+                // Move r towards l.
+                let t_lo = Some(fdiv(&(c * &q - &p * d), &(&p * b - a * &q)));
+                let t = min_steps([t_lo, max_steps(c, a, h), max_steps(d, b, h)]);
+                iter.r = [c + &t * a, d + &t * b];
+            }
+        }
+        iter
+    }
+}
+
+impl Iterator for BoundedHeight {
+    type Item = Rational;
+
+    fn next(&mut self) -> Option<Rational> {
+        let [c, d] = &self.r;
+        if d.sign() <= 0 {
+            return None;
+        }
+        let res = Rational::from([c, d]);
+        if res > self.hi {
+            self.r = [Integer::one(), Integer::zero()];
+            return None;
+        }
+
+        // The next term is k*r - l for the largest k keeping it in bounds.
+        let [a, b] = &self.l;
+        let h = &self.height;
+        let k = min_steps([
+            Some(fdiv(&(h + b), d)),
+            max_steps(&(-a), c, h),
+            None
+        ]);
+        let next = [&k * c - a, &k * d - b];
+        self.l = std::mem::replace(&mut self.r, next);
+        Some(res)
+    }
+}
+
+/// An iterator over a Farey sequence. Created by [Rational::farey_sequence].
+#[derive(Clone, Debug)]
+pub struct FareySequence(BoundedHeight);
+
+impl Iterator for FareySequence {
+    type Item = Rational;
+
+    #[inline]
+    fn next(&mut self) -> Option<Rational> {
+        self.0.next()
+    }
+}
+
+/// A breadth-first iterator over the Stern–Brocot tree. Created by
+/// [Rational::stern_brocot].
+#[derive(Clone, Debug)]
+pub struct SternBrocot {
+    // The path from the root to the next node, `true` for a right move.
+    path: Vec<bool>,
+}
+
+impl Iterator for SternBrocot {
+    type Item = Rational;
+
+    fn next(&mut self) -> Option<Rational> {
+        let mut l = [Integer::zero(), Integer::one()];
+        let mut r = [Integer::one(), Integer::zero()];
+        for &right in &self.path {
+            let m = [&l[0] + &r[0], &l[1] + &r[1]];
+            if right {
+                l = m;
+            } else {
+                r = m;
+            }
+        }
+        let res = Rational::from([&l[0] + &r[0], &l[1] + &r[1]]);
+
+        // Advance to the next node on this level, or the first node on the
+        // next level.
+        match self.path.iter().rposition(|&right| !right) {
+            Some(i) => {
+                self.path[i] = true;
+                self.path[i + 1..].iter_mut().for_each(|x| *x = false);
+            }
+            None => {
+                let n = self.path.len() + 1;
+                self.path = vec![false; n];
+            }
+        }
+        Some(res)
+    }
+}
+
+/// A breadth-first iterator over the Calkin–Wilf tree. Created by
+/// [Rational::calkin_wilf].
+#[derive(Clone, Debug)]
+pub struct CalkinWilf {
+    next: Rational,
+}
+
+impl Iterator for CalkinWilf {
+    type Item = Rational;
+
+    fn next(&mut self) -> Option<Rational> {
+        let mut next = Rational::default();
+        unsafe {
+            fmpq::fmpq_next_calkin_wilf(next.as_mut_ptr(), self.next.as_ptr());
+        }
+        Some(std::mem::replace(&mut self.next, next))
+    }
+}
@@ -0,0 +1,243 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! [num-traits](https://docs.rs/num-traits) implementations for [Rational].
+//!
+//! `Num` and `Signed` are not implemented since they require
+//! `Rem<Output = Self>`, while the remainder of a `Rational` by an [Integer]
+//! is a modular reduction.
+
+use crate::{Integer, Rational, Round};
+use flint_sys::fmpz;
+use num_traits::{
+    CheckedAdd,
+    CheckedDiv,
+    CheckedMul,
+    CheckedNeg,
+    CheckedSub,
+    FromPrimitive,
+    Inv,
+    One,
+    Pow,
+    ToPrimitive,
+    Zero,
+};
+
+impl Zero for Rational {
+    #[inline]
+    fn zero() -> Self {
+        Rational::zero()
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        Rational::is_zero(self)
+    }
+}
+
+impl One for Rational {
+    #[inline]
+    fn one() -> Self {
+        Rational::one()
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        Rational::is_one(self)
+    }
+}
+
+macro_rules! impl_pow {
+    ($($t:ident)+) => ($(
+        impl Pow<$t> for Rational {
+            type Output = Rational;
+            #[inline]
+            fn pow(self, rhs: $t) -> Rational {
+                crate::ops::Pow::pow(self, rhs)
+            }
+        }
+
+        impl Pow<$t> for &Rational {
+            type Output = Rational;
+            #[inline]
+            fn pow(self, rhs: $t) -> Rational {
+                crate::ops::Pow::pow(self, rhs)
+            }
+        }
+    )+);
+}
+
+impl_pow! { u64 u32 u16 u8 i64 i32 i16 i8 Integer }
+
+impl Inv for Rational {
+    type Output = Rational;
+    #[inline]
+    fn inv(self) -> Rational {
+        crate::ops::Inv::inv(self)
+    }
+}
+
+impl Inv for &Rational {
+    type Output = Rational;
+    #[inline]
+    fn inv(self) -> Rational {
+        crate::ops::Inv::inv(self)
+    }
+}
+
+impl CheckedAdd for Rational {
+    #[inline]
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(self + rhs)
+    }
+}
+
+impl CheckedSub for Rational {
+    #[inline]
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        Some(self - rhs)
+    }
+}
+
+impl CheckedMul for Rational {
+    #[inline]
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        Some(self * rhs)
+    }
+}
+
+impl CheckedDiv for Rational {
+    #[inline]
+    fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        if rhs.is_zero() {
+            None
+        } else {
+            Some(self / rhs)
+        }
+    }
+}
+
+impl CheckedNeg for Rational {
+    #[inline]
+    fn checked_neg(&self) -> Option<Self> {
+        Some(-self)
+    }
+}
+
+impl ToPrimitive for Rational {
+    /// Converts to an `i64` after truncating towards zero.
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        self.trunc_integer().get_si()
+    }
+
+    /// Converts to a `u64` after truncating towards zero.
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        self.trunc_integer().get_ui()
+    }
+
+    #[inline]
+    fn to_i128(&self) -> Option<i128> {
+        self.trunc_integer().get_i128()
+    }
+
+    #[inline]
+    fn to_u128(&self) -> Option<u128> {
+        self.trunc_integer().get_u128()
+    }
+
+    #[inline]
+    fn to_f32(&self) -> Option<f32> {
+        Some(Rational::to_f32(self, Round::Nearest))
+    }
+
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        Some(Rational::to_f64(self, Round::Nearest))
+    }
+}
+
+impl FromPrimitive for Rational {
+    #[inline]
+    fn from_i64(n: i64) -> Option<Self> {
+        Some(Rational::from(n))
+    }
+
+    #[inline]
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(Rational::from(n))
+    }
+
+    #[inline]
+    fn from_i128(n: i128) -> Option<Self> {
+        Some(Rational::from(n))
+    }
+
+    #[inline]
+    fn from_u128(n: u128) -> Option<Self> {
+        Some(Rational::from(n))
+    }
+
+    #[inline]
+    fn from_f32(n: f32) -> Option<Self> {
+        Rational::from_f32(n)
+    }
+
+    #[inline]
+    fn from_f64(n: f64) -> Option<Self> {
+        Rational::from_f64(n)
+    }
+}
+
+impl Rational {
+    // The integer part of the rational number, rounding towards zero.
+    fn trunc_integer(&self) -> Integer {
+        let mut res = Integer::default();
+        unsafe {
+            fmpz::fmpz_tdiv_q(res.as_mut_ptr(), &self.inner.num, &self.inner.den);
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Rational;
+    use num_traits::{CheckedDiv, FromPrimitive, One, ToPrimitive, Zero};
+
+    fn mean<T: Zero + One + Clone + CheckedDiv>(xs: &[T]) -> Option<T> {
+        let mut sum = T::zero();
+        let mut n = T::zero();
+        for x in xs {
+            sum = sum + x.clone();
+            n = n + T::one();
+        }
+        sum.checked_div(&n)
+    }
+
+    #[test]
+    fn generic() {
+        let xs = [Rational::from([1, 2]), Rational::from([1, 3])];
+        assert_eq!(mean(&xs), Some(Rational::from([5, 12])));
+        assert_eq!(mean::<Rational>(&[]), None);
+
+        assert_eq!(Rational::from([-7, 2]).to_i64(), Some(-3));
+        assert_eq!(Rational::from_f64(0.5), Some(Rational::from([1, 2])));
+        assert_eq!(ToPrimitive::to_f64(&Rational::from([1, 4])), Some(0.25));
+    }
+}
@@ -18,10 +18,15 @@
 mod extras;
 mod ops;
 mod conv;
+mod io;
+mod borrowed;
+mod index;
 
 //#[cfg(feature = "serde")]
 //mod serde;
 
+pub use borrowed::RatMatWindow;
+
 use crate::*;
 use flint_sys::fmpq_types::*;
 use flint_sys::fmpq_mat::*;
@@ -31,7 +36,10 @@ use std::hash::{Hash, Hasher};
 use std::mem::MaybeUninit;
 
 
+// Transparent so that windows, which share entries with their parent, can
+// be borrowed as a `RatMat`.
 #[derive(Debug)]
+#[repr(transparent)]
 pub struct RatMat {
     inner: fmpq_mat_struct,
 }
@@ -86,11 +94,14 @@ impl Drop for RatMat {
     }
 }
 
-// TODO: make entries method that borrows so we dont need to copy entries
 impl Hash for RatMat {
     #[inline]
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.get_entries().hash(state);
+        self.nrows().hash(state);
+        self.ncols().hash(state);
+        for e in self.iter_entries() {
+            e.hash(state);
+        }
     }
 }
 
@@ -513,35 +524,14 @@ impl RatMat {
         res
     }
    
-    // TODO: 'window' version to avoid allocation
     /// Return a new matrix containing the `r2 - r1` by `c2 - c1` submatrix of 
     /// an integer matrix whose `(0, 0)` entry is the `(r1, c1)` entry of the input.
+    /// See [RatMat::window] for a view that does not copy the entries.
     pub fn submatrix(&self, r1: usize, c1: usize, r2: usize, c2: usize) -> RatMat {
         if r1 == r2 || c1 == c2 {
             return RatMat::zero(0, 0)
         }
-        
-        assert!(r1 <= r2);
-        assert!(c1 <= c2);
-        let (r1, c1) = self.check_indices(r1, c1);
-        let (r2, c2) = self.check_indices(r2, c2);
-
-        let mut res = RatMat::zero(r2 - r1, c2 - c1);
-        let mut win = MaybeUninit::uninit();
-        unsafe {
-            fmpq_mat::fmpq_mat_window_init(
-                win.as_mut_ptr(), 
-                self.as_ptr(),
-                r1,
-                c1,
-                r2,
-                c2
-            );
-            fmpq_mat::fmpq_mat_set(res.as_mut_ptr(), win.as_ptr());
-            fmpq_mat::fmpq_mat_window_clear(win.as_mut_ptr());
-        }
-        res
-
+        RatMat::clone(&self.window(r1, c1, r2, c2))
     }
     
     /// Return row `i` as an integer matrix.
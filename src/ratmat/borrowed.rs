@@ -0,0 +1,135 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Borrowed views of rational matrices: entry references, windows and
+//! iterators that do not copy entries.
+
+use crate::{RatMat, RationalRef};
+use flint_sys::fmpq_mat;
+use flint_sys::fmpq_types::fmpq_mat_struct;
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::ops::Deref;
+
+/// A read-only view of a block of a [RatMat], created by [RatMat::window]
+/// without copying any entries.
+///
+/// A window dereferences to [RatMat], so any method taking `&self` can be
+/// used on it, including arithmetic by reference and further windows. Use
+/// `clone` on the dereferenced matrix to get an owned copy.
+///
+/// ```
+/// use rufl::ops::NewMatrix;
+/// use rufl::ratmat::RatMat;
+/// use rufl::rational::Rational;
+///
+/// let m = RatMat::new([1, 2, 3, 4].map(|x| Rational::from([x, 2])), 2, 2);
+/// let w = m.window(0, 1, 2, 2);
+/// assert_eq!(w.ncols(), 1);
+/// assert_eq!(w.entry(1, 0), 2);
+/// ```
+pub struct RatMatWindow<'a> {
+    inner: fmpq_mat_struct,
+    phantom: PhantomData<&'a RatMat>,
+}
+
+impl Deref for RatMatWindow<'_> {
+    type Target = RatMat;
+    #[inline]
+    fn deref(&self) -> &RatMat {
+        // `RatMat` is a transparent wrapper of `fmpq_mat_struct`. The window
+        // shares the entries of its parent and is only ever freed by
+        // `fmpq_mat_window_clear`.
+        unsafe { &*(&self.inner as *const fmpq_mat_struct as *const RatMat) }
+    }
+}
+
+impl Drop for RatMatWindow<'_> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { fmpq_mat::fmpq_mat_window_clear(&mut self.inner) }
+    }
+}
+
+impl fmt::Debug for RatMatWindow<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl fmt::Display for RatMatWindow<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
+    }
+}
+
+impl RatMat {
+    /// Return a reference to the `(i, j)`-th entry without copying it.
+    #[inline]
+    pub fn entry(&self, i: usize, j: usize) -> RationalRef<'_> {
+        let (i, j) = self.check_indices(i, j);
+        unsafe { RationalRef::from_ptr(fmpq_mat::fmpq_mat_entry(self.as_ptr(), i, j)) }
+    }
+
+    /// Return a view of the `r2 - r1` by `c2 - c1` block whose `(0, 0)`
+    /// entry is the `(r1, c1)` entry of the matrix, without copying.
+    pub fn window(&self, r1: usize, c1: usize, r2: usize, c2: usize) -> RatMatWindow<'_> {
+        assert!(r1 <= r2 && r2 <= self.nrows());
+        assert!(c1 <= c2 && c2 <= self.ncols());
+        let mut win = MaybeUninit::uninit();
+        unsafe {
+            fmpq_mat::fmpq_mat_window_init(
+                win.as_mut_ptr(),
+                self.as_ptr(),
+                r1 as i64,
+                c1 as i64,
+                r2 as i64,
+                c2 as i64
+            );
+            RatMatWindow { inner: win.assume_init(), phantom: PhantomData }
+        }
+    }
+
+    /// Return an iterator over references to the entries in row-major order.
+    pub fn iter_entries(&self) -> impl Iterator<Item = RationalRef<'_>> + '_ {
+        (0..self.nrows()).flat_map(move |i| self.iter_row(i))
+    }
+
+    /// Return an iterator over references to the entries of row `i`.
+    pub fn iter_row(&self, i: usize)
+        -> impl ExactSizeIterator<Item = RationalRef<'_>> + DoubleEndedIterator + '_
+    {
+        assert!(i < self.nrows());
+        (0..self.ncols()).map(move |j| self.entry(i, j))
+    }
+
+    /// Return an iterator over references to the entries of column `j`.
+    pub fn iter_column(&self, j: usize)
+        -> impl ExactSizeIterator<Item = RationalRef<'_>> + DoubleEndedIterator + '_
+    {
+        assert!(j < self.ncols());
+        (0..self.nrows()).map(move |i| self.entry(i, j))
+    }
+
+    /// Return an iterator over the rows as one-row windows.
+    pub fn iter_rows(&self)
+        -> impl ExactSizeIterator<Item = RatMatWindow<'_>> + DoubleEndedIterator + '_
+    {
+        (0..self.nrows()).map(move |i| self.window(i, 0, i + 1, self.ncols()))
+    }
+}
@@ -0,0 +1,68 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Indexing of rational matrices by `(row, column)` pairs.
+
+use crate::{RatMat, Rational};
+use flint_sys::fmpq_mat;
+use std::ops::{Index, IndexMut};
+
+/// Read an entry by reference, without copying it.
+///
+/// Panics if either index is out of bounds.
+///
+/// ```
+/// use rufl::ops::NewMatrix;
+/// use rufl::ratmat::RatMat;
+/// use rufl::rational::Rational;
+///
+/// let m = RatMat::new([1, 2, 3, 4].map(|x| Rational::from([x, 2])), 2, 2);
+/// assert_eq!(m[(0, 1)], 1);
+/// ```
+impl Index<(usize, usize)> for RatMat {
+    type Output = Rational;
+    #[inline]
+    fn index(&self, (i, j): (usize, usize)) -> &Rational {
+        self.entry(i, j).as_rational()
+    }
+}
+
+/// Modify an entry in place. The entry stays in canonical form since every
+/// `Rational` operation leaves it so.
+///
+/// Panics if either index is out of bounds.
+///
+/// ```
+/// use rufl::ops::NewMatrix;
+/// use rufl::ratmat::RatMat;
+/// use rufl::rational::Rational;
+///
+/// let mut m = RatMat::new([1, 2, 3, 4].map(|x| Rational::from([x, 2])), 2, 2);
+/// m[(0, 0)] += Rational::from([1, 2]);
+/// m[(1, 1)] *= 3;
+/// assert_eq!(m[(0, 0)], 1);
+/// assert_eq!(m[(1, 1)], 6);
+/// ```
+impl IndexMut<(usize, usize)> for RatMat {
+    #[inline]
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut Rational {
+        let (i, j) = self.check_indices(i, j);
+        // `Rational` is a transparent wrapper of `fmpq`, so the entry can be
+        // modified in place by any `Rational` method.
+        unsafe { &mut *(fmpq_mat::fmpq_mat_entry(self.as_mut_ptr(), i, j) as *mut Rational) }
+    }
+}
@@ -0,0 +1,93 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Reading and writing rational matrices in the formats of [MatrixFormat].
+
+use crate::{RatMat, Rational, Result};
+use crate::matio::{self, MatrixFormat};
+use crate::ops::NegAssign;
+use std::io::{self, Read, Write};
+use std::str::FromStr;
+
+impl RatMat {
+    /// Write the matrix to `w` in the format `fmt`.
+    pub fn write_format<W: Write>(&self, w: W, fmt: MatrixFormat) -> io::Result<()> {
+        matio::write_matrix(w, fmt, &matio::RATIONALS, self.nrows(), self.ncols(), |i, j| {
+            self.get_entry(i, j).to_string()
+        })
+    }
+
+    /// Return the matrix as a string in the format `fmt`.
+    ///
+    /// ```
+    /// use rufl::matio::MatrixFormat;
+    /// use rufl::ops::NewMatrix;
+    /// use rufl::ratmat::RatMat;
+    /// use rufl::rational::Rational;
+    ///
+    /// let m = RatMat::new([Rational::from([1, 2]), Rational::from(-3)], 1, 2);
+    /// assert_eq!(m.to_str_format(MatrixFormat::Magma), "Matrix(Rationals(), 1, 2, [1/2, -3])");
+    /// ```
+    pub fn to_str_format(&self, fmt: MatrixFormat) -> String {
+        let mut buf = vec![];
+        self.write_format(&mut buf, fmt).expect("Writing to a buffer cannot fail.");
+        String::from_utf8(buf).expect("Matrix output is valid UTF-8.")
+    }
+
+    /// Read a matrix in the format `fmt` from `r`. Errors in the input are
+    /// reported with the line they occur on. Entries of a Matrix Market file
+    /// with the `real` field are read exactly as decimal numbers.
+    ///
+    /// ```
+    /// use rufl::matio::MatrixFormat;
+    /// use rufl::ratmat::RatMat;
+    /// use rufl::rational::Rational;
+    ///
+    /// let src = "%%MatrixMarket matrix array real general\n2 1\n0.25\n-1.5e1\n";
+    /// let m = RatMat::read_format(src.as_bytes(), MatrixFormat::MatrixMarketArray).unwrap();
+    /// assert_eq!(m.get_entry(0, 0), Rational::from([1, 4]));
+    /// assert_eq!(m.get_entry(1, 0), -15);
+    ///
+    /// let m = RatMat::from_str_format("[1/2, 0;\n 0, 1/3]", MatrixFormat::Pari).unwrap();
+    /// assert_eq!(m.get_entry(1, 1), Rational::from([1, 3]));
+    /// assert!(RatMat::from_str_format("[1, 2;\n 3]", MatrixFormat::Pari).is_err());
+    /// ```
+    pub fn read_format<R: Read>(r: R, fmt: MatrixFormat) -> Result<RatMat> {
+        let parsed = matio::read_matrix(r, fmt)?;
+        let (nrows, ncols) = parsed.dense_dims()?;
+        let mut res = RatMat::zero(nrows, ncols);
+        for e in parsed.entries {
+            let x = if parsed.real {
+                matio::parse_decimal(&e.val)
+            } else {
+                Rational::from_str(&e.val)
+            };
+            let mut x = x.map_err(|err| matio::line_error(e.line, err))?;
+            if e.neg {
+                x.neg_assign();
+            }
+            res.set_entry(e.i, e.j, x);
+        }
+        Ok(res)
+    }
+
+    /// Read a matrix in the format `fmt` from a string, as for
+    /// [RatMat::read_format].
+    pub fn from_str_format(s: &str, fmt: MatrixFormat) -> Result<RatMat> {
+        RatMat::read_format(s.as_bytes(), fmt)
+    }
+}
@@ -18,14 +18,26 @@
 mod ops;
 mod conv;
 mod extras;
+mod families;
+mod division;
+mod interpolate;
+mod recurrence;
+mod index;
+
+#[cfg(feature = "num-traits")]
+mod num;
 
 #[cfg(feature = "serde")]
 mod serde;
 
+pub use index::RatPolyCoeffMut;
+pub use recurrence::RatBerlekampMassey;
+
 use crate::{
     Integer, 
     Rational, 
-    IntPoly
+    IntPoly,
+    PolyFormat
 };
 use flint_sys::fmpq_types::*;
 use flint_sys::fmpq_poly::*;
@@ -35,6 +47,13 @@ use std::hash::{Hash, Hasher};
 use std::mem::{ManuallyDrop, MaybeUninit};
 
 
+/// A polynomial with rational coefficients.
+///
+/// Neither `Index` nor `IndexMut` is implemented: FLINT stores the
+/// coefficients as integer numerators over a common denominator, so there is
+/// no `Rational` to borrow. Read coefficients with [RatPoly::get_coeff] and
+/// modify them with [RatPoly::set_coeff] or the guard returned by
+/// [RatPoly::coeff_mut].
 #[derive(Debug)]
 pub struct RatPoly {
     inner: fmpq_poly_struct,
@@ -72,67 +91,7 @@ impl Default for RatPoly {
 impl fmt::Display for RatPoly {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let deg = self.degree();
-        if deg < 0 {
-            return write!(f, "0");
-        } else if deg == 0 {
-            return write!(f, "{}", self.get_coeff(0).to_string());
-        }
-
-        let deg: usize = deg.try_into().unwrap();
-        let mut out = String::new();
-        let coeffs = self.get_coeffs();
-
-        let sign = |s| {
-            if s > 0 { " + " }
-            else if s < 0 { " - " }
-            else { unreachable!() }
-        };
-       
-        for (k, c) in coeffs.iter().enumerate().rev() {
-            let s = c.sign();
-            if s == 0 {
-                continue;
-            }
-
-            let abs = c.abs();
-            if k == 0 {
-                out.push_str(&format!("{}{}", sign(s), abs));
-            } else if k == deg {
-                if abs.is_one() && s > 0 {
-                    if k == 1 {
-                        out.push_str("x")
-                    } else {
-                        out.push_str(&format!("x^{}", k));
-                    }
-                } else if abs.is_one() && s < 0 {
-                    if k == 1 {
-                        out.push_str("-x")
-                    } else {
-                        out.push_str(&format!("-x^{}", k));
-                    }
-                } else {
-                    if k == 1 {
-                        out.push_str(&format!("{}*x", c));
-                    } else {
-                        out.push_str(&format!("{}*x^{}", c, k));
-                    }
-                }
-            } else if k == 1 {
-                if abs.is_one() {
-                    out.push_str(&format!("{}x", sign(s)));
-                } else {
-                    out.push_str(&format!("{}{}*x", sign(s), abs));
-                }
-            } else {
-                if abs.is_one() {
-                    out.push_str(&format!("{}x^{}", sign(s), k));
-                } else {
-                    out.push_str(&format!("{}{}*x^{}", sign(s), abs, k));
-                }
-            }
-        }
-        write!(f, "{}", out)
+        write!(f, "{}", self.to_str_format(&PolyFormat::default()))
     }
 }
 
@@ -359,4 +318,23 @@ impl RatPoly {
         }
         res
     }
+
+    /// Return the polynomial printed according to `fmt`.
+    ///
+    /// ```
+    /// use rufl::ratpoly::RatPoly;
+    /// use rufl::rational::Rational;
+    /// use rufl::polyfmt::PolyFormat;
+    ///
+    /// let f = RatPoly::from([Rational::from([1, 2]), Rational::from(-1), Rational::from(0)]);
+    /// let fmt = PolyFormat { var: "t".to_string(), ascending: true, ..Default::default() };
+    /// assert_eq!(f.to_str_format(&fmt), "1/2 - t");
+    /// ```
+    pub fn to_str_format(&self, fmt: &PolyFormat) -> String {
+        let coeffs: Vec<_> = self.get_coeffs()
+            .iter()
+            .map(|c| (c.sign(), c.abs().to_string()))
+            .collect();
+        fmt.format_terms(&coeffs)
+    }
 }
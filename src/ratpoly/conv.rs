@@ -16,9 +16,67 @@
  */
 
 use crate::*;
+use crate::polyfmt::parse_terms;
 use flint_sys::fmpq_poly;
+use std::str::FromStr;
 
 
+/// Parses a polynomial written as a sum of terms `c*x^k` with coefficients
+/// of the form `x` or `x/y`, as printed by `Display` or
+/// [RatPoly::to_str_format]. The notation accepted is the same as for
+/// [IntPoly].
+///
+/// ```
+/// use rufl::rational::Rational;
+/// use rufl::ratpoly::RatPoly;
+///
+/// let f: RatPoly = "3*t^5 - t + 1/2".parse().unwrap();
+/// assert_eq!(f.get_coeff(0), Rational::from([1, 2]));
+/// assert_eq!(f.get_coeff(1), -1);
+/// assert_eq!(f.get_coeff(5), 3);
+/// assert_eq!(f.to_string().parse::<RatPoly>().unwrap(), f);
+///
+/// assert!("1/0*x".parse::<RatPoly>().is_err());
+/// ```
+impl FromStr for RatPoly {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        parse_rat_poly(s, None)
+    }
+}
+
+impl RatPoly {
+    /// Parse a polynomial as for [FromStr], requiring the variable to be
+    /// `var`.
+    ///
+    /// ```
+    /// use rufl::ratpoly::RatPoly;
+    ///
+    /// let f = RatPoly::from_str_var("1/3*z + 1", "z").unwrap();
+    /// assert_eq!(f, RatPoly::from_str_var("1 + 1/3z", "z").unwrap());
+    /// assert!(RatPoly::from_str_var("1/3*x + 1", "z").is_err());
+    /// ```
+    pub fn from_str_var(s: &str, var: &str) -> Result<RatPoly> {
+        parse_rat_poly(s, Some(var))
+    }
+}
+
+fn parse_rat_poly(s: &str, var: Option<&str>) -> Result<RatPoly> {
+    let mut res = RatPoly::zero();
+    for term in parse_terms(s, var)? {
+        let mut c = match term.coeff {
+            Some(c) => Rational::from_str(&c)?,
+            None => Rational::one(),
+        };
+        if term.neg {
+            c.neg_assign();
+        }
+        let c = res.get_coeff(term.exp) + c;
+        res.set_coeff(term.exp, c);
+    }
+    Ok(res)
+}
+
 impl_from_unsafe! {
     None
     RatPoly, u64 {usize u64 u32 u16 u8}
@@ -0,0 +1,87 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Euclidean division and exact division of rational polynomials.
+
+use crate::{Error, RatPoly, Result};
+use flint_sys::fmpq_poly;
+
+impl RatPoly {
+    /// Return the quotient and remainder `(q, r)` with `self = q*rhs + r` and
+    /// `deg(r) < deg(rhs)`. Panics if `rhs` is zero.
+    ///
+    /// ```
+    /// use rufl::ratpoly::RatPoly;
+    /// use rufl::rational::Rational;
+    ///
+    /// let (q, r) = RatPoly::from([1, 0, 1]).div_rem(RatPoly::from([0, 2]));
+    /// assert_eq!(q, RatPoly::from([Rational::from(0), Rational::from([1, 2])]));
+    /// assert_eq!(r, 1);
+    /// ```
+    pub fn div_rem<T: AsRef<RatPoly>>(&self, rhs: T) -> (RatPoly, RatPoly) {
+        let rhs = rhs.as_ref();
+        assert!(!rhs.is_zero(), "Division by zero.");
+        let mut q = RatPoly::default();
+        let mut r = RatPoly::default();
+        unsafe {
+            fmpq_poly::fmpq_poly_divrem(q.as_mut_ptr(), r.as_mut_ptr(), self.as_ptr(), rhs.as_ptr());
+        }
+        (q, r)
+    }
+
+    /// Return the quotient `self/rhs` if `rhs` divides `self` exactly, and
+    /// `None` otherwise. Panics if `rhs` is zero.
+    ///
+    /// ```
+    /// use rufl::ratpoly::RatPoly;
+    /// use rufl::rational::Rational;
+    ///
+    /// let a = RatPoly::from([-4, 0, 1]);
+    /// let q = a.divides(RatPoly::from([4, 2])).unwrap();
+    /// assert_eq!(q, RatPoly::from([Rational::from(-1), Rational::from([1, 2])]));
+    /// assert!(a.divides(RatPoly::from([3, 1])).is_none());
+    /// ```
+    pub fn divides<T: AsRef<RatPoly>>(&self, rhs: T) -> Option<RatPoly> {
+        let (q, r) = self.div_rem(rhs);
+        if r.is_zero() {
+            Some(q)
+        } else {
+            None
+        }
+    }
+
+    /// Return the exact quotient `self/rhs`, or an error if `rhs` is zero or
+    /// does not divide `self`.
+    ///
+    /// ```
+    /// use rufl::ratpoly::RatPoly;
+    ///
+    /// let a = RatPoly::from([-1, 0, 0, 1]);
+    /// assert_eq!(a.div_exact(RatPoly::from([-1, 1])).unwrap(), RatPoly::from([1, 1, 1]));
+    /// assert!(a.div_exact(RatPoly::from([1, 1])).is_err());
+    /// assert!(a.div_exact(RatPoly::zero()).is_err());
+    /// ```
+    pub fn div_exact<T: AsRef<RatPoly>>(&self, rhs: T) -> Result<RatPoly> {
+        let rhs = rhs.as_ref();
+        if rhs.is_zero() {
+            return Err(Error::DivisionError("Division by zero.".to_string()));
+        }
+        self.divides(rhs).ok_or_else(|| Error::DivisionError(
+            "Divisor does not divide the dividend exactly.".to_string()
+        ))
+    }
+}
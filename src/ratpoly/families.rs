@@ -0,0 +1,114 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Classical families of rational polynomials.
+
+use crate::{RatPoly, Rational};
+use flint_sys::{arith, fmpq_poly};
+
+impl RatPoly {
+    /// Return the Legendre polynomial `P_n`.
+    ///
+    /// ```
+    /// use rufl::ratpoly::RatPoly;
+    /// use rufl::rational::Rational;
+    ///
+    /// let p = RatPoly::legendre_p(2);
+    /// assert_eq!(p.get_coeffs(), vec![
+    ///     Rational::from([-1, 2]), Rational::from(0), Rational::from([3, 2])
+    /// ]);
+    /// ```
+    #[inline]
+    pub fn legendre_p(n: u64) -> RatPoly {
+        let mut res = RatPoly::default();
+        unsafe { fmpq_poly::fmpq_poly_legendre_p(res.as_mut_ptr(), n); }
+        res
+    }
+
+    /// Return the Laguerre polynomial `L_n`.
+    ///
+    /// ```
+    /// use rufl::ratpoly::RatPoly;
+    /// use rufl::rational::Rational;
+    ///
+    /// let p = RatPoly::laguerre_l(2);
+    /// assert_eq!(p.get_coeffs(), vec![
+    ///     Rational::from(1), Rational::from(-2), Rational::from([1, 2])
+    /// ]);
+    /// ```
+    #[inline]
+    pub fn laguerre_l(n: u64) -> RatPoly {
+        let mut res = RatPoly::default();
+        unsafe { fmpq_poly::fmpq_poly_laguerre_l(res.as_mut_ptr(), n); }
+        res
+    }
+
+    /// Return the Gegenbauer (ultraspherical) polynomial `C_n^(a)`.
+    ///
+    /// ```
+    /// use rufl::intpoly::IntPoly;
+    /// use rufl::ratpoly::RatPoly;
+    /// use rufl::rational::Rational;
+    ///
+    /// // C_n^(1) is the Chebyshev polynomial U_n.
+    /// let p = RatPoly::gegenbauer_c(4, Rational::from(1));
+    /// assert_eq!(p, RatPoly::from(IntPoly::chebyshev_u(4)));
+    /// ```
+    #[inline]
+    pub fn gegenbauer_c<T: AsRef<Rational>>(n: u64, a: T) -> RatPoly {
+        let mut res = RatPoly::default();
+        unsafe {
+            fmpq_poly::fmpq_poly_gegenbauer_c(res.as_mut_ptr(), n, a.as_ref().as_ptr());
+        }
+        res
+    }
+
+    /// Return the Bernoulli polynomial `B_n`, so that `B_n(0)` is the
+    /// Bernoulli number `B_n`.
+    ///
+    /// ```
+    /// use rufl::ratpoly::RatPoly;
+    /// use rufl::rational::Rational;
+    ///
+    /// let p = RatPoly::bernoulli(2);
+    /// assert_eq!(p.get_coeffs(), vec![
+    ///     Rational::from([1, 6]), Rational::from(-1), Rational::from(1)
+    /// ]);
+    /// ```
+    #[inline]
+    pub fn bernoulli(n: u64) -> RatPoly {
+        let mut res = RatPoly::default();
+        unsafe { arith::arith_bernoulli_polynomial(res.as_mut_ptr(), n); }
+        res
+    }
+
+    /// Return the Euler polynomial `E_n`.
+    ///
+    /// ```
+    /// use rufl::ratpoly::RatPoly;
+    /// use rufl::rational::Rational;
+    ///
+    /// let p = RatPoly::euler(1);
+    /// assert_eq!(p.get_coeffs(), vec![Rational::from([-1, 2]), Rational::from(1)]);
+    /// ```
+    #[inline]
+    pub fn euler(n: u64) -> RatPoly {
+        let mut res = RatPoly::default();
+        unsafe { arith::arith_euler_polynomial(res.as_mut_ptr(), n); }
+        res
+    }
+}
@@ -0,0 +1,94 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! A guard for modifying a single coefficient of a rational polynomial in
+//! place.
+
+use crate::{RatPoly, Rational};
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+
+/// A mutable handle to one coefficient of a [RatPoly], created by
+/// [RatPoly::coeff_mut].
+///
+/// The handle dereferences to a copy of the coefficient, which is written
+/// back when the handle is dropped. The polynomial is put back in canonical
+/// form at that point, with the common denominator and length updated.
+///
+/// This takes the place of `IndexMut`, which [RatPoly] does not implement
+/// since its coefficients cannot be borrowed as `Rational`s.
+pub struct RatPolyCoeffMut<'a> {
+    poly: &'a mut RatPoly,
+    i: usize,
+    value: Rational,
+}
+
+impl Deref for RatPolyCoeffMut<'_> {
+    type Target = Rational;
+    #[inline]
+    fn deref(&self) -> &Rational {
+        &self.value
+    }
+}
+
+impl DerefMut for RatPolyCoeffMut<'_> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Rational {
+        &mut self.value
+    }
+}
+
+impl Drop for RatPolyCoeffMut<'_> {
+    #[inline]
+    fn drop(&mut self) {
+        self.poly.set_coeff(self.i, &self.value);
+    }
+}
+
+impl fmt::Debug for RatPolyCoeffMut<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.value, f)
+    }
+}
+
+impl fmt::Display for RatPolyCoeffMut<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)
+    }
+}
+
+impl RatPoly {
+    /// Return a handle for modifying the coefficient of `x^i` in place. The
+    /// polynomial is canonicalised when the handle is dropped.
+    ///
+    /// ```
+    /// use rufl::ratpoly::RatPoly;
+    /// use rufl::rational::Rational;
+    ///
+    /// let mut f = RatPoly::from([Rational::from([1, 2]), Rational::from([1, 3])]);
+    /// *f.coeff_mut(0) += Rational::from([1, 2]);
+    /// assert_eq!(f.get_coeff(0), 1);
+    ///
+    /// *f.coeff_mut(1) -= Rational::from([1, 3]);
+    /// assert_eq!(f.degree(), 0);
+    /// ```
+    #[inline]
+    pub fn coeff_mut(&mut self, i: usize) -> RatPolyCoeffMut<'_> {
+        let value = self.get_coeff(i);
+        RatPolyCoeffMut { poly: self, i, value }
+    }
+}
@@ -0,0 +1,131 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Interpolation and construction from roots for rational polynomials.
+
+use crate::{Error, RatPoly, Rational, Result};
+use flint_sys::fmpq_poly;
+
+// The polynomial `x - r`.
+fn linear(r: &Rational) -> RatPoly {
+    RatPoly::from([-r, Rational::one()])
+}
+
+impl_subproduct_tree!(RatPoly, Rational, linear);
+
+fn not_distinct() -> Error {
+    Error::DivisionError("Interpolation points must be distinct.".to_string())
+}
+
+impl RatPoly {
+    /// Return the monic polynomial whose roots are `roots`, with
+    /// multiplicity, computed with a product tree.
+    ///
+    /// ```
+    /// use rufl::ratpoly::RatPoly;
+    /// use rufl::rational::Rational;
+    ///
+    /// let f = RatPoly::from_roots(&[Rational::from([1, 2]), Rational::from(-1)]);
+    /// assert_eq!(f, RatPoly::from([Rational::from([-1, 2]), Rational::from([1, 2]), Rational::from(1)]));
+    /// assert_eq!(RatPoly::from_roots(&[]), 1);
+    /// ```
+    pub fn from_roots(roots: &[Rational]) -> RatPoly {
+        if roots.is_empty() {
+            return RatPoly::one();
+        }
+        product_tree(roots).pop().unwrap().pop().unwrap()
+    }
+
+    /// Return the unique polynomial of degree less than the number of points
+    /// passing through `points`, using Newton's divided differences. Returns
+    /// an error if two points share an `x` value.
+    ///
+    /// ```
+    /// use rufl::ratpoly::RatPoly;
+    /// use rufl::rational::Rational;
+    ///
+    /// let points: Vec<(Rational, Rational)> = [(0, 1), (1, 3), (2, 7)].iter()
+    ///     .map(|&(x, y)| (Rational::from(x), Rational::from(y)))
+    ///     .collect();
+    /// let f = RatPoly::interpolate(&points).unwrap();
+    /// assert_eq!(f, RatPoly::from([1, 1, 1]));
+    ///
+    /// let bad = vec![(Rational::from(1), Rational::from(1)); 2];
+    /// assert!(RatPoly::interpolate(&bad).is_err());
+    /// ```
+    pub fn interpolate(points: &[(Rational, Rational)]) -> Result<RatPoly> {
+        let xs: Vec<&Rational> = points.iter().map(|p| &p.0).collect();
+        let n = points.len();
+        let mut c: Vec<Rational> = points.iter().map(|p| p.1.clone()).collect();
+        for j in 1..n {
+            for i in (j..n).rev() {
+                let d = xs[i] - xs[i - j];
+                if d.is_zero() {
+                    return Err(not_distinct());
+                }
+                c[i] = (&c[i] - &c[i - 1]) / d;
+            }
+        }
+
+        let mut res = RatPoly::zero();
+        for i in (0..n).rev() {
+            res = res * linear(xs[i]) + &c[i];
+        }
+        Ok(res)
+    }
+
+    /// Return the same polynomial as [RatPoly::interpolate] using a
+    /// subproduct tree, which is faster for many points.
+    ///
+    /// ```
+    /// use rufl::ratpoly::RatPoly;
+    /// use rufl::rational::Rational;
+    ///
+    /// let points: Vec<(Rational, Rational)> = (0..20)
+    ///     .map(|x| (Rational::from(x), Rational::from([x * x * x - 1, 3])))
+    ///     .collect();
+    /// assert_eq!(
+    ///     RatPoly::interpolate_fast(&points).unwrap(),
+    ///     RatPoly::interpolate(&points).unwrap()
+    /// );
+    ///
+    /// let bad = vec![(Rational::from(1), Rational::from(1)); 2];
+    /// assert!(RatPoly::interpolate_fast(&bad).is_err());
+    /// ```
+    pub fn interpolate_fast(points: &[(Rational, Rational)]) -> Result<RatPoly> {
+        if points.is_empty() {
+            return Ok(RatPoly::zero());
+        }
+        let xs: Vec<Rational> = points.iter().map(|p| p.0.clone()).collect();
+
+        let tree = product_tree(&xs);
+        let root = &tree.last().unwrap()[0];
+        let mut dm = RatPoly::default();
+        unsafe { fmpq_poly::fmpq_poly_derivative(dm.as_mut_ptr(), root.as_ptr()); }
+
+        // m'(x_i) is the product of the differences x_i - x_j, so it vanishes
+        // exactly when x_i is repeated.
+        let mut vals = vec![];
+        for (d, p) in evaluate_tree(&dm, &tree[..tree.len() - 1]).iter().zip(points) {
+            if d.is_zero() {
+                return Err(not_distinct());
+            }
+            vals.push(RatPoly::from(&p.1 / d));
+        }
+        Ok(combine_tree(vals, &tree[..tree.len() - 1]))
+    }
+}
@@ -0,0 +1,67 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! [num-traits](https://docs.rs/num-traits) implementations for [RatPoly].
+
+use crate::RatPoly;
+use num_traits::{One, Pow, Zero};
+
+impl Zero for RatPoly {
+    #[inline]
+    fn zero() -> Self {
+        RatPoly::zero()
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        RatPoly::is_zero(self)
+    }
+}
+
+impl One for RatPoly {
+    #[inline]
+    fn one() -> Self {
+        RatPoly::one()
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        RatPoly::is_one(self)
+    }
+}
+
+macro_rules! impl_pow {
+    ($($t:ident)+) => ($(
+        impl Pow<$t> for RatPoly {
+            type Output = RatPoly;
+            #[inline]
+            fn pow(self, rhs: $t) -> RatPoly {
+                crate::ops::Pow::pow(self, rhs)
+            }
+        }
+
+        impl Pow<$t> for &RatPoly {
+            type Output = RatPoly;
+            #[inline]
+            fn pow(self, rhs: $t) -> RatPoly {
+                crate::ops::Pow::pow(self, rhs)
+            }
+        }
+    )+);
+}
+
+impl_pow! { u64 u32 u16 u8 }
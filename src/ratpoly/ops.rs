@@ -22,8 +22,6 @@ use crate::ratpoly::extras::*;
 use flint_sys::fmpq_poly;
 
 
-// TODO: Rem, Pow
-
 impl_assign_unsafe! {
     None
     RatPoly, RatPoly
@@ -321,6 +319,12 @@ impl_binop_unsafe! {
     AssignMul {assign_mul}
     fmpq_poly::fmpq_poly_mul;
     
+    Div {div}
+    DivAssign {div_assign}
+    DivFrom {div_from}
+    AssignDiv {assign_div}
+    fmpq_poly::fmpq_poly_div;
+    
     Rem {rem}
     RemAssign {rem_assign}
     RemFrom {rem_from}
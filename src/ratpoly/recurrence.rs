@@ -0,0 +1,75 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Minimal linear recurrences of rational sequences.
+
+use crate::{RatPoly, Rational};
+
+impl_berlekamp_massey! {
+    /// An incremental Berlekamp–Massey computation of the shortest linear
+    /// recurrence satisfied by a sequence of rationals.
+    ///
+    /// After feeding `s_0, ..., s_{N-1}`, the connection polynomial
+    /// `C(x) = 1 + c_1 x + ... + c_L x^L` satisfies
+    /// `s_n + c_1 s_{n-1} + ... + c_L s_{n-L} = 0` for `L <= n < N`, where `L` is
+    /// the linear complexity of the sequence. The recurrence is determined
+    /// uniquely once `N >= 2L`.
+    ///
+    /// ```
+    /// use rufl::rational::Rational;
+    /// use rufl::ratpoly::{RatBerlekampMassey, RatPoly};
+    ///
+    /// // Guess a recurrence for a(n) = 2^n + n.
+    /// let mut bm = RatBerlekampMassey::new();
+    /// bm.extend((0..8u64).map(|n| Rational::from((1u64 << n) + n)));
+    /// assert_eq!(bm.linear_complexity(), 3);
+    /// assert_eq!(bm.connection_polynomial(), RatPoly::from([1, -4, 5, -2]));
+    /// ```
+    RatBerlekampMassey, Rational, RatPoly
+    zero_poly: |_zero| RatPoly::zero();
+}
+
+impl Default for RatBerlekampMassey {
+    fn default() -> Self {
+        RatBerlekampMassey::new()
+    }
+}
+
+impl RatBerlekampMassey {
+    /// Start a computation with no terms.
+    pub fn new() -> Self {
+        RatBerlekampMassey::start(Rational::zero(), Rational::one())
+    }
+}
+
+impl RatPoly {
+    /// Return the connection polynomial of the shortest linear recurrence
+    /// satisfied by `seq`, computed with [RatBerlekampMassey].
+    ///
+    /// ```
+    /// use rufl::rational::Rational;
+    /// use rufl::ratpoly::RatPoly;
+    ///
+    /// let seq: Vec<Rational> = [1, 2, 4, 8, 16].iter().map(|&s| Rational::from(s)).collect();
+    /// assert_eq!(RatPoly::berlekamp_massey(&seq), RatPoly::from([1, -2]));
+    /// ```
+    pub fn berlekamp_massey(seq: &[Rational]) -> RatPoly {
+        let mut bm = RatBerlekampMassey::new();
+        bm.extend(seq);
+        bm.connection_polynomial()
+    }
+}
@@ -0,0 +1,273 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Truncated power series with rational coefficients.
+
+mod ops;
+mod conv;
+
+use crate::{RatPoly, Rational};
+use crate::intseries::prec_si;
+use flint_sys::fmpq_poly;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// A power series `f + O(x^n)` over the rationals, stored as the polynomial
+/// `f` of length at most `n` together with the precision `n`.
+///
+/// Arithmetic tracks precision in the same way as
+/// [IntSeries](crate::IntSeries).
+///
+/// ```
+/// use rufl::ratseries::RatSeries;
+/// use rufl::rational::Rational;
+///
+/// let x = RatSeries::gen(8);
+/// let s = x.exp().log();
+/// assert_eq!(s, x);
+///
+/// // atan(x) = x - x^3/3 + x^5/5 - ...
+/// let a = x.atan();
+/// assert_eq!(a.get_coeff(5), Rational::from([1, 5]));
+/// ```
+#[derive(Clone, Debug)]
+pub struct RatSeries {
+    poly: RatPoly,
+    prec: usize,
+}
+
+impl AsRef<RatSeries> for RatSeries {
+    fn as_ref(&self) -> &RatSeries {
+        self
+    }
+}
+
+impl fmt::Display for RatSeries {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.poly.is_zero() {
+            write!(f, "O(x^{})", self.prec)
+        } else {
+            write!(f, "{} + O(x^{})", self.poly, self.prec)
+        }
+    }
+}
+
+impl Hash for RatSeries {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.poly.hash(state);
+        self.prec.hash(state);
+    }
+}
+
+// A unary series function from `fmpq_poly`.
+type SeriesFn = unsafe extern "C" fn(
+    *mut flint_sys::fmpq_types::fmpq_poly_struct,
+    *const flint_sys::fmpq_types::fmpq_poly_struct,
+    i64
+);
+
+impl RatSeries {
+    /// Construct the series `poly + O(x^prec)`, truncating `poly`. Panics if
+    /// the precision is zero.
+    #[inline]
+    pub fn new<T: Into<RatPoly>>(poly: T, prec: usize) -> Self {
+        assert!(prec > 0, "Precision must be positive.");
+        let mut poly = poly.into();
+        unsafe { fmpq_poly::fmpq_poly_truncate(poly.as_mut_ptr(), prec_si(prec)); }
+        RatSeries { poly, prec }
+    }
+
+    /// The zero series `O(x^prec)`.
+    #[inline]
+    pub fn zero(prec: usize) -> Self {
+        RatSeries::new(RatPoly::zero(), prec)
+    }
+
+    /// The series `1 + O(x^prec)`.
+    #[inline]
+    pub fn one(prec: usize) -> Self {
+        RatSeries::new(RatPoly::one(), prec)
+    }
+
+    /// The series `x + O(x^prec)`.
+    #[inline]
+    pub fn gen(prec: usize) -> Self {
+        RatSeries::new([0, 1], prec)
+    }
+
+    /// Return the precision `n` of the series `f + O(x^n)`.
+    #[inline]
+    pub fn prec(&self) -> usize {
+        self.prec
+    }
+
+    /// Return a reference to the underlying polynomial.
+    #[inline]
+    pub fn poly(&self) -> &RatPoly {
+        &self.poly
+    }
+
+    /// Return the underlying polynomial, discarding the precision.
+    #[inline]
+    pub fn into_poly(self) -> RatPoly {
+        self.poly
+    }
+
+    /// Return true if the series is zero to its precision.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.poly.is_zero()
+    }
+
+    /// Return the index of the first nonzero coefficient, or the precision
+    /// if the series is zero to its precision.
+    pub fn valuation(&self) -> usize {
+        (0..self.poly.len())
+            .find(|&i| !self.poly.get_coeff(i).is_zero())
+            .unwrap_or(self.prec)
+    }
+
+    /// Return the coefficient of `x^i`. Panics if `i` is not below the
+    /// precision.
+    #[inline]
+    pub fn get_coeff(&self, i: usize) -> Rational {
+        assert!(i < self.prec, "Coefficient is beyond the precision of the series.");
+        self.poly.get_coeff(i)
+    }
+
+    /// Return the coefficients of `x^0, ..., x^(n-1)`, where `n` is the
+    /// precision.
+    #[inline]
+    pub fn get_coeffs(&self) -> Vec<Rational> {
+        (0..self.prec).map(|i| self.poly.get_coeff(i)).collect()
+    }
+
+    /// Reduce the precision of the series to `prec`. Does nothing if `prec`
+    /// is not smaller than the current precision.
+    #[inline]
+    pub fn truncate(&mut self, prec: usize) {
+        if prec < self.prec {
+            assert!(prec > 0, "Precision must be positive.");
+            unsafe { fmpq_poly::fmpq_poly_truncate(self.poly.as_mut_ptr(), prec_si(prec)); }
+            self.prec = prec;
+        }
+    }
+
+    // Apply a FLINT series function at the precision of `self`.
+    fn apply(&self, func: SeriesFn) -> RatSeries {
+        let mut res = RatPoly::default();
+        unsafe { func(res.as_mut_ptr(), self.poly.as_ptr(), prec_si(self.prec)); }
+        RatSeries { poly: res, prec: self.prec }
+    }
+
+    /// Return the multiplicative inverse of the series. Panics if the
+    /// constant term is zero.
+    #[inline]
+    pub fn inv(&self) -> RatSeries {
+        assert!(!self.poly.get_coeff(0).is_zero(), "Constant term must be nonzero.");
+        self.apply(fmpq_poly::fmpq_poly_inv_series)
+    }
+
+    /// Return the square root of the series. Panics unless the constant term
+    /// is one.
+    ///
+    /// ```
+    /// use rufl::ratseries::RatSeries;
+    /// use rufl::rational::Rational;
+    ///
+    /// // sqrt(1 + x) = 1 + x/2 - x^2/8 + ...
+    /// let s = RatSeries::new([1, 1], 3).sqrt();
+    /// assert_eq!(s.get_coeffs(), vec![
+    ///     Rational::from(1), Rational::from([1, 2]), Rational::from([-1, 8])
+    /// ]);
+    /// ```
+    #[inline]
+    pub fn sqrt(&self) -> RatSeries {
+        assert!(self.poly.get_coeff(0).is_one(), "Constant term must be one.");
+        self.apply(fmpq_poly::fmpq_poly_sqrt_series)
+    }
+
+    /// Return the exponential of the series. Panics unless the constant term
+    /// is zero.
+    #[inline]
+    pub fn exp(&self) -> RatSeries {
+        assert!(self.poly.get_coeff(0).is_zero(), "Constant term must be zero.");
+        self.apply(fmpq_poly::fmpq_poly_exp_series)
+    }
+
+    /// Return the logarithm of the series. Panics unless the constant term
+    /// is one.
+    #[inline]
+    pub fn log(&self) -> RatSeries {
+        assert!(self.poly.get_coeff(0).is_one(), "Constant term must be one.");
+        self.apply(fmpq_poly::fmpq_poly_log_series)
+    }
+
+    /// Return the arctangent of the series. Panics unless the constant term
+    /// is zero.
+    #[inline]
+    pub fn atan(&self) -> RatSeries {
+        assert!(self.poly.get_coeff(0).is_zero(), "Constant term must be zero.");
+        self.apply(fmpq_poly::fmpq_poly_atan_series)
+    }
+
+    /// Return the composition `self(other)`. Panics unless the constant term
+    /// of `other` is zero.
+    ///
+    /// The result is known to precision `min(n_f v_g, n_g)`, where `v_g` is
+    /// the valuation of `other`.
+    pub fn compose<T: AsRef<RatSeries>>(&self, other: T) -> RatSeries {
+        let other = other.as_ref();
+        assert!(
+            other.poly.get_coeff(0).is_zero(),
+            "Inner series must have zero constant term."
+        );
+        let prec = std::cmp::min(
+            self.prec.saturating_mul(other.valuation()),
+            other.prec
+        );
+        let mut res = RatPoly::default();
+        unsafe {
+            fmpq_poly::fmpq_poly_compose_series(
+                res.as_mut_ptr(),
+                self.poly.as_ptr(),
+                other.poly.as_ptr(),
+                prec_si(prec)
+            );
+        }
+        RatSeries { poly: res, prec }
+    }
+
+    /// Return the compositional inverse `g` of the series `f`, so that
+    /// `f(g) = g(f) = x`. Panics unless the constant term is zero and the
+    /// coefficient of `x` is nonzero.
+    ///
+    /// ```
+    /// use rufl::ratseries::RatSeries;
+    ///
+    /// // The inverse of exp(x) - 1 is log(1 + x).
+    /// let x = RatSeries::gen(6);
+    /// let f = &x.exp() - RatSeries::one(6);
+    /// assert_eq!(f.revert(), (&x + RatSeries::one(6)).log());
+    /// ```
+    #[inline]
+    pub fn revert(&self) -> RatSeries {
+        assert!(self.poly.get_coeff(0).is_zero(), "Constant term must be zero.");
+        assert!(!self.poly.get_coeff(1).is_zero(), "Linear coefficient must be nonzero.");
+        self.apply(fmpq_poly::fmpq_poly_revert_series)
+    }
+}
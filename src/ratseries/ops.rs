@@ -0,0 +1,118 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::{RatPoly, RatSeries};
+use crate::intseries::prec_si;
+use crate::ops::*;
+use flint_sys::fmpq_poly;
+use std::cmp::min;
+
+impl_cmp! {
+    eq
+    RatSeries
+    {
+        fn eq(&self, rhs: &RatSeries) -> bool {
+            self.prec() == rhs.prec() && self.poly() == rhs.poly()
+        }
+    }
+}
+
+impl_unop! {
+    RatSeries
+    Neg {neg}
+    {
+        fn neg(self) -> RatSeries {
+            RatSeries::new(-self.poly(), self.prec())
+        }
+    }
+    NegAssign {neg_assign}
+    {
+        fn neg_assign(&mut self) {
+            *self = -&*self;
+        }
+    }
+}
+
+fn add(lhs: &RatSeries, rhs: &RatSeries) -> RatSeries {
+    RatSeries::new(lhs.poly() + rhs.poly(), min(lhs.prec(), rhs.prec()))
+}
+
+fn sub(lhs: &RatSeries, rhs: &RatSeries) -> RatSeries {
+    RatSeries::new(lhs.poly() - rhs.poly(), min(lhs.prec(), rhs.prec()))
+}
+
+fn mul(lhs: &RatSeries, rhs: &RatSeries) -> RatSeries {
+    let prec = min(
+        lhs.prec() + rhs.valuation(),
+        rhs.prec() + lhs.valuation()
+    );
+    let mut res = RatPoly::default();
+    unsafe {
+        fmpq_poly::fmpq_poly_mullow(
+            res.as_mut_ptr(),
+            lhs.poly().as_ptr(),
+            rhs.poly().as_ptr(),
+            prec_si(prec)
+        );
+    }
+    RatSeries::new(res, prec)
+}
+
+fn div(lhs: &RatSeries, rhs: &RatSeries) -> RatSeries {
+    assert!(
+        !rhs.poly().get_coeff(0).is_zero(),
+        "Constant term of the divisor must be nonzero."
+    );
+    let prec = min(lhs.prec(), rhs.prec() + lhs.valuation());
+    let mut res = RatPoly::default();
+    unsafe {
+        fmpq_poly::fmpq_poly_div_series(
+            res.as_mut_ptr(),
+            lhs.poly().as_ptr(),
+            rhs.poly().as_ptr(),
+            prec_si(prec)
+        );
+    }
+    RatSeries::new(res, prec)
+}
+
+impl_binop_series! {
+    RatSeries
+    Add {add}
+    AddAssign {add_assign}
+    AddFrom {add_from}
+    AssignAdd {assign_add}
+    add;
+
+    Sub {sub}
+    SubAssign {sub_assign}
+    SubFrom {sub_from}
+    AssignSub {assign_sub}
+    sub;
+
+    Mul {mul}
+    MulAssign {mul_assign}
+    MulFrom {mul_from}
+    AssignMul {assign_mul}
+    mul;
+
+    Div {div}
+    DivAssign {div_assign}
+    DivFrom {div_from}
+    AssignDiv {assign_div}
+    div;
+}
@@ -0,0 +1,253 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Sparse matrices over the integers, stored in compressed sparse row (CSR)
+//! form.
+
+mod conv;
+
+use crate::Integer;
+
+/// A sparse matrix over the integers in compressed sparse row form.
+///
+/// Only the nonzero entries are stored, row by row and with increasing
+/// column indices within each row, so two matrices with the same entries
+/// compare equal regardless of how they were built.
+///
+/// ```
+/// use rufl::sparseintmat::SparseIntMat;
+/// use rufl::integer::Integer;
+///
+/// // Duplicate entries are summed and zeros are dropped.
+/// let m = SparseIntMat::from_triplets(3, 4, [(0, 1, 2), (2, 3, -1), (0, 1, 3), (1, 0, 0)]);
+/// assert_eq!(m.nnz(), 2);
+/// assert_eq!(m.get_entry(0, 1), 5);
+/// assert!(m.get_entry(1, 0).is_zero());
+///
+/// let x = [1, 1, 1, 1].map(Integer::from);
+/// assert_eq!(m.mul_vec(&x), [5, 0, -1].map(Integer::from));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SparseIntMat {
+    nrows: usize,
+    ncols: usize,
+    // The nonzero entries of row `i` are at positions `row_ptr[i]..row_ptr[i + 1]`
+    // of `cols` and `values`.
+    row_ptr: Vec<usize>,
+    cols: Vec<usize>,
+    values: Vec<Integer>,
+}
+
+impl SparseIntMat {
+    /// Return the `nrows` by `ncols` zero matrix.
+    pub fn zero(nrows: usize, ncols: usize) -> SparseIntMat {
+        SparseIntMat {
+            nrows,
+            ncols,
+            row_ptr: vec![0; nrows + 1],
+            cols: vec![],
+            values: vec![],
+        }
+    }
+
+    /// Construct a matrix from `(row, column, value)` triplets in any order.
+    /// Values at the same position are summed and zero entries are dropped.
+    /// Panics if a position is out of bounds.
+    pub fn from_triplets<I, T>(nrows: usize, ncols: usize, entries: I) -> SparseIntMat
+    where
+        I: IntoIterator<Item = (usize, usize, T)>,
+        T: Into<Integer>
+    {
+        let mut entries: Vec<(usize, usize, Integer)> = entries
+            .into_iter()
+            .map(|(i, j, x)| {
+                assert!(i < nrows && j < ncols, "Entry ({}, {}) is out of bounds.", i, j);
+                (i, j, x.into())
+            })
+            .collect();
+        entries.sort_by_key(|e| (e.0, e.1));
+
+        let mut merged: Vec<(usize, usize, Integer)> = Vec::with_capacity(entries.len());
+        for (i, j, x) in entries {
+            match merged.last_mut() {
+                Some((a, b, y)) if (*a, *b) == (i, j) => *y += x,
+                _ => merged.push((i, j, x)),
+            }
+        }
+        SparseIntMat::from_sorted(
+            nrows,
+            ncols,
+            merged.into_iter().filter(|e| !e.2.is_zero())
+        )
+    }
+
+    // Build a matrix from nonzero triplets sorted by row and then column,
+    // with no repeated positions.
+    pub(crate) fn from_sorted<I>(nrows: usize, ncols: usize, entries: I) -> SparseIntMat
+    where
+        I: IntoIterator<Item = (usize, usize, Integer)>
+    {
+        let mut res = SparseIntMat::zero(nrows, ncols);
+        for (i, j, x) in entries {
+            res.row_ptr[i + 1] += 1;
+            res.cols.push(j);
+            res.values.push(x);
+        }
+        let mut total = 0;
+        for p in res.row_ptr.iter_mut() {
+            total += *p;
+            *p = total;
+        }
+        res
+    }
+
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    /// Return the number of stored (nonzero) entries.
+    #[inline]
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    #[inline]
+    pub fn is_square(&self) -> bool {
+        self.nrows == self.ncols
+    }
+
+    /// Return the number of nonzero entries in row `i`.
+    #[inline]
+    pub fn row_weight(&self, i: usize) -> usize {
+        assert!(i < self.nrows);
+        self.row_ptr[i + 1] - self.row_ptr[i]
+    }
+
+    /// Return the `(i, j)`-th entry, found by binary search within row `i`.
+    pub fn get_entry(&self, i: usize, j: usize) -> Integer {
+        assert!(i < self.nrows && j < self.ncols);
+        let (start, end) = (self.row_ptr[i], self.row_ptr[i + 1]);
+        match self.cols[start..end].binary_search(&j) {
+            Ok(k) => self.values[start + k].clone(),
+            Err(_) => Integer::default(),
+        }
+    }
+
+    /// Return an iterator over the nonzero entries of row `i` as
+    /// `(column, value)` pairs, in increasing column order.
+    ///
+    /// ```
+    /// use rufl::sparseintmat::SparseIntMat;
+    ///
+    /// let m = SparseIntMat::from_triplets(2, 5, [(1, 4, 7), (1, 0, -2)]);
+    /// let row: Vec<_> = m.iter_row(1).map(|(j, x)| (j, x.clone())).collect();
+    /// assert_eq!(row, [(0, (-2).into()), (4, 7.into())]);
+    /// ```
+    pub fn iter_row(&self, i: usize)
+        -> impl ExactSizeIterator<Item = (usize, &Integer)> + DoubleEndedIterator + '_
+    {
+        assert!(i < self.nrows);
+        let (start, end) = (self.row_ptr[i], self.row_ptr[i + 1]);
+        self.cols[start..end].iter().copied().zip(&self.values[start..end])
+    }
+
+    /// Return an iterator over the nonzero entries as `(row, column, value)`
+    /// triplets in row-major order.
+    pub fn iter_entries(&self) -> impl Iterator<Item = (usize, usize, &Integer)> + '_ {
+        (0..self.nrows).flat_map(move |i| self.iter_row(i).map(move |(j, x)| (i, j, x)))
+    }
+
+    /// Return the transpose of the matrix.
+    ///
+    /// ```
+    /// use rufl::sparseintmat::SparseIntMat;
+    ///
+    /// let m = SparseIntMat::from_triplets(2, 3, [(0, 2, 1), (1, 0, 4), (1, 2, 5)]);
+    /// let t = m.transpose();
+    /// assert_eq!(t, SparseIntMat::from_triplets(3, 2, [(2, 0, 1), (0, 1, 4), (2, 1, 5)]));
+    /// assert_eq!(t.transpose(), m);
+    /// ```
+    pub fn transpose(&self) -> SparseIntMat {
+        // Counting sort on the column indices. Rows are visited in order, so
+        // the entries of each row of the transpose come out sorted.
+        let mut row_ptr = vec![0; self.ncols + 1];
+        for &j in self.cols.iter() {
+            row_ptr[j + 1] += 1;
+        }
+        let mut total = 0;
+        for p in row_ptr.iter_mut() {
+            total += *p;
+            *p = total;
+        }
+
+        let mut next = row_ptr.clone();
+        let mut cols = vec![0; self.nnz()];
+        let mut values = vec![Integer::default(); self.nnz()];
+        for (i, j, x) in self.iter_entries() {
+            let k = next[j];
+            cols[k] = i;
+            values[k] = x.clone();
+            next[j] += 1;
+        }
+        SparseIntMat { nrows: self.ncols, ncols: self.nrows, row_ptr, cols, values }
+    }
+
+    /// Return the product `Ax` of the matrix with the column vector `x`.
+    pub fn mul_vec(&self, x: &[Integer]) -> Vec<Integer> {
+        assert_eq!(x.len(), self.ncols);
+        (0..self.nrows)
+            .map(|i| {
+                let mut s = Integer::default();
+                for (j, a) in self.iter_row(i) {
+                    s.addmul_mut(a, &x[j]);
+                }
+                s
+            })
+            .collect()
+    }
+
+    /// Return the product `A^T y` of the transpose of the matrix with the
+    /// column vector `y`, without forming the transpose.
+    ///
+    /// ```
+    /// use rufl::sparseintmat::SparseIntMat;
+    /// use rufl::integer::Integer;
+    ///
+    /// let m = SparseIntMat::from_triplets(2, 3, [(0, 0, 1), (0, 2, 2), (1, 2, 3)]);
+    /// let y = [1, -1].map(Integer::from);
+    /// assert_eq!(m.transpose_mul_vec(&y), m.transpose().mul_vec(&y));
+    /// ```
+    pub fn transpose_mul_vec(&self, y: &[Integer]) -> Vec<Integer> {
+        assert_eq!(y.len(), self.nrows);
+        let mut res = vec![Integer::default(); self.ncols];
+        for (i, j, a) in self.iter_entries() {
+            res[j].addmul_mut(a, &y[i]);
+        }
+        res
+    }
+}
@@ -0,0 +1,59 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::{IntMat, SparseIntMat, Integer};
+
+impl_from! {
+    SparseIntMat, IntMat
+    {
+        /// Keep the nonzero entries of a dense matrix.
+        ///
+        /// ```
+        /// use rufl::intmat::IntMat;
+        /// use rufl::sparseintmat::SparseIntMat;
+        ///
+        /// let m = IntMat::new([0, 3, 0, 0, -1, 2], 2, 3);
+        /// let s = SparseIntMat::from(&m);
+        /// assert_eq!(s.nnz(), 3);
+        /// assert_eq!(IntMat::from(&s), m);
+        /// ```
+        fn from(x: &IntMat) -> SparseIntMat {
+            let entries = (0..x.nrows())
+                .flat_map(|i| (0..x.ncols()).map(move |j| (i, j)))
+                .map(|(i, j)| (i, j, x.entry(i, j)))
+                .filter(|(_, _, e)| !e.is_zero())
+                .map(|(i, j, e)| (i, j, Integer::from(e)));
+            SparseIntMat::from_sorted(x.nrows(), x.ncols(), entries)
+        }
+    }
+}
+
+impl_from! {
+    IntMat, SparseIntMat
+    {
+        fn from(x: &SparseIntMat) -> IntMat {
+            let mut res = IntMat::zero(
+                x.nrows().try_into().expect("Cannot convert usize to a signed long."),
+                x.ncols().try_into().expect("Cannot convert usize to a signed long.")
+            );
+            for (i, j, e) in x.iter_entries() {
+                res.set_entry(i, j, e);
+            }
+            res
+        }
+    }
+}
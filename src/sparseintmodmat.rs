@@ -0,0 +1,197 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Sparse matrices over the integers modulo `n`, stored in compressed sparse
+//! row (CSR) form, with sparse linear algebra over prime fields.
+
+mod conv;
+mod dense;
+mod solve;
+mod prune;
+mod wiedemann;
+mod lanczos;
+
+use crate::*;
+
+/// A sparse matrix over the integers modulo `n`.
+///
+/// The entries are stored as a [SparseIntMat] of residues in `[0, n)`,
+/// with zero residues dropped. Products with vectors accumulate over the
+/// integers and reduce once per entry of the result.
+///
+/// ```
+/// use rufl::intmod::{IntMod, IntModCtx};
+/// use rufl::sparseintmodmat::SparseIntModMat;
+///
+/// let ctx = IntModCtx::new(7);
+/// let m = SparseIntModMat::from_triplets(2, 3, [(0, 0, 3), (0, 2, 5), (1, 1, 14)], &ctx);
+/// assert_eq!(m.nnz(), 2);
+///
+/// let x = [1, 1, 1].map(|c| IntMod::new(c, &ctx));
+/// assert_eq!(m.mul_vec(&x), [1, 0].map(|c| IntMod::new(c, &ctx)));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SparseIntModMat {
+    inner: SparseIntMat,
+    ctx: IntModCtx,
+}
+
+impl NewCtx<&SparseIntMat, IntModCtx> for SparseIntModMat {
+    /// Reduce the entries of an integer matrix modulo `n`.
+    fn new(src: &SparseIntMat, ctx: &IntModCtx) -> Self {
+        let entries = src.iter_entries()
+            .map(|(i, j, x)| (i, j, Integer::from(&IntMod::new(x.clone(), ctx))))
+            .filter(|e| !e.2.is_zero());
+        SparseIntModMat {
+            inner: SparseIntMat::from_sorted(src.nrows(), src.ncols(), entries),
+            ctx: ctx.clone(),
+        }
+    }
+}
+
+impl NewCtx<SparseIntMat, IntModCtx> for SparseIntModMat {
+    #[inline]
+    fn new(src: SparseIntMat, ctx: &IntModCtx) -> Self {
+        SparseIntModMat::new(&src, ctx)
+    }
+}
+
+impl NewCtx<&IntMat, IntModCtx> for SparseIntModMat {
+    /// Reduce the entries of a dense integer matrix modulo `n`.
+    #[inline]
+    fn new(src: &IntMat, ctx: &IntModCtx) -> Self {
+        SparseIntModMat::new(&SparseIntMat::from(src), ctx)
+    }
+}
+
+impl SparseIntModMat {
+    /// Return the `nrows` by `ncols` zero matrix.
+    #[inline]
+    pub fn zero(nrows: usize, ncols: usize, ctx: &IntModCtx) -> SparseIntModMat {
+        SparseIntModMat { inner: SparseIntMat::zero(nrows, ncols), ctx: ctx.clone() }
+    }
+
+    /// Construct a matrix from `(row, column, value)` triplets in any order.
+    /// Values at the same position are summed, the sums are reduced modulo
+    /// `n`, and zero entries are dropped. Panics if a position is out of
+    /// bounds.
+    pub fn from_triplets<I, T>(
+        nrows: usize,
+        ncols: usize,
+        entries: I,
+        ctx: &IntModCtx
+    ) -> SparseIntModMat
+    where
+        I: IntoIterator<Item = (usize, usize, T)>,
+        T: Into<Integer>
+    {
+        SparseIntModMat::new(&SparseIntMat::from_triplets(nrows, ncols, entries), ctx)
+    }
+
+    #[inline]
+    pub fn context(&self) -> &IntModCtx {
+        &self.ctx
+    }
+
+    #[inline]
+    pub fn modulus(&self) -> Integer {
+        self.context().modulus()
+    }
+
+    /// Return the matrix of residues in `[0, n)`, sharing its storage.
+    #[inline]
+    pub fn residues(&self) -> &SparseIntMat {
+        &self.inner
+    }
+
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.inner.nrows()
+    }
+
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.inner.ncols()
+    }
+
+    /// Return the number of stored (nonzero) entries.
+    #[inline]
+    pub fn nnz(&self) -> usize {
+        self.inner.nnz()
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.inner.is_zero()
+    }
+
+    #[inline]
+    pub fn is_square(&self) -> bool {
+        self.inner.is_square()
+    }
+
+    /// Return the number of nonzero entries in row `i`.
+    #[inline]
+    pub fn row_weight(&self, i: usize) -> usize {
+        self.inner.row_weight(i)
+    }
+
+    /// Return the `(i, j)`-th entry.
+    #[inline]
+    pub fn get_entry(&self, i: usize, j: usize) -> IntMod {
+        IntMod::new(self.inner.get_entry(i, j), &self.ctx)
+    }
+
+    /// Return an iterator over the nonzero entries of row `i` as
+    /// `(column, value)` pairs, in increasing column order.
+    pub fn iter_row(&self, i: usize) -> impl ExactSizeIterator<Item = (usize, IntMod)> + '_ {
+        self.inner.iter_row(i).map(move |(j, x)| (j, IntMod::new(x.clone(), &self.ctx)))
+    }
+
+    /// Return the transpose of the matrix.
+    #[inline]
+    pub fn transpose(&self) -> SparseIntModMat {
+        SparseIntModMat { inner: self.inner.transpose(), ctx: self.ctx.clone() }
+    }
+
+    // Lift a vector of residues to the integers, checking the contexts.
+    fn lift_vec(&self, x: &[IntMod]) -> Vec<Integer> {
+        x.iter()
+            .map(|c| {
+                assert_eq!(c.context(), &self.ctx);
+                Integer::from(c)
+            })
+            .collect()
+    }
+
+    /// Return the product `Ax` of the matrix with the column vector `x`.
+    pub fn mul_vec(&self, x: &[IntMod]) -> Vec<IntMod> {
+        self.inner.mul_vec(&self.lift_vec(x))
+            .into_iter()
+            .map(|s| IntMod::new(s, &self.ctx))
+            .collect()
+    }
+
+    /// Return the product `A^T y` of the transpose of the matrix with the
+    /// column vector `y`, without forming the transpose.
+    pub fn transpose_mul_vec(&self, y: &[IntMod]) -> Vec<IntMod> {
+        self.inner.transpose_mul_vec(&self.lift_vec(y))
+            .into_iter()
+            .map(|s| IntMod::new(s, &self.ctx))
+            .collect()
+    }
+}
@@ -0,0 +1,87 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::{IntMat, IntModWord, IntModWordCtx, IntModWordMat, SparseIntMat, SparseIntModMat};
+
+impl_from! {
+    SparseIntMat, SparseIntModMat
+    {
+        /// Lift each entry to its residue in `[0, n)`.
+        fn from(x: &SparseIntModMat) -> SparseIntMat {
+            x.residues().clone()
+        }
+    }
+}
+
+impl_from! {
+    IntMat, SparseIntModMat
+    {
+        /// Return the dense matrix of residues in `[0, n)`.
+        ///
+        /// ```
+        /// use rufl::intmat::IntMat;
+        /// use rufl::intmod::IntModCtx;
+        /// use rufl::ops::NewCtx;
+        /// use rufl::sparseintmodmat::SparseIntModMat;
+        ///
+        /// let ctx = IntModCtx::new(5);
+        /// let m = SparseIntModMat::new(&IntMat::new([0, -1, 7, 0], 2, 2), &ctx);
+        /// assert_eq!(IntMat::from(&m), IntMat::new([0, 4, 2, 0], 2, 2));
+        /// ```
+        fn from(x: &SparseIntModMat) -> IntMat {
+            IntMat::from(x.residues())
+        }
+    }
+}
+
+impl_tryfrom! {
+    IntModWordMat, SparseIntModMat
+    {
+        /// Return the dense word-size matrix, or an error if the modulus does
+        /// not fit in a `u64`.
+        ///
+        /// ```
+        /// use rufl::integer::Integer;
+        /// use rufl::intmat::IntMat;
+        /// use rufl::intmod::IntModCtx;
+        /// use rufl::intmodwordmat::IntModWordMat;
+        /// use rufl::ops::NewCtx;
+        /// use rufl::sparseintmodmat::SparseIntModMat;
+        ///
+        /// let ctx = IntModCtx::new(5);
+        /// let m = SparseIntModMat::new(&IntMat::new([0, -1, 7, 0], 2, 2), &ctx);
+        /// let d = IntModWordMat::try_from(&m).unwrap();
+        /// assert_eq!(d.get_entries_ui(), vec![0, 4, 2, 0]);
+        ///
+        /// let ctx = IntModCtx::new(Integer::from(u64::MAX) + 2u64);
+        /// let m = SparseIntModMat::zero(2, 2, &ctx);
+        /// assert!(IntModWordMat::try_from(&m).is_err());
+        /// ```
+        fn try_from(x: &SparseIntModMat) -> Result<Self, Self::Error> {
+            let Some(n) = x.modulus().get_ui() else {
+                return Err("SparseIntModMat modulus does not fit in a word.");
+            };
+            let ctx = IntModWordCtx::new(n);
+            let mut res = IntModWordMat::zero(x.nrows() as i64, x.ncols() as i64, &ctx);
+            for (i, j, e) in x.residues().iter_entries() {
+                // Residues lie in [0, n), so they fit whenever n does.
+                res.set_entry(i, j, IntModWord::from_ui(e.get_ui().unwrap(), &ctx));
+            }
+            Ok(res)
+        }
+    }
+}
@@ -0,0 +1,311 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Residue arithmetic and small dense matrices shared by the sparse solvers:
+//! the dense core of structured Gaussian elimination and the blocks of
+//! vectors in block Wiedemann and block Lanczos.
+
+use crate::*;
+use flint_sys::fmpz_mod;
+
+pub(super) fn mod_reduce(a: &Integer, ctx: &IntModCtx) -> Integer {
+    let mut res = Integer::default();
+    unsafe {
+        fmpz_mod::fmpz_mod_set_fmpz(res.as_mut_ptr(), a.as_ptr(), ctx.as_ptr());
+    }
+    res
+}
+
+pub(super) fn mod_mul(a: &Integer, b: &Integer, ctx: &IntModCtx) -> Integer {
+    let mut res = Integer::default();
+    unsafe {
+        fmpz_mod::fmpz_mod_mul(res.as_mut_ptr(), a.as_ptr(), b.as_ptr(), ctx.as_ptr());
+    }
+    res
+}
+
+// Return `a - c*b` reduced modulo `n`.
+pub(super) fn mod_submul(a: &Integer, c: &Integer, b: &Integer, ctx: &IntModCtx) -> Integer {
+    let mut res = mod_mul(c, b, ctx);
+    unsafe {
+        fmpz_mod::fmpz_mod_sub(res.as_mut_ptr(), a.as_ptr(), res.as_ptr(), ctx.as_ptr());
+    }
+    res
+}
+
+pub(super) fn mod_inv(a: &Integer, ctx: &IntModCtx) -> Integer {
+    let mut res = Integer::default();
+    unsafe {
+        fmpz_mod::fmpz_mod_inv(res.as_mut_ptr(), a.as_ptr(), ctx.as_ptr());
+    }
+    res
+}
+
+// Return a maximal linearly independent subset of `vecs`, keeping the
+// earliest vectors.
+pub(super) fn independent(vecs: Vec<Vec<IntMod>>, ctx: &IntModCtx) -> Vec<Vec<IntMod>> {
+    // Reduced copies of the kept vectors, each one at its pivot and zero at
+    // the pivots of the others.
+    let mut echelon: Vec<(usize, Vec<Integer>)> = vec![];
+    let mut res = vec![];
+    for v in vecs {
+        let mut w: Vec<Integer> = v.iter().map(Integer::from).collect();
+        for (c, e) in echelon.iter() {
+            let f = w[*c].clone();
+            if !f.is_zero() {
+                for (wi, ei) in w.iter_mut().zip(e) {
+                    *wi = mod_submul(wi, &f, ei, ctx);
+                }
+            }
+        }
+        let Some(c) = w.iter().position(|x| !x.is_zero()) else {
+            continue;
+        };
+        let inv = mod_inv(&w[c], ctx);
+        for wi in w.iter_mut() {
+            *wi = mod_mul(wi, &inv, ctx);
+        }
+        for (_, e) in echelon.iter_mut() {
+            let f = e[c].clone();
+            if !f.is_zero() {
+                for (ei, wi) in e.iter_mut().zip(&w) {
+                    *ei = mod_submul(ei, &f, wi, ctx);
+                }
+            }
+        }
+        echelon.push((c, w));
+        res.push(v);
+    }
+    res
+}
+
+// A dense matrix of residues in `[0, n)`, stored by rows. A block of `b`
+// vectors of length `n` is kept as an `n x b` matrix.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) struct DenseMat {
+    pub(super) rows: Vec<Vec<Integer>>,
+    pub(super) ncols: usize,
+}
+
+impl DenseMat {
+    pub(super) fn zero(nrows: usize, ncols: usize) -> DenseMat {
+        DenseMat { rows: vec![vec![Integer::default(); ncols]; nrows], ncols }
+    }
+
+    pub(super) fn one(n: usize) -> DenseMat {
+        let mut res = DenseMat::zero(n, n);
+        for (i, row) in res.rows.iter_mut().enumerate() {
+            row[i] = Integer::from(1);
+        }
+        res
+    }
+
+    pub(super) fn random(nrows: usize, ncols: usize, state: &mut RandState, ctx: &IntModCtx) -> DenseMat {
+        let rows = (0..nrows)
+            .map(|_| (0..ncols).map(|_| Integer::from(&state.intmod(ctx))).collect())
+            .collect();
+        DenseMat { rows, ncols }
+    }
+
+    pub(super) fn nrows(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub(super) fn is_zero(&self) -> bool {
+        self.rows.iter().flatten().all(Integer::is_zero)
+    }
+
+    pub(super) fn column(&self, k: usize, ctx: &IntModCtx) -> Vec<IntMod> {
+        self.rows.iter().map(|row| IntMod::new(row[k].clone(), ctx)).collect()
+    }
+
+    // Return the matrix whose columns are those of `self` followed by those
+    // of `other`.
+    pub(super) fn concat(&self, other: &DenseMat) -> DenseMat {
+        assert_eq!(self.nrows(), other.nrows());
+        let rows = self.rows.iter()
+            .zip(&other.rows)
+            .map(|(a, b)| a.iter().chain(b).cloned().collect())
+            .collect();
+        DenseMat { rows, ncols: self.ncols + other.ncols }
+    }
+
+    pub(super) fn add(&self, other: &DenseMat, ctx: &IntModCtx) -> DenseMat {
+        self.zip_with(other, |a, b| mod_reduce(&(a + b), ctx))
+    }
+
+    pub(super) fn sub(&self, other: &DenseMat, ctx: &IntModCtx) -> DenseMat {
+        self.zip_with(other, |a, b| mod_reduce(&(a - b), ctx))
+    }
+
+    pub(super) fn neg(&self, ctx: &IntModCtx) -> DenseMat {
+        let rows = self.rows.iter()
+            .map(|row| row.iter().map(|a| mod_reduce(&-a, ctx)).collect())
+            .collect();
+        DenseMat { rows, ncols: self.ncols }
+    }
+
+    fn zip_with<F>(&self, other: &DenseMat, f: F) -> DenseMat
+    where
+        F: Fn(&Integer, &Integer) -> Integer
+    {
+        assert_eq!((self.nrows(), self.ncols), (other.nrows(), other.ncols));
+        let rows = self.rows.iter()
+            .zip(&other.rows)
+            .map(|(a, b)| a.iter().zip(b).map(|(x, y)| f(x, y)).collect())
+            .collect();
+        DenseMat { rows, ncols: self.ncols }
+    }
+
+    pub(super) fn mul(&self, other: &DenseMat, ctx: &IntModCtx) -> DenseMat {
+        assert_eq!(self.ncols, other.nrows());
+        let rows = self.rows.iter()
+            .map(|row| {
+                let mut acc = vec![Integer::default(); other.ncols];
+                for (a, orow) in row.iter().zip(&other.rows) {
+                    if !a.is_zero() {
+                        for (s, b) in acc.iter_mut().zip(orow) {
+                            s.addmul_mut(a, b);
+                        }
+                    }
+                }
+                acc.iter().map(|s| mod_reduce(s, ctx)).collect()
+            })
+            .collect();
+        DenseMat { rows, ncols: other.ncols }
+    }
+
+    // Return `self^T other` without forming the transpose.
+    pub(super) fn transpose_mul(&self, other: &DenseMat, ctx: &IntModCtx) -> DenseMat {
+        assert_eq!(self.nrows(), other.nrows());
+        let mut acc = vec![vec![Integer::default(); other.ncols]; self.ncols];
+        for (row, orow) in self.rows.iter().zip(&other.rows) {
+            for (a, out) in row.iter().zip(acc.iter_mut()) {
+                if !a.is_zero() {
+                    for (s, b) in out.iter_mut().zip(orow) {
+                        s.addmul_mut(a, b);
+                    }
+                }
+            }
+        }
+        let rows = acc.iter()
+            .map(|row| row.iter().map(|s| mod_reduce(s, ctx)).collect())
+            .collect();
+        DenseMat { rows, ncols: other.ncols }
+    }
+
+    // Return the product with a column vector of residues.
+    pub(super) fn mul_vec(&self, x: &[Integer], ctx: &IntModCtx) -> Vec<Integer> {
+        self.rows.iter()
+            .map(|row| {
+                let mut s = Integer::default();
+                for (a, b) in row.iter().zip(x) {
+                    s.addmul_mut(a, b);
+                }
+                mod_reduce(&s, ctx)
+            })
+            .collect()
+    }
+
+    // Put the matrix in reduced row echelon form, choosing pivots only among
+    // the first `ncols` columns, and return the pivot columns. The rows
+    // past the rank are then zero in those columns.
+    pub(super) fn rref(&mut self, ncols: usize, ctx: &IntModCtx) -> Vec<usize> {
+        let mut pivots = vec![];
+        for c in 0..ncols {
+            let r = pivots.len();
+            let Some(k) = (r..self.nrows()).find(|&k| !self.rows[k][c].is_zero()) else {
+                continue;
+            };
+            self.rows.swap(r, k);
+            let inv = mod_inv(&self.rows[r][c], ctx);
+            for a in self.rows[r].iter_mut() {
+                *a = mod_mul(a, &inv, ctx);
+            }
+            // The pivot row is taken out while the others are reduced, and
+            // is skipped below as it is empty.
+            let pivot = std::mem::take(&mut self.rows[r]);
+            for row in self.rows.iter_mut() {
+                let f = match row.get(c) {
+                    Some(f) if !f.is_zero() => f.clone(),
+                    _ => continue,
+                };
+                for (a, b) in row.iter_mut().zip(&pivot) {
+                    *a = mod_submul(a, &f, b, ctx);
+                }
+            }
+            self.rows[r] = pivot;
+            pivots.push(c);
+        }
+        pivots
+    }
+
+    // Return a basis of the right nullspace as the columns of a matrix.
+    pub(super) fn nullspace(&self, ctx: &IntModCtx) -> DenseMat {
+        let mut r = self.clone();
+        let pivots = r.rref(self.ncols, ctx);
+        let mut is_pivot = vec![false; self.ncols];
+        for &c in pivots.iter() {
+            is_pivot[c] = true;
+        }
+        let free: Vec<usize> = (0..self.ncols).filter(|&c| !is_pivot[c]).collect();
+        let mut res = DenseMat::zero(self.ncols, free.len());
+        for (k, &f) in free.iter().enumerate() {
+            res.rows[f][k] = Integer::from(1);
+            for (row, &c) in r.rows.iter().zip(&pivots) {
+                res.rows[c][k] = mod_reduce(&-&row[f], ctx);
+            }
+        }
+        res
+    }
+}
+
+impl SparseIntModMat {
+    // Return the product `AV` with a block of vectors.
+    pub(super) fn mul_block(&self, v: &DenseMat) -> DenseMat {
+        assert_eq!(self.ncols(), v.nrows());
+        let rows = (0..self.nrows())
+            .map(|i| {
+                let mut acc = vec![Integer::default(); v.ncols];
+                for (j, a) in self.inner.iter_row(i) {
+                    for (s, b) in acc.iter_mut().zip(&v.rows[j]) {
+                        s.addmul_mut(a, b);
+                    }
+                }
+                acc.iter().map(|s| mod_reduce(s, &self.ctx)).collect()
+            })
+            .collect();
+        DenseMat { rows, ncols: v.ncols }
+    }
+
+    // Return the product `A^T V` with a block of vectors.
+    pub(super) fn transpose_mul_block(&self, v: &DenseMat) -> DenseMat {
+        assert_eq!(self.nrows(), v.nrows());
+        let mut acc = vec![vec![Integer::default(); v.ncols]; self.ncols()];
+        for (i, vrow) in v.rows.iter().enumerate() {
+            for (j, a) in self.inner.iter_row(i) {
+                for (s, b) in acc[j].iter_mut().zip(vrow) {
+                    s.addmul_mut(a, b);
+                }
+            }
+        }
+        let rows = acc.iter()
+            .map(|row| row.iter().map(|s| mod_reduce(s, &self.ctx)).collect())
+            .collect();
+        DenseMat { rows, ncols: v.ncols }
+    }
+}
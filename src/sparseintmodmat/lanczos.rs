@@ -0,0 +1,228 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Montgomery's block Lanczos algorithm over prime fields.
+
+use super::dense::{independent, mod_inv, mod_mul, mod_submul, DenseMat};
+use crate::*;
+
+// Montgomery's choice of the columns `S` of `V` to keep in an iteration,
+// given `T = V^T B V` and the previous choice, whose complement is tried
+// first. Returns `W^-1 = S (S^T T S)^-1 S^T` and the choice.
+fn select(t: &DenseMat, prev: &[bool], ctx: &IntModCtx) -> (DenseMat, Vec<bool>) {
+    let n = t.ncols;
+    let mut m = t.concat(&DenseMat::one(n));
+    let order: Vec<usize> = (0..n).filter(|&c| !prev[c]).chain((0..n).filter(|&c| prev[c])).collect();
+    let mut sel = vec![false; n];
+
+    // Clear column `col` in every other row with row `c`, after swapping it
+    // with the first row in `order[from..]` that is nonzero there.
+    let pivot = |m: &mut DenseMat, from: usize, c: usize, col: usize| -> bool {
+        let Some(k) = (from..n).find(|&k| !m.rows[order[k]][col].is_zero()) else {
+            return false;
+        };
+        m.rows.swap(c, order[k]);
+        let inv = mod_inv(&m.rows[c][col], ctx);
+        for a in m.rows[c].iter_mut() {
+            *a = mod_mul(a, &inv, ctx);
+        }
+        let row = m.rows[c].clone();
+        for (r, other) in m.rows.iter_mut().enumerate() {
+            if r != c && !other[col].is_zero() {
+                let f = other[col].clone();
+                for (a, b) in other.iter_mut().zip(&row) {
+                    *a = mod_submul(a, &f, b, ctx);
+                }
+            }
+        }
+        true
+    };
+    for (j, &c) in order.iter().enumerate() {
+        if pivot(&mut m, j, c, c) {
+            sel[c] = true;
+        } else {
+            // `T` is singular in this direction: drop the column.
+            assert!(pivot(&mut m, j, c, n + c));
+            m.rows[c].iter_mut().for_each(|a| *a = Integer::default());
+        }
+    }
+    let winv = DenseMat {
+        rows: m.rows.into_iter().map(|row| row[n..].to_vec()).collect(),
+        ncols: n,
+    };
+    (winv, sel)
+}
+
+// The quantities kept from an earlier iteration.
+struct Step {
+    v: DenseMat,
+    winv: DenseMat,
+    t: DenseMat,
+    t2: DenseMat,
+    sel: DenseMat,
+}
+
+impl SparseIntModMat {
+    /// Return linearly independent vectors `x` with `Ax = 0` over a prime
+    /// field, using Montgomery's block Lanczos algorithm with blocks of
+    /// `block_size` random vectors drawn from `state`. The matrix need not
+    /// be square.
+    ///
+    /// Lanczos is run on the symmetric matrix `B = A^T A` from a random
+    /// block `Y`, giving `X` with `BX = BY` and a final block `V` that `B`
+    /// maps into a small space. Nullvectors of `A` are then combinations of
+    /// the columns of `X - Y` and `V`, found by dense elimination on their
+    /// images under `A`. Only products of `A` and `A^T` with blocks of
+    /// vectors are used, about `n / block_size` of each. At most about
+    /// `block_size` nullvectors are returned, and an empty result means
+    /// there are none or the random choices were unlucky, in which case the
+    /// call can be retried with the same state. Small fields need larger
+    /// blocks: over `p = 2`, use a block size of at least 8, and preferably
+    /// a word such as 64. Panics if `block_size` is zero or the modulus is
+    /// not prime.
+    ///
+    /// ```
+    /// use rufl::intmod::IntModCtx;
+    /// use rufl::rand::RandState;
+    /// use rufl::sparseintmodmat::SparseIntModMat;
+    ///
+    /// let ctx = IntModCtx::new(2);
+    /// let m = SparseIntModMat::from_triplets(3, 5, [
+    ///     (0, 0, 1), (0, 3, 1), (1, 1, 1), (1, 2, 1), (2, 0, 1), (2, 4, 1)
+    /// ], &ctx);
+    ///
+    /// let mut state = RandState::with_seed(1);
+    /// let k = loop {
+    ///     let k = m.lanczos_nullvectors(16, &mut state);
+    ///     if !k.is_empty() {
+    ///         break k;
+    ///     }
+    /// };
+    /// for x in k.iter() {
+    ///     assert!(x.iter().any(|c| !c.is_zero()));
+    ///     assert!(m.mul_vec(x).iter().all(|c| c.is_zero()));
+    /// }
+    /// ```
+    pub fn lanczos_nullvectors(&self, block_size: usize, state: &mut RandState) -> Vec<Vec<IntMod>> {
+        assert!(block_size > 0);
+        self.check_prime();
+        let ctx = &self.ctx;
+        let n = self.ncols();
+        let b = block_size;
+        let apply = |v: &DenseMat| self.transpose_mul_block(&self.mul_block(v));
+
+        let y = DenseMat::random(n, b, state, ctx);
+        let v0 = apply(&y);
+        let one = DenseMat::one(b);
+        let mut x = DenseMat::zero(n, b);
+        let mut v = v0.clone();
+        let mut prev_sel = vec![true; b];
+        let mut history: Vec<Step> = vec![];
+
+        // The blocks `V_i` are pairwise `B`-orthogonal, and `X` collects the
+        // projections of `V_0 = BY` onto them.
+        for _ in 0..n + 10 {
+            let bv = apply(&v);
+            let t = v.transpose_mul(&bv, ctx);
+            if t.is_zero() {
+                break;
+            }
+            let t2 = bv.transpose_mul(&bv, ctx);
+            let (winv, sel) = select(&t, &prev_sel, ctx);
+            if !sel.contains(&true) {
+                break;
+            }
+            let mut ss = DenseMat::zero(b, b);
+            for (i, &s) in sel.iter().enumerate() {
+                if s {
+                    ss.rows[i][i] = Integer::from(1);
+                }
+            }
+
+            x = x.add(&v.mul(&winv.mul(&v.transpose_mul(&v0, ctx), ctx), ctx), ctx);
+
+            // V_{i+1} = BV_i S_i + V_i D_{i+1} + V_{i-1} E_{i+1} + V_{i-2} F_{i+1}
+            let d = one.sub(&winv.mul(&t2.mul(&ss, ctx).add(&t, ctx), ctx), ctx);
+            let mut next = bv.mul(&ss, ctx).add(&v.mul(&d, ctx), ctx);
+            if let Some(h1) = history.last() {
+                let e = h1.winv.mul(&t.mul(&ss, ctx), ctx).neg(ctx);
+                next = next.add(&h1.v.mul(&e, ctx), ctx);
+                if history.len() == 2 {
+                    let h2 = &history[0];
+                    let f = h2.winv
+                        .mul(&one.sub(&h1.t.mul(&h1.winv, ctx), ctx), ctx)
+                        .mul(&h1.t2.mul(&h1.sel, ctx).add(&h1.t, ctx), ctx)
+                        .mul(&ss, ctx)
+                        .neg(ctx);
+                    next = next.add(&h2.v.mul(&f, ctx), ctx);
+                }
+            }
+
+            history.push(Step { v, winv, t, t2, sel: ss });
+            if history.len() > 2 {
+                history.remove(0);
+            }
+            v = next;
+            prev_sel = sel;
+        }
+
+        // `B` maps the columns of `X - Y` and `V` into a small space, so
+        // `A` is singular on their span with high probability.
+        let u = x.sub(&y, ctx).concat(&v);
+        let k = self.mul_block(&u).nullspace(ctx);
+        let w = u.mul(&k, ctx);
+        let found = (0..w.ncols)
+            .map(|j| w.column(j, ctx))
+            .filter(|c| c.iter().any(|a| !a.is_zero()))
+            .collect();
+        independent(found, ctx)
+    }
+
+    /// Solve `Ax = b` over a prime field using block Lanczos with blocks of
+    /// `block_size` random vectors drawn from `state`. The matrix need not
+    /// be square. The nullvectors `(x, t)` of `[A | b]` with `t` nonzero give
+    /// the solutions `-x/t`.
+    ///
+    /// Returns `None` if no solution was found, which happens when there is
+    /// none or when the random choices were unlucky, in which case the call
+    /// can be retried with the same state. Panics under the same conditions
+    /// as [SparseIntModMat::lanczos_nullvectors].
+    ///
+    /// ```
+    /// use rufl::intmod::{IntMod, IntModCtx};
+    /// use rufl::rand::RandState;
+    /// use rufl::sparseintmodmat::SparseIntModMat;
+    ///
+    /// let ctx = IntModCtx::new(1000003);
+    /// let m = SparseIntModMat::from_triplets(4, 3, [
+    ///     (0, 0, 1), (0, 1, 1), (1, 1, 2), (2, 2, 3), (3, 0, 1), (3, 2, 1)
+    /// ], &ctx);
+    /// let b = [3, 4, 9, 4].map(|c| IntMod::new(c, &ctx));
+    ///
+    /// let mut state = RandState::with_seed(1);
+    /// let x = loop {
+    ///     if let Some(x) = m.lanczos_solve(&b, 2, &mut state) {
+    ///         break x;
+    ///     }
+    /// };
+    /// assert_eq!(x, [1, 2, 3].map(|c| IntMod::new(c, &ctx)));
+    /// ```
+    pub fn lanczos_solve(&self, b: &[IntMod], block_size: usize, state: &mut RandState) -> Option<Vec<IntMod>> {
+        let aug = self.augment(b, false);
+        self.solution_from_nullvectors(aug.lanczos_nullvectors(block_size, state))
+    }
+}
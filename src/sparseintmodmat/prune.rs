@@ -0,0 +1,205 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! The filtering stages of structured Gaussian elimination, singleton and
+//! clique removal, which shrink a matrix while keeping part of its
+//! nullspace.
+
+use crate::*;
+
+// The columns still kept, with the number of kept entries in each row.
+struct Pruning<'a> {
+    mat: &'a SparseIntMat,
+    col_rows: Vec<Vec<usize>>,
+    weight: Vec<usize>,
+    alive: Vec<bool>,
+    ncols: usize,
+    nrows: usize,
+}
+
+impl Pruning<'_> {
+    // Remove column `j`, then every column left alone in a row, since the
+    // row forces it to zero. The removed columns are appended to `log`.
+    fn remove(&mut self, j: usize, log: &mut Vec<usize>) {
+        let mut stack = vec![j];
+        while let Some(c) = stack.pop() {
+            if !self.alive[c] {
+                continue;
+            }
+            self.alive[c] = false;
+            self.ncols -= 1;
+            log.push(c);
+            for &i in self.col_rows[c].iter() {
+                self.weight[i] -= 1;
+                match self.weight[i] {
+                    0 => self.nrows -= 1,
+                    1 => stack.extend(self.mat.iter_row(i).map(|e| e.0).filter(|&k| self.alive[k])),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    // Undo the removal of the columns in `log`.
+    fn restore(&mut self, log: &[usize]) {
+        for &c in log.iter().rev() {
+            self.alive[c] = true;
+            self.ncols += 1;
+            for &i in self.col_rows[c].iter() {
+                if self.weight[i] == 0 {
+                    self.nrows += 1;
+                }
+                self.weight[i] += 1;
+            }
+        }
+    }
+
+    // Return the cliques: the connected components of the kept columns
+    // linked by rows with exactly two kept entries.
+    fn cliques(&self) -> Vec<Vec<usize>> {
+        let n = self.alive.len();
+        let mut parent: Vec<usize> = (0..n).collect();
+        fn find(parent: &mut [usize], mut x: usize) -> usize {
+            while parent[x] != x {
+                parent[x] = parent[parent[x]];
+                x = parent[x];
+            }
+            x
+        }
+        let mut linked = vec![false; n];
+        for i in (0..self.weight.len()).filter(|&i| self.weight[i] == 2) {
+            let mut cols = self.mat.iter_row(i).map(|e| e.0).filter(|&k| self.alive[k]);
+            let (a, b) = (cols.next().unwrap(), cols.next().unwrap());
+            let (ra, rb) = (find(&mut parent, a), find(&mut parent, b));
+            parent[ra] = rb;
+            linked[a] = true;
+            linked[b] = true;
+        }
+        let mut components = vec![vec![]; n];
+        for j in (0..n).filter(|&j| linked[j]) {
+            let r = find(&mut parent, j);
+            components[r].push(j);
+        }
+        components.retain(|c| !c.is_empty());
+        components
+    }
+}
+
+impl SparseIntModMat {
+    /// Shrink the matrix by singleton and clique removal, the filtering
+    /// stages of structured Gaussian elimination, and return the result with
+    /// the indices of the columns it keeps.
+    ///
+    /// Removing a column amounts to setting that coordinate to zero, and
+    /// rows left with no entries are dropped. A nullvector `y` of the result
+    /// therefore gives the nullvector `x` of `self` with `x[kept[k]] = y[k]`
+    /// and zeros elsewhere, but not every nullvector of `self` arises this
+    /// way. A row with a single entry forces its coordinate to zero, so that
+    /// column is removed (singleton removal). Columns linked by rows with two
+    /// entries form cliques, and the largest cliques are removed while the
+    /// number of columns still exceeds the number of nonzero rows by at
+    /// least `excess` (clique removal), which keeps the dimension of the
+    /// nullspace of the result at least `excess` over a prime field.
+    ///
+    /// This is meant to reduce a large relation matrix before calling
+    /// [SparseIntModMat::wiedemann_nullvectors] or
+    /// [SparseIntModMat::lanczos_nullvectors] on it; the exact methods such as
+    /// [SparseIntModMat::nullspace] do not discard any solutions.
+    ///
+    /// ```
+    /// use rufl::intmod::{IntMod, IntModCtx};
+    /// use rufl::sparseintmodmat::SparseIntModMat;
+    ///
+    /// let ctx = IntModCtx::new(7);
+    /// let m = SparseIntModMat::from_triplets(4, 6, [
+    ///     (0, 0, 1), (0, 1, 1), (1, 1, 1), (1, 2, 1), (2, 3, 1), (3, 2, 1), (3, 4, 1), (3, 5, 1)
+    /// ], &ctx);
+    ///
+    /// // Column 3 is a singleton, and columns 0, 1 and 2 form a clique.
+    /// let (p, kept) = m.prune(2);
+    /// assert_eq!(kept, [0, 1, 2, 4, 5]);
+    /// let (p, kept) = m.prune(1);
+    /// assert_eq!(kept, [4, 5]);
+    /// assert_eq!((p.nrows(), p.ncols()), (1, 2));
+    ///
+    /// let k = p.nullspace();
+    /// let mut x = vec![IntMod::zero(&ctx); 6];
+    /// for (i, &j) in kept.iter().enumerate() {
+    ///     x[j] = k.get_entry(i, 0);
+    /// }
+    /// assert!(m.mul_vec(&x).iter().all(|c| c.is_zero()));
+    /// ```
+    pub fn prune(&self, excess: usize) -> (SparseIntModMat, Vec<usize>) {
+        let (m, n) = (self.nrows(), self.ncols());
+        let mut col_rows = vec![vec![]; n];
+        for (i, j, _) in self.inner.iter_entries() {
+            col_rows[j].push(i);
+        }
+        let weight: Vec<usize> = (0..m).map(|i| self.row_weight(i)).collect();
+        let nrows = weight.iter().filter(|&&w| w > 0).count();
+        let mut p = Pruning {
+            mat: &self.inner,
+            col_rows,
+            weight,
+            alive: vec![true; n],
+            ncols: n,
+            nrows,
+        };
+
+        for i in 0..m {
+            if p.weight[i] == 1 {
+                let j = self.inner.iter_row(i).map(|e| e.0).find(|&j| p.alive[j]).unwrap();
+                p.remove(j, &mut vec![]);
+            }
+        }
+
+        while p.ncols > p.nrows + excess {
+            let Some(clique) = p.cliques().into_iter().max_by_key(|c| c.len()) else {
+                break;
+            };
+            let mut log = vec![];
+            for j in clique {
+                p.remove(j, &mut log);
+            }
+            if p.ncols < p.nrows + excess {
+                p.restore(&log);
+                break;
+            }
+        }
+
+        let kept: Vec<usize> = (0..n).filter(|&j| p.alive[j]).collect();
+        let mut index = vec![0; n];
+        for (k, &j) in kept.iter().enumerate() {
+            index[j] = k;
+        }
+        let rows: Vec<usize> = (0..m).filter(|&i| p.weight[i] > 0).collect();
+        let (alive, index) = (&p.alive, &index);
+        let entries = rows.iter()
+            .enumerate()
+            .flat_map(|(k, &i)| {
+                self.inner.iter_row(i)
+                    .filter(move |(j, _)| alive[*j])
+                    .map(move |(j, x)| (k, index[j], x.clone()))
+            })
+            .collect::<Vec<_>>();
+        let res = SparseIntModMat {
+            inner: SparseIntMat::from_sorted(rows.len(), kept.len(), entries),
+            ctx: self.ctx.clone(),
+        };
+        (res, kept)
+    }
+}
@@ -0,0 +1,355 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Exact sparse linear algebra over prime fields by structured Gaussian
+//! elimination. Columns are split into light and heavy ones; rows that
+//! have a single light entry are used to eliminate that column, so that
+//! fill-in stays in the heavy columns, and more columns are declared heavy
+//! whenever no such row is left. The rows that remain only involve heavy
+//! columns and are reduced as a dense matrix.
+
+use super::dense::{mod_inv, mod_mul, mod_submul, DenseMat};
+use crate::*;
+use std::collections::BTreeSet;
+
+// A sparse row of residues as `(column, value)` pairs in increasing column
+// order.
+type SparseRow = Vec<(usize, Integer)>;
+
+// Return `a - c*b`, dropping entries that cancel.
+fn row_submul(a: &[(usize, Integer)], c: &Integer, b: &[(usize, Integer)], ctx: &IntModCtx) -> SparseRow {
+    let zero = Integer::default();
+    let mut res = Vec::with_capacity(a.len() + b.len());
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        if j == b.len() || (i < a.len() && a[i].0 < b[j].0) {
+            res.push(a[i].clone());
+            i += 1;
+        } else if i == a.len() || b[j].0 < a[i].0 {
+            res.push((b[j].0, mod_submul(&zero, c, &b[j].1, ctx)));
+            j += 1;
+        } else {
+            let v = mod_submul(&a[i].1, c, &b[j].1, ctx);
+            if !v.is_zero() {
+                res.push((a[i].0, v));
+            }
+            i += 1;
+            j += 1;
+        }
+    }
+    res
+}
+
+// The result of structured Gaussian elimination of a matrix, possibly
+// augmented by a right hand side in column `ncols`.
+struct Elimination {
+    // The pivot columns with their rows, in the order they were chosen.
+    // Each pivot is one, and the other entries of a pivot row are in
+    // columns that are either free or pivots chosen later, or in the right
+    // hand side.
+    pivots: Vec<(usize, SparseRow)>,
+    // Whether some row reduced to `0 = b'` with `b'` nonzero.
+    inconsistent: bool,
+}
+
+// The rows still to be eliminated, with the remaining rows having an entry
+// in each column.
+struct ActiveRows {
+    rows: Vec<SparseRow>,
+    col_rows: Vec<BTreeSet<usize>>,
+    active: Vec<bool>,
+}
+
+impl ActiveRows {
+    // Remove row `r` and return it scaled to be one in column `c`.
+    fn take_pivot(&mut self, r: usize, c: usize, ctx: &IntModCtx) -> SparseRow {
+        let mut row = std::mem::take(&mut self.rows[r]);
+        for (j, _) in row.iter() {
+            self.col_rows[*j].remove(&r);
+        }
+        self.active[r] = false;
+        let k = row.binary_search_by_key(&c, |e| e.0).unwrap();
+        let inv = mod_inv(&row[k].1, ctx);
+        for (_, v) in row.iter_mut() {
+            *v = mod_mul(v, &inv, ctx);
+        }
+        row
+    }
+
+    // Clear column `c` from the remaining rows using a pivot row.
+    fn eliminate_column(&mut self, c: usize, pivot: &[(usize, Integer)], ctx: &IntModCtx) {
+        for s in std::mem::take(&mut self.col_rows[c]) {
+            let k = self.rows[s].binary_search_by_key(&c, |e| e.0).unwrap();
+            let factor = self.rows[s][k].1.clone();
+            let row = row_submul(&self.rows[s], &factor, pivot, ctx);
+            for (j, _) in self.rows[s].iter() {
+                self.col_rows[*j].remove(&s);
+            }
+            for (j, _) in row.iter() {
+                self.col_rows[*j].insert(s);
+            }
+            self.rows[s] = row;
+        }
+    }
+}
+
+impl SparseIntModMat {
+    pub(super) fn check_prime(&self) {
+        assert!(self.modulus().is_prime(), "Sparse elimination requires a prime modulus.");
+    }
+
+    // Return `[A | b]`, with a zero row appended if `square` so that a
+    // square matrix stays square.
+    pub(super) fn augment(&self, b: &[IntMod], square: bool) -> SparseIntModMat {
+        assert_eq!(b.len(), self.nrows());
+        let (m, n) = (self.nrows(), self.ncols());
+        let entries = self.inner.iter_entries()
+            .map(|(i, j, x)| (i, j, x.clone()))
+            .chain(self.lift_vec(b).into_iter().enumerate().map(|(i, x)| (i, n, x)))
+            .collect::<Vec<_>>();
+        SparseIntModMat {
+            inner: SparseIntMat::from_triplets(m + square as usize, n + 1, entries),
+            ctx: self.ctx.clone(),
+        }
+    }
+
+    // Return a solution of `Ax = b` from nullvectors `(x, t)` of an
+    // augmented matrix `[A | b]`, as `-x/t` for the first with `t` nonzero.
+    pub(super) fn solution_from_nullvectors(&self, nullvectors: Vec<Vec<IntMod>>) -> Option<Vec<IntMod>> {
+        let n = self.ncols();
+        let mut v = nullvectors.into_iter().find(|v| !v[n].is_zero())?;
+        let c = IntMod::new(-mod_inv(&Integer::from(&v[n]), &self.ctx), &self.ctx);
+        v.truncate(n);
+        for vi in v.iter_mut() {
+            *vi *= &c;
+        }
+        Some(v)
+    }
+
+    // Structured Gaussian elimination of `A`, or of `[A | b]` when `rhs` is
+    // given. The right hand side column is never a pivot.
+    fn eliminate(&self, rhs: Option<&[IntMod]>) -> Elimination {
+        self.check_prime();
+        let ctx = &self.ctx;
+        let (m, n) = (self.nrows(), self.ncols());
+        let rows: Vec<SparseRow> = (0..m)
+            .map(|i| {
+                let mut row: SparseRow = self.inner.iter_row(i).map(|(j, x)| (j, x.clone())).collect();
+                if let Some(b) = rhs {
+                    assert_eq!(b[i].context(), ctx);
+                    if !b[i].is_zero() {
+                        row.push((n, Integer::from(&b[i])));
+                    }
+                }
+                row
+            })
+            .collect();
+        let mut col_rows = vec![BTreeSet::new(); n + 1];
+        for (i, row) in rows.iter().enumerate() {
+            for (j, _) in row.iter() {
+                col_rows[*j].insert(i);
+            }
+        }
+        let mut a = ActiveRows { rows, col_rows, active: vec![true; m] };
+
+        // Columns that are pivots or free are done.
+        let mut heavy = vec![false; n];
+        let mut done = vec![false; n];
+        let mut pivots = vec![];
+        loop {
+            let mut changed = true;
+            while changed {
+                changed = false;
+                // A column in no remaining row is free, and one in a single
+                // remaining row is a pivot that needs no elimination.
+                for j in 0..n {
+                    if done[j] {
+                        continue;
+                    }
+                    if let Some(&r) = a.col_rows[j].first() {
+                        if a.col_rows[j].len() > 1 {
+                            continue;
+                        }
+                        pivots.push((j, a.take_pivot(r, j, ctx)));
+                        changed = true;
+                    }
+                    done[j] = true;
+                }
+                // A row with a single light entry is a pivot row for that
+                // column, and eliminating it only fills in heavy columns.
+                for r in 0..m {
+                    if !a.active[r] {
+                        continue;
+                    }
+                    let c = {
+                        let mut light = a.rows[r].iter().map(|e| e.0).filter(|&j| j < n && !heavy[j]);
+                        match (light.next(), light.next()) {
+                            (Some(c), None) => c,
+                            _ => continue,
+                        }
+                    };
+                    let pivot = a.take_pivot(r, c, ctx);
+                    a.eliminate_column(c, &pivot, ctx);
+                    pivots.push((c, pivot));
+                    done[c] = true;
+                    changed = true;
+                }
+            }
+
+            // Stuck: declare the heaviest light columns heavy.
+            let mut light: Vec<usize> = (0..n).filter(|&j| !done[j] && !heavy[j]).collect();
+            if light.is_empty() {
+                break;
+            }
+            light.sort_by_key(|&j| std::cmp::Reverse(a.col_rows[j].len()));
+            for &j in light.iter().take(1.max(light.len() / 16)) {
+                heavy[j] = true;
+            }
+        }
+
+        // The remaining rows only have entries in the remaining heavy
+        // columns and the right hand side.
+        let core_cols: Vec<usize> = (0..n).filter(|&j| !done[j]).collect();
+        let w = core_cols.len();
+        let mut index = vec![w; n + 1];
+        for (k, &j) in core_cols.iter().enumerate() {
+            index[j] = k;
+        }
+        let mut core = DenseMat::zero(0, w + 1);
+        for r in (0..m).filter(|&r| a.active[r] && !a.rows[r].is_empty()) {
+            let mut row = vec![Integer::default(); w + 1];
+            for (j, v) in a.rows[r].iter() {
+                row[index[*j]] = v.clone();
+            }
+            core.rows.push(row);
+        }
+        let core_pivots = core.rref(w, ctx);
+        let inconsistent = core.rows[core_pivots.len()..].iter().any(|row| !row[w].is_zero());
+        for (row, &k) in core.rows.iter().zip(core_pivots.iter()) {
+            let row = row.iter()
+                .enumerate()
+                .filter(|(_, v)| !v.is_zero())
+                .map(|(l, v)| (if l == w { n } else { core_cols[l] }, v.clone()))
+                .collect();
+            pivots.push((core_cols[k], row));
+        }
+        Elimination { pivots, inconsistent }
+    }
+
+    // Fill in the pivot coordinates of `x` by back substitution, given its
+    // other coordinates. A pivot row reads `x_c + sum a_j x_j = b` with `b`
+    // in column `ncols`, or zero if there is none.
+    fn back_substitute(&self, e: &Elimination, x: &mut [Integer]) {
+        let n = self.ncols();
+        for (c, row) in e.pivots.iter().rev() {
+            let mut b = Integer::default();
+            let mut s = Integer::default();
+            for (j, v) in row.iter() {
+                if *j == n {
+                    b = v.clone();
+                } else if j != c {
+                    s.addmul_mut(v, &x[*j]);
+                }
+            }
+            x[*c] = Integer::from(&IntMod::new(b - s, &self.ctx));
+        }
+    }
+
+    /// Return the rank of a matrix over a prime field, computed by
+    /// structured Gaussian elimination. Panics if the modulus is not prime.
+    ///
+    /// ```
+    /// use rufl::intmod::IntModCtx;
+    /// use rufl::sparseintmodmat::SparseIntModMat;
+    ///
+    /// let ctx = IntModCtx::new(7);
+    /// let m = SparseIntModMat::from_triplets(3, 3, [(0, 0, 1), (0, 1, 2), (1, 1, 1), (2, 0, 1), (2, 1, 3)], &ctx);
+    /// assert_eq!(m.rank(), 2);
+    /// ```
+    #[inline]
+    pub fn rank(&self) -> usize {
+        self.eliminate(None).pivots.len()
+    }
+
+    /// Return a matrix whose columns form a basis of the right nullspace
+    /// `{x : Ax = 0}` over a prime field, computed by structured Gaussian
+    /// elimination. Each basis vector has a single one among the non-pivot
+    /// coordinates. Panics if the modulus is not prime.
+    ///
+    /// ```
+    /// use rufl::intmat::IntMat;
+    /// use rufl::intmod::IntModCtx;
+    /// use rufl::sparseintmodmat::SparseIntModMat;
+    ///
+    /// let ctx = IntModCtx::new(7);
+    /// let m = SparseIntModMat::from_triplets(2, 3, [(0, 0, 1), (0, 1, 1), (1, 2, 1)], &ctx);
+    /// let k = m.nullspace();
+    /// assert_eq!(IntMat::from(&k), IntMat::new([6, 1, 0], 3, 1));
+    /// ```
+    pub fn nullspace(&self) -> SparseIntModMat {
+        let e = self.eliminate(None);
+        let n = self.ncols();
+        let mut is_pivot = vec![false; n];
+        for (c, _) in e.pivots.iter() {
+            is_pivot[*c] = true;
+        }
+
+        let mut entries = vec![];
+        for (k, f) in (0..n).filter(|&j| !is_pivot[j]).enumerate() {
+            let mut x = vec![Integer::default(); n];
+            x[f] = Integer::from(1);
+            self.back_substitute(&e, &mut x);
+            entries.extend(
+                x.into_iter().enumerate().filter(|(_, v)| !v.is_zero()).map(|(j, v)| (j, k, v))
+            );
+        }
+        SparseIntModMat {
+            inner: SparseIntMat::from_triplets(n, n - e.pivots.len(), entries),
+            ctx: self.ctx.clone(),
+        }
+    }
+
+    /// Solve `Ax = b` over a prime field by structured Gaussian elimination,
+    /// returning `None` if there is no solution. When the solution is not
+    /// unique, the non-pivot coordinates are set to zero. Panics if the
+    /// modulus is not prime.
+    ///
+    /// ```
+    /// use rufl::intmod::{IntMod, IntModCtx};
+    /// use rufl::sparseintmodmat::SparseIntModMat;
+    ///
+    /// let ctx = IntModCtx::new(11);
+    /// let m = SparseIntModMat::from_triplets(2, 2, [(0, 0, 2), (1, 0, 1), (1, 1, 3)], &ctx);
+    /// let b = [4, 5].map(|c| IntMod::new(c, &ctx));
+    /// let x = m.solve(&b).unwrap();
+    /// assert_eq!(m.mul_vec(&x), b);
+    ///
+    /// let m = SparseIntModMat::from_triplets(2, 2, [(0, 0, 1), (1, 0, 2)], &ctx);
+    /// assert!(m.solve(&b).is_none());
+    /// ```
+    pub fn solve(&self, b: &[IntMod]) -> Option<Vec<IntMod>> {
+        assert_eq!(b.len(), self.nrows());
+        let e = self.eliminate(Some(b));
+        if e.inconsistent {
+            return None;
+        }
+
+        let mut x = vec![Integer::default(); self.ncols()];
+        self.back_substitute(&e, &mut x);
+        Some(x.into_iter().map(|v| IntMod::new(v, &self.ctx)).collect())
+    }
+}
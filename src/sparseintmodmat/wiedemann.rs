@@ -0,0 +1,237 @@
+/*
+ *  Copyright (C) 2024 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Coppersmith's block Wiedemann algorithm over prime fields.
+
+use super::dense::{independent, mod_inv, mod_mul, mod_reduce, mod_submul, DenseMat};
+use crate::*;
+
+// A polynomial as its coefficients, low degree first, possibly with
+// trailing zeros.
+type Poly = Vec<Integer>;
+
+fn degree(f: &[Integer]) -> Option<usize> {
+    f.iter().rposition(|c| !c.is_zero())
+}
+
+fn coeff(f: &[Integer], k: usize) -> Integer {
+    f.get(k).cloned().unwrap_or_default()
+}
+
+// Set `f` to `f - c*g`.
+fn poly_submul(f: &mut Poly, c: &Integer, g: &[Integer], ctx: &IntModCtx) {
+    if f.len() < g.len() {
+        f.resize(g.len(), Integer::default());
+    }
+    for (a, b) in f.iter_mut().zip(g) {
+        *a = mod_submul(a, c, b, ctx);
+    }
+}
+
+// Return a basis of the approximants of order `len` of `F = [S^T; -I]`,
+// where `S = sum M_k x^k` for the `b x b` matrices `seq`: the rows `p` of
+// polynomials with `p F = 0 mod x^len`, together with their degrees. The
+// basis is computed one order at a time (the M-Basis algorithm), keeping
+// the residual `P F` alongside the basis `P`.
+fn approximant_basis(seq: &[DenseMat], len: usize, ctx: &IntModCtx) -> (Vec<Vec<Poly>>, Vec<usize>) {
+    let b = seq[0].ncols;
+    let mut basis: Vec<Vec<Poly>> = (0..2 * b)
+        .map(|r| (0..2 * b).map(|c| if r == c { vec![Integer::from(1)] } else { vec![] }).collect())
+        .collect();
+    let mut residual: Vec<Vec<Poly>> = (0..b)
+        .map(|r| (0..b).map(|c| seq.iter().map(|m| m.rows[c][r].clone()).collect()).collect())
+        .chain((0..b).map(|r| {
+            (0..b).map(|c| if r == c { vec![mod_reduce(&Integer::from(-1), ctx)] } else { vec![] }).collect()
+        }))
+        .collect();
+    let mut degs = vec![0; 2 * b];
+
+    for k in 0..len {
+        // Clear the coefficients of `x^k` in the residual with a pivot row
+        // of least degree in each column, then multiply the pivot rows by
+        // `x`.
+        let mut is_pivot = vec![false; 2 * b];
+        for c in 0..b {
+            let cand: Vec<usize> = (0..2 * b)
+                .filter(|&r| !is_pivot[r] && !coeff(&residual[r][c], k).is_zero())
+                .collect();
+            let Some(&piv) = cand.iter().min_by_key(|&&r| (degs[r], r)) else {
+                continue;
+            };
+            is_pivot[piv] = true;
+            let inv = mod_inv(&residual[piv][c][k], ctx);
+            for &r in cand.iter().filter(|&&r| r != piv) {
+                let f = mod_mul(&residual[r][c][k], &inv, ctx);
+                for t in 0..2 * b {
+                    let g = basis[piv][t].clone();
+                    poly_submul(&mut basis[r][t], &f, &g, ctx);
+                }
+                for t in 0..b {
+                    let g = residual[piv][t].clone();
+                    poly_submul(&mut residual[r][t], &f, &g, ctx);
+                }
+            }
+        }
+        for r in (0..2 * b).filter(|&r| is_pivot[r]) {
+            for f in basis[r].iter_mut().chain(residual[r].iter_mut()) {
+                if !f.is_empty() {
+                    f.insert(0, Integer::default());
+                }
+            }
+            degs[r] += 1;
+        }
+    }
+    (basis, degs)
+}
+
+impl SparseIntModMat {
+    /// Return linearly independent vectors `x` with `Ax = 0` for a square
+    /// matrix over a prime field, using Coppersmith's block Wiedemann
+    /// algorithm with blocks of `block_size` random vectors drawn from
+    /// `state`.
+    ///
+    /// Only products of the matrix with blocks of vectors are used, so
+    /// memory stays linear in the number of nonzero entries, and about
+    /// `2n / block_size` such products are needed. At most about
+    /// `block_size` nullvectors are returned. An empty result means the
+    /// matrix is nonsingular or the random choices were unlucky, in which
+    /// case the call can be retried with the same state. The chance of
+    /// failure drops quickly as the block size grows, so small fields need
+    /// larger blocks; `block_size = 1` is the scalar algorithm and is only
+    /// reliable when `p` is much larger than `n`. Panics if the matrix is
+    /// not square, `block_size` is zero, or the modulus is not prime.
+    ///
+    /// ```
+    /// use rufl::intmod::IntModCtx;
+    /// use rufl::rand::RandState;
+    /// use rufl::sparseintmodmat::SparseIntModMat;
+    ///
+    /// let ctx = IntModCtx::new(2);
+    /// // The third row is the sum of the first two.
+    /// let m = SparseIntModMat::from_triplets(4, 4, [
+    ///     (0, 0, 1), (0, 1, 1), (1, 1, 1), (1, 2, 1), (2, 0, 1), (2, 2, 1), (3, 3, 1)
+    /// ], &ctx);
+    ///
+    /// let mut state = RandState::with_seed(1);
+    /// let k = loop {
+    ///     let k = m.wiedemann_nullvectors(8, &mut state);
+    ///     if !k.is_empty() {
+    ///         break k;
+    ///     }
+    /// };
+    /// for x in k.iter() {
+    ///     assert!(x.iter().any(|c| !c.is_zero()));
+    ///     assert!(m.mul_vec(x).iter().all(|c| c.is_zero()));
+    /// }
+    /// ```
+    pub fn wiedemann_nullvectors(&self, block_size: usize, state: &mut RandState) -> Vec<Vec<IntMod>> {
+        assert!(self.is_square());
+        assert!(block_size > 0);
+        self.check_prime();
+        let ctx = &self.ctx;
+        let n = self.ncols();
+        if n == 0 {
+            return vec![];
+        }
+        let apply = |v: &[Integer]| -> Vec<Integer> {
+            self.inner.mul_vec(v).iter().map(|s| mod_reduce(s, ctx)).collect()
+        };
+
+        // The sequence `X^T A^i Y` for `Y = AZ` and random blocks `X`, `Z`.
+        let x = DenseMat::random(n, block_size, state, ctx);
+        let z = DenseMat::random(n, block_size, state, ctx);
+        let len = 2 * n.div_ceil(block_size) + 4;
+        let mut seq = Vec::with_capacity(len);
+        let mut w = self.mul_block(&z);
+        for _ in 0..len {
+            seq.push(x.transpose_mul(&w, ctx));
+            w = self.mul_block(&w);
+        }
+
+        // A row `(u, w)` of the approximant basis has `u S^T = w` modulo
+        // `x^len`. For `d` at least the degree of `u` and above that of `w`,
+        // the reversal `f` of `u` then gives `A^(d+1) sum_k A^k Z f_k = 0`
+        // with high probability, and multiplying `sum_k A^k Z f_k` by `A`
+        // until the next product is zero gives a nullvector.
+        let (basis, degs) = approximant_basis(&seq, len, ctx);
+        let mut order: Vec<usize> = (0..2 * block_size).collect();
+        order.sort_by_key(|&r| degs[r]);
+        let mut found = vec![];
+        for r in order {
+            let (u, w) = basis[r].split_at(block_size);
+            let Some(du) = u.iter().filter_map(|f| degree(f)).max() else {
+                continue;
+            };
+            let d = w.iter().filter_map(|f| degree(f)).map(|dw| dw + 1).fold(du, usize::max);
+            let f = |k: usize| -> Vec<Integer> { u.iter().map(|g| coeff(g, d - k)).collect() };
+
+            let mut v = z.mul_vec(&f(d), ctx);
+            for k in (0..d).rev() {
+                v = apply(&v)
+                    .iter()
+                    .zip(z.mul_vec(&f(k), ctx))
+                    .map(|(a, b)| mod_reduce(&(a + b), ctx))
+                    .collect();
+            }
+            if v.iter().all(Integer::is_zero) {
+                continue;
+            }
+            for _ in 0..d + 2 {
+                let av = apply(&v);
+                if av.iter().all(Integer::is_zero) {
+                    found.push(v.iter().map(|c| IntMod::new(c.clone(), ctx)).collect());
+                    break;
+                }
+                v = av;
+            }
+        }
+        independent(found, ctx)
+    }
+
+    /// Solve `Ax = b` for a square matrix over a prime field using block
+    /// Wiedemann with blocks of `block_size` random vectors drawn from
+    /// `state`. The nullvectors `(x, t)` of `[A, b; 0, 0]` with `t` nonzero
+    /// give the solutions `-x/t`.
+    ///
+    /// Returns `None` if no solution was found, which happens when there is
+    /// none or when the random choices were unlucky, in which case the call
+    /// can be retried with the same state. Panics under the same conditions
+    /// as [SparseIntModMat::wiedemann_nullvectors].
+    ///
+    /// ```
+    /// use rufl::intmod::{IntMod, IntModCtx};
+    /// use rufl::rand::RandState;
+    /// use rufl::sparseintmodmat::SparseIntModMat;
+    ///
+    /// let ctx = IntModCtx::new(1000003);
+    /// let m = SparseIntModMat::from_triplets(3, 3, [(0, 0, 2), (0, 2, 1), (1, 1, 3), (2, 0, 1), (2, 2, 5)], &ctx);
+    /// let b = [1, 2, 3].map(|c| IntMod::new(c, &ctx));
+    ///
+    /// let mut state = RandState::with_seed(1);
+    /// let x = loop {
+    ///     if let Some(x) = m.wiedemann_solve(&b, 2, &mut state) {
+    ///         break x;
+    ///     }
+    /// };
+    /// assert_eq!(m.mul_vec(&x), b);
+    /// ```
+    pub fn wiedemann_solve(&self, b: &[IntMod], block_size: usize, state: &mut RandState) -> Option<Vec<IntMod>> {
+        assert!(self.is_square());
+        let aug = self.augment(b, true);
+        self.solution_from_nullvectors(aug.wiedemann_nullvectors(block_size, state))
+    }
+}